@@ -43,4 +43,7 @@ fn main() {
 
     let _ = format_description!("[ignore]");
     let _ = format_description!("[ignore count:0]");
+
+    let _ = format_description!(version = 2, "[optinal [[year]]]");
+    let _ = format_description!(version = 2, "[first [[year]] [[month]]");
 }
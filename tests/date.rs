@@ -480,6 +480,24 @@ fn from_iso_week_date() {
     assert!(Date::from_iso_week_date(-9999, 1, Monday).is_ok());
     // 2019 doesn't have 53 weeks.
     assert!(Date::from_iso_week_date(2019, 53, Monday).is_err());
+    // 2020 is a leap year starting on a Wednesday, so it has 53 weeks.
+    assert_eq!(
+        Date::from_iso_week_date(2020, 53, Monday),
+        Ok(date!(2020 - 12 - 28))
+    );
+    // 2009 is a common year starting on a Thursday, so it has 53 weeks.
+    assert_eq!(
+        Date::from_iso_week_date(2009, 53, Sunday),
+        Ok(date!(2010 - 01 - 03))
+    );
+    // 2008 is a leap year starting on a Tuesday, so it only has 52 weeks.
+    assert!(Date::from_iso_week_date(2008, 53, Monday).is_err());
+    assert!(matches!(
+        Date::from_iso_week_date(2021, 53, Monday),
+        Err(err) if err.name() == "week"
+    ));
+    assert!(Date::from_iso_week_date(2020, 54, Monday).is_err());
+    assert!(Date::from_iso_week_date(2020, 0, Monday).is_err());
     // Regression test. Year zero (1 BCE) has dominical letter BA.
     assert_eq!(
         Date::from_iso_week_date(-1, 52, Saturday),
@@ -502,6 +520,18 @@ fn month() {
     assert_eq!(date!(2020 - 060).month(), Month::February);
 }
 
+#[test]
+fn quarter() {
+    assert_eq!(date!(2019 - 01 - 01).quarter(), 1);
+    assert_eq!(date!(2019 - 03 - 31).quarter(), 1);
+    assert_eq!(date!(2019 - 04 - 01).quarter(), 2);
+    assert_eq!(date!(2019 - 06 - 30).quarter(), 2);
+    assert_eq!(date!(2019 - 07 - 01).quarter(), 3);
+    assert_eq!(date!(2019 - 09 - 30).quarter(), 3);
+    assert_eq!(date!(2019 - 10 - 01).quarter(), 4);
+    assert_eq!(date!(2019 - 12 - 31).quarter(), 4);
+}
+
 #[test]
 fn day() {
     assert_eq!(date!(2019 - 002).day(), 2);
@@ -679,6 +709,25 @@ fn from_julian_day() {
     assert!(Date::from_julian_day(i32::MAX).is_err());
 }
 
+#[test]
+fn julian_day_i64() {
+    assert_eq!(date!(-999_999 - 01 - 01).to_julian_day_i64(), -363_521_074);
+    assert_eq!(date!(2000 - 01 - 01).to_julian_day_i64(), 2_451_545);
+    assert_eq!(
+        Date::from_julian_day_i64(-363_521_074),
+        Ok(date!(-999_999 - 01 - 01))
+    );
+    assert_eq!(Date::from_julian_day_i64(0), Ok(date!(-4713 - 11 - 24)));
+    assert_eq!(
+        Date::from_julian_day_i64(Date::MAX.to_julian_day_i64()),
+        Ok(Date::MAX)
+    );
+    assert!(Date::from_julian_day_i64(Date::MAX.to_julian_day_i64() + 1).is_err());
+    assert!(Date::from_julian_day_i64(Date::MIN.to_julian_day_i64() - 1).is_err());
+    assert!(Date::from_julian_day_i64(i64::MAX).is_err());
+    assert!(Date::from_julian_day_i64(i64::MIN).is_err());
+}
+
 #[test]
 fn midnight() {
     assert_eq!(date!(1970 - 01 - 01).midnight(), datetime!(1970-01-01 0:00));
@@ -922,6 +971,94 @@ fn checked_sub_duration() {
     assert_eq!(Date::MAX.checked_sub(Duration::MIN), None);
 }
 
+#[test]
+fn checked_add_months() {
+    assert_eq!(
+        date!(2021 - 01 - 15).checked_add_months(0),
+        Some(date!(2021 - 01 - 15))
+    );
+    assert_eq!(
+        date!(2021 - 01 - 15).checked_add_months(1),
+        Some(date!(2021 - 02 - 15))
+    );
+    assert_eq!(
+        date!(2021 - 01 - 31).checked_add_months(1),
+        Some(date!(2021 - 02 - 28))
+    );
+    assert_eq!(
+        date!(2020 - 01 - 31).checked_add_months(1),
+        Some(date!(2020 - 02 - 29))
+    );
+    assert_eq!(
+        date!(2020 - 02 - 29).checked_add_months(12),
+        Some(date!(2021 - 02 - 28))
+    );
+    assert_eq!(
+        date!(2020 - 02 - 29).checked_add_months(48),
+        Some(date!(2024 - 02 - 29))
+    );
+    assert_eq!(
+        date!(2021 - 08 - 31).checked_add_months(1),
+        Some(date!(2021 - 09 - 30))
+    );
+    assert_eq!(
+        date!(2021 - 11 - 30).checked_add_months(2),
+        Some(date!(2022 - 01 - 30))
+    );
+    assert_eq!(
+        date!(2021 - 03 - 31).checked_add_months(-1),
+        Some(date!(2021 - 02 - 28))
+    );
+    assert_eq!(
+        date!(2021 - 01 - 01).checked_add_months(-1),
+        Some(date!(2020 - 12 - 01))
+    );
+    assert_eq!(
+        date!(2021 - 01 - 01).checked_add_months(-25),
+        Some(date!(2018 - 12 - 01))
+    );
+
+    assert_eq!(Date::MAX.checked_add_months(0), Some(Date::MAX));
+    assert_eq!(Date::MAX.checked_add_months(1), None);
+    assert_eq!(
+        Date::MAX.checked_add_months(-1),
+        Date::from_calendar_date(Date::MAX.year(), Month::November, 30).ok()
+    );
+    assert_eq!(Date::MIN.checked_add_months(-1), None);
+    assert_eq!(Date::MIN.checked_add_months(i32::MAX), None);
+    assert_eq!(Date::MAX.checked_add_months(i32::MIN), None);
+}
+
+#[test]
+fn checked_sub_months() {
+    assert_eq!(
+        date!(2021 - 03 - 15).checked_sub_months(1),
+        Some(date!(2021 - 02 - 15))
+    );
+    assert_eq!(
+        date!(2021 - 03 - 31).checked_sub_months(1),
+        Some(date!(2021 - 02 - 28))
+    );
+    assert_eq!(
+        date!(2020 - 03 - 31).checked_sub_months(1),
+        Some(date!(2020 - 02 - 29))
+    );
+    assert_eq!(
+        date!(2021 - 01 - 31).checked_sub_months(2),
+        Some(date!(2020 - 11 - 30))
+    );
+    assert_eq!(
+        date!(2021 - 01 - 31).checked_sub_months(-1),
+        Some(date!(2021 - 02 - 28))
+    );
+
+    assert_eq!(Date::MIN.checked_sub_months(0), Some(Date::MIN));
+    assert_eq!(Date::MIN.checked_sub_months(1), None);
+    assert_eq!(Date::MAX.checked_sub_months(-1), None);
+    assert_eq!(Date::MAX.checked_sub_months(i32::MIN), None);
+    assert_eq!(Date::MIN.checked_sub_months(i32::MAX), None);
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -966,6 +1103,23 @@ fn saturating_sub_duration() {
     assert_eq!(Date::MAX.saturating_sub(Duration::ZERO), Date::MAX);
 }
 
+#[test]
+fn clamp() {
+    let (min, max) = (date!(2020 - 01 - 01), date!(2020 - 12 - 31));
+    assert_eq!(date!(2019 - 12 - 31).clamp(min, max), min);
+    assert_eq!(date!(2021 - 01 - 01).clamp(min, max), max);
+    assert_eq!(min.clamp(min, max), min);
+    assert_eq!(max.clamp(min, max), max);
+    assert_eq!(date!(2020 - 06 - 15).clamp(min, max), date!(2020 - 06 - 15));
+    assert_eq!(Date::MIN.clamp(min, min), min);
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = date!(2020 - 01 - 01).clamp(date!(2020 - 01 - 02), date!(2020 - 01 - 01));
+}
+
 #[test]
 fn replace_year() {
     assert_eq!(
@@ -1151,6 +1305,248 @@ fn nth_prev_occurrence_test() {
     );
 }
 
+#[test]
+fn try_from_tuple() {
+    assert_eq!(
+        Date::try_from((2024, Month::March, 14)),
+        Ok(date!(2024 - 03 - 14))
+    );
+    assert_eq!(Date::try_from((2024, 74)), Ok(date!(2024 - 03 - 14)));
+    assert!(matches!(
+        Date::try_from((2023, Month::February, 29)),
+        Err(err) if err.name() == "day"
+    ));
+    assert!(matches!(
+        Date::try_from((2023, 366)),
+        Err(err) if err.name() == "ordinal"
+    ));
+    assert!(matches!(
+        Date::try_from((1_000_000, Month::January, 1)),
+        Err(err) if err.name() == "year"
+    ));
+}
+
+#[test]
+fn next_anniversary() {
+    use Month::*;
+
+    assert_eq!(
+        date!(2023 - 06 - 28).next_anniversary(July, 4),
+        Some(date!(2023 - 07 - 04))
+    );
+    assert_eq!(
+        date!(2023 - 07 - 04).next_anniversary(July, 4),
+        Some(date!(2023 - 07 - 04))
+    );
+    assert_eq!(
+        date!(2023 - 07 - 05).next_anniversary(July, 4),
+        Some(date!(2024 - 07 - 04))
+    );
+    assert_eq!(
+        date!(2023 - 03 - 01).next_anniversary(February, 29),
+        Some(date!(2024 - 02 - 29))
+    );
+    assert_eq!(
+        date!(2024 - 03 - 01).next_anniversary(February, 29),
+        Some(date!(2025 - 02 - 28))
+    );
+    assert_eq!(date!(2023 - 01 - 01).next_anniversary(February, 30), None);
+    assert_eq!(date!(2023 - 01 - 01).next_anniversary(June, 0), None);
+    assert_eq!(Date::MAX.next_anniversary(January, 1), None);
+    assert_eq!(Date::MAX.next_anniversary(December, 31), Some(Date::MAX));
+}
+
+#[test]
+fn previous_anniversary() {
+    use Month::*;
+
+    assert_eq!(
+        date!(2023 - 06 - 28).previous_anniversary(July, 4),
+        Some(date!(2022 - 07 - 04))
+    );
+    assert_eq!(
+        date!(2023 - 07 - 04).previous_anniversary(July, 4),
+        Some(date!(2023 - 07 - 04))
+    );
+    assert_eq!(
+        date!(2023 - 07 - 05).previous_anniversary(July, 4),
+        Some(date!(2023 - 07 - 04))
+    );
+    assert_eq!(
+        date!(2024 - 02 - 28).previous_anniversary(February, 29),
+        Some(date!(2023 - 02 - 28))
+    );
+    assert_eq!(
+        date!(2024 - 02 - 29).previous_anniversary(February, 29),
+        Some(date!(2024 - 02 - 29))
+    );
+    assert_eq!(date!(2023 - 01 - 01).previous_anniversary(April, 31), None);
+    assert_eq!(Date::MIN.previous_anniversary(December, 31), None);
+    assert_eq!(Date::MIN.previous_anniversary(January, 1), Some(Date::MIN));
+}
+
+#[test]
+fn nth_weekday_of_month() {
+    use Month::*;
+    use Weekday::*;
+
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, November, Thursday, 1),
+        Some(date!(2023 - 11 - 02))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, November, Thursday, 4),
+        Some(date!(2023 - 11 - 23))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, November, Thursday, 5),
+        Some(date!(2023 - 11 - 30))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, November, Friday, 4),
+        Some(date!(2023 - 11 - 24))
+    );
+    assert_eq!(Date::nth_weekday_of_month(2023, November, Friday, 5), None);
+    assert_eq!(
+        Date::nth_weekday_of_month(2024, February, Thursday, 5),
+        Some(date!(2024 - 02 - 29))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, February, Thursday, 5),
+        None
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, November, Thursday, 0),
+        None
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2023, November, Thursday, 255),
+        None
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(i32::MAX, January, Monday, 1),
+        None
+    );
+}
+
+#[test]
+fn last_weekday_of_month() {
+    use Month::*;
+    use Weekday::*;
+
+    assert_eq!(
+        Date::last_weekday_of_month(2023, May, Monday),
+        Some(date!(2023 - 05 - 29))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2023, May, Wednesday),
+        Some(date!(2023 - 05 - 31))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2023, November, Friday),
+        Some(date!(2023 - 11 - 24))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2024, February, Thursday),
+        Some(date!(2024 - 02 - 29))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2023, February, Thursday),
+        Some(date!(2023 - 02 - 23))
+    );
+    assert_eq!(Date::last_weekday_of_month(i32::MAX, January, Monday), None);
+}
+
+#[test]
+fn first_day_of_week_of_month() {
+    use Month::*;
+    use Weekday::*;
+
+    // February 2021 begins on a Monday.
+    assert_eq!(
+        Date::first_day_of_week_of_month(2021, February, 1, Monday),
+        Some(date!(2021 - 02 - 01))
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2021, February, 4, Monday),
+        Some(date!(2021 - 02 - 22))
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2021, February, 5, Monday),
+        None
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2021, February, 2, Sunday),
+        Some(date!(2021 - 02 - 07))
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2021, February, 5, Sunday),
+        Some(date!(2021 - 02 - 28))
+    );
+
+    // March 2024 begins on a Friday.
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, March, 2, Monday),
+        Some(date!(2024 - 03 - 04))
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, March, 6, Monday),
+        None
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, March, 6, Sunday),
+        Some(date!(2024 - 03 - 31))
+    );
+
+    // September 2024 begins on a Sunday.
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, September, 2, Monday),
+        Some(date!(2024 - 09 - 02))
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, September, 6, Monday),
+        Some(date!(2024 - 09 - 30))
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, September, 2, Sunday),
+        Some(date!(2024 - 09 - 08))
+    );
+
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, March, 0, Monday),
+        None
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(2024, March, u8::MAX, Monday),
+        None
+    );
+    assert_eq!(
+        Date::first_day_of_week_of_month(i32::MAX, January, 1, Monday),
+        None
+    );
+
+    // Each week is a row of the calendar grid.
+    for month in [January, February, March, September] {
+        for week_start in Monday.all_from() {
+            let grid = Date::calendar_month(2024, month, week_start)
+                .expect("year is in range")
+                .collect::<Vec<_>>();
+            for (index, row) in grid.chunks(7).enumerate() {
+                let week = (index + 1) as u8;
+                assert_eq!(
+                    Date::first_day_of_week_of_month(2024, month, week, week_start),
+                    row.iter().flatten().next().copied()
+                );
+            }
+            let week = (grid.len() / 7 + 1) as u8;
+            assert_eq!(
+                Date::first_day_of_week_of_month(2024, month, week, week_start),
+                None
+            );
+        }
+    }
+}
+
 #[test]
 #[should_panic]
 fn next_occurrence_overflow_test() {
@@ -1186,3 +1582,220 @@ fn nth_prev_occurence_zeroth_occurence_test() {
 fn nth_prev_occurrence_overflow_test() {
     date!(-999999 - 01 - 07).nth_prev_occurrence(Weekday::Sunday, 1);
 }
+
+#[test]
+fn add_business_days() {
+    // Friday across the weekend
+    assert_eq!(
+        date!(2023 - 06 - 09).add_business_days(1),
+        Some(date!(2023 - 06 - 12))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 08).add_business_days(3),
+        Some(date!(2023 - 06 - 13))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 12).add_business_days(-1),
+        Some(date!(2023 - 06 - 09))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 13).add_business_days(-3),
+        Some(date!(2023 - 06 - 08))
+    );
+    // Starting on a weekend
+    assert_eq!(
+        date!(2023 - 06 - 10).add_business_days(1),
+        Some(date!(2023 - 06 - 12))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 11).add_business_days(5),
+        Some(date!(2023 - 06 - 16))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 11).add_business_days(-1),
+        Some(date!(2023 - 06 - 09))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 10).add_business_days(0),
+        Some(date!(2023 - 06 - 10))
+    );
+    // Multiple weeks
+    assert_eq!(
+        date!(2023 - 06 - 07).add_business_days(23),
+        Some(date!(2023 - 07 - 10))
+    );
+    assert_eq!(
+        date!(2023 - 07 - 10).add_business_days(-23),
+        Some(date!(2023 - 06 - 07))
+    );
+    assert_eq!(
+        date!(2023 - 06 - 12).add_business_days(10),
+        Some(date!(2023 - 06 - 26))
+    );
+
+    assert_eq!(Date::MAX.add_business_days(5), None);
+    assert_eq!(Date::MIN.add_business_days(-5), None);
+}
+
+#[test]
+fn business_days_between() {
+    assert_eq!(
+        date!(2023 - 06 - 09).business_days_between(date!(2023 - 06 - 12)),
+        1
+    );
+    assert_eq!(
+        date!(2023 - 06 - 12).business_days_between(date!(2023 - 06 - 09)),
+        -1
+    );
+    assert_eq!(
+        date!(2023 - 06 - 10).business_days_between(date!(2023 - 06 - 11)),
+        0
+    );
+    assert_eq!(
+        date!(2023 - 06 - 07).business_days_between(date!(2023 - 07 - 10)),
+        23
+    );
+    assert_eq!(
+        date!(2023 - 06 - 07).business_days_between(date!(2023 - 06 - 07)),
+        0
+    );
+
+    // Round trip between every pair of business days over several weeks.
+    let start = date!(2023 - 05 - 29);
+    let business_days = (0..60)
+        .map(|n| start + n.days())
+        .filter(|date| !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday))
+        .collect::<Vec<_>>();
+    for &a in &business_days {
+        for &b in &business_days {
+            let n = a.business_days_between(b);
+            assert_eq!(a.add_business_days(n as _), Some(b));
+        }
+    }
+}
+
+#[test]
+fn range() {
+    assert_eq!(
+        Date::range(date!(2020 - 02 - 27), date!(2020 - 03 - 02)).collect::<Vec<_>>(),
+        [
+            date!(2020 - 02 - 27),
+            date!(2020 - 02 - 28),
+            date!(2020 - 02 - 29),
+            date!(2020 - 03 - 01),
+        ]
+    );
+    assert_eq!(
+        Date::range(date!(2020 - 12 - 30), date!(2021 - 01 - 02))
+            .rev()
+            .collect::<Vec<_>>(),
+        [
+            date!(2021 - 01 - 01),
+            date!(2020 - 12 - 31),
+            date!(2020 - 12 - 30),
+        ]
+    );
+    assert_eq!(
+        Date::range(date!(2021 - 01 - 01), date!(2021 - 01 - 01)).next(),
+        None
+    );
+    assert_eq!(
+        Date::range(date!(2021 - 01 - 02), date!(2021 - 01 - 01)).next_back(),
+        None
+    );
+    assert_eq!(
+        Date::range(date!(2021 - 01 - 02), date!(2021 - 01 - 01)).len(),
+        0
+    );
+    assert_eq!(
+        Date::range(date!(2020 - 01 - 01), date!(2021 - 01 - 01)).len(),
+        366
+    );
+
+    let mut range = Date::range(date!(2021 - 01 - 01), date!(2021 - 01 - 05));
+    assert_eq!(range.next(), Some(date!(2021 - 01 - 01)));
+    assert_eq!(range.next_back(), Some(date!(2021 - 01 - 04)));
+    assert_eq!(range.len(), 2);
+    assert_eq!(range.nth(1), Some(date!(2021 - 01 - 03)));
+    assert_eq!(range.len(), 0);
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+
+    let mut range = Date::range(date!(2021 - 01 - 01), date!(2021 - 01 - 05));
+    assert_eq!(range.nth(4), None);
+    assert_eq!(range.next(), None);
+
+    let mut full = Date::range(Date::MIN, Date::MAX);
+    assert_eq!(full.len() as i64, (Date::MAX - Date::MIN).whole_days());
+    assert_eq!(full.next(), Some(Date::MIN));
+    assert_eq!(full.next_back(), Date::MAX.previous_day());
+}
+
+#[test]
+fn calendar_month() {
+    // February 2021 begins on a Monday and has exactly four weeks.
+    let grid = Date::calendar_month(2021, Month::February, Weekday::Monday)
+        .expect("year is in range")
+        .collect::<Vec<_>>();
+    assert_eq!(grid.len(), 28);
+    assert!(grid.iter().all(Option::is_some));
+    assert_eq!(grid[0], Some(date!(2021 - 02 - 01)));
+    assert_eq!(grid[27], Some(date!(2021 - 02 - 28)));
+
+    // With Sunday-first weeks, one blank precedes the first and six follow the last.
+    let grid = Date::calendar_month(2021, Month::February, Weekday::Sunday)
+        .expect("year is in range")
+        .collect::<Vec<_>>();
+    assert_eq!(grid.len(), 35);
+    assert_eq!(grid[0], None);
+    assert_eq!(grid[1], Some(date!(2021 - 02 - 01)));
+    assert_eq!(grid[28], Some(date!(2021 - 02 - 28)));
+    assert!(grid[29..].iter().all(Option::is_none));
+
+    // August 2021 begins on a Sunday and spans six Monday-first weeks.
+    let grid = Date::calendar_month(2021, Month::August, Weekday::Monday)
+        .expect("year is in range")
+        .collect::<Vec<_>>();
+    assert_eq!(grid.len(), 42);
+    assert!(grid[..6].iter().all(Option::is_none));
+    assert_eq!(grid[6], Some(date!(2021 - 08 - 01)));
+    assert_eq!(grid[36], Some(date!(2021 - 08 - 31)));
+    assert!(grid[37..].iter().all(Option::is_none));
+    for (row, week) in grid.chunks(7).enumerate() {
+        for (column, day) in week.iter().enumerate() {
+            if let Some(day) = day {
+                assert_eq!(day.weekday().number_days_from_monday(), column as u8);
+                assert_eq!((*day - date!(2021 - 07 - 26)).whole_weeks(), row as i64);
+            }
+        }
+    }
+
+    let mut grid =
+        Date::calendar_month(2024, Month::February, Weekday::Wednesday).expect("year is in range");
+    assert_eq!(grid.len(), 35);
+    assert_eq!(grid.next(), Some(None));
+    assert_eq!(grid.next(), Some(Some(date!(2024 - 02 - 01))));
+    assert_eq!(grid.next_back(), Some(None));
+    assert_eq!(grid.len(), 32);
+    assert_eq!(grid.rev().flatten().next(), Some(date!(2024 - 02 - 29)));
+
+    assert!(Date::calendar_month(1_000_000, Month::January, Weekday::Monday).is_none());
+}
+
+#[test]
+fn iter_days() {
+    let mut days = date!(2019 - 12 - 31).iter_days();
+    assert_eq!(days.next(), Some(date!(2019 - 12 - 31)));
+    assert_eq!(days.next(), Some(date!(2020 - 01 - 01)));
+    assert_eq!(days.next_back(), Some(Date::MAX));
+
+    let mut days = Date::MAX.iter_days();
+    assert_eq!(days.len(), 1);
+    assert_eq!(days.next(), Some(Date::MAX));
+    assert_eq!(days.next(), None);
+
+    assert_eq!(
+        Date::MIN.iter_days().len() as i64,
+        (Date::MAX - Date::MIN).whole_days() + 1
+    );
+}
@@ -2,7 +2,9 @@ use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 
-use time::error::{self, ConversionRange, IndeterminateOffset, TryFromParsed};
+use time::error::{
+    self, ConversionRange, IndeterminateOffset, IndeterminateOffsetReason, TryFromParsed,
+};
 use time::ext::NumericalDuration;
 use time::format_description::{self, modifier, well_known, Component, BorrowedFormatItem, OwnedFormatItem};
 use time::macros::{date, offset, time};
@@ -41,6 +43,7 @@ fn clone() {
     assert_cloned_eq!(Duration::ZERO);
     assert_cloned_eq!(instant);
     assert_cloned_eq!(IndeterminateOffset);
+    assert_cloned_eq!(IndeterminateOffsetReason::Indeterminate);
     assert_cloned_eq!(ConversionRange);
     assert_cloned_eq!(invalid_format_description());
     assert_cloned_eq!(TryFromParsed::InsufficientInformation);
@@ -83,6 +86,7 @@ fn clone() {
     assert_cloned_eq!(modifier::Second::default());
     assert_cloned_eq!(modifier::SubsecondDigits::default());
     assert_cloned_eq!(modifier::Subsecond::default());
+    assert_cloned_eq!(modifier::SecondsOfDay::default());
     assert_cloned_eq!(modifier::OffsetHour::default());
     assert_cloned_eq!(modifier::OffsetMinute::default());
     assert_cloned_eq!(modifier::OffsetSecond::default());
@@ -149,6 +153,7 @@ fn debug() {
     debug_all! {
         Duration::ZERO;
         IndeterminateOffset;
+        IndeterminateOffsetReason::Indeterminate;
         ConversionRange;
         TryFromParsed::InsufficientInformation;
         Parsed::new();
@@ -184,6 +189,7 @@ fn debug() {
         modifier::Second::default();
         modifier::SubsecondDigits::default();
         modifier::Subsecond::default();
+        modifier::SecondsOfDay::default();
         modifier::OffsetHour::default();
         modifier::OffsetMinute::default();
         modifier::OffsetSecond::default();
@@ -63,8 +63,14 @@ fn abs(#[case] input: Duration, #[case] expected: Duration) {
 #[case(1.seconds(), 1.std_seconds())]
 #[case(0.seconds(), 0.std_seconds())]
 #[case((-1).seconds(), 1.std_seconds())]
+#[case(
+    Duration::MIN,
+    StdDuration::new(i64::MIN.unsigned_abs(), 999_999_999)
+)]
+#[case(Duration::MAX, StdDuration::new(i64::MAX as u64, 999_999_999))]
 fn unsigned_abs(#[case] input: Duration, #[case] expected: StdDuration) {
     assert_eq!(input.unsigned_abs(), expected);
+    assert_eq!(input.abs_std(), expected);
 }
 
 #[rstest]
@@ -282,6 +288,28 @@ fn as_seconds_f64(#[case] seconds: f64) {
     assert_eq!(Duration::seconds_f64(seconds).as_seconds_f64(), seconds);
 }
 
+#[rstest]
+#[case(1.5.seconds(), 1.5)]
+#[case((-1.5).seconds(), -1.5)]
+#[case(Duration::ZERO, 0.)]
+#[case(Duration::seconds(1 << 53), 9_007_199_254_740_992.)]
+#[case(Duration::seconds(-(1 << 53)), -9_007_199_254_740_992.)]
+#[case(Duration::seconds((1 << 53) - 1), 9_007_199_254_740_991.)]
+#[case(Duration::seconds(1 << 54), 18_014_398_509_481_984.)]
+fn as_seconds_f64_exact(#[case] duration: Duration, #[case] expected: f64) {
+    assert_eq!(duration.as_seconds_f64_exact(), Ok(expected));
+}
+
+#[rstest]
+#[case(Duration::seconds((1 << 53) + 1))]
+#[case(Duration::seconds(-(1 << 53) - 1))]
+#[case(Duration::new((1 << 53) - 1, 999_999_999))]
+#[case(Duration::new(-(1 << 53) + 1, -999_999_999))]
+#[case(Duration::MAX)]
+fn as_seconds_f64_exact_err(#[case] duration: Duration) {
+    assert_eq!(duration.as_seconds_f64_exact(), Err(error::ConversionRange));
+}
+
 #[rstest]
 #[case(0.5, Duration::milliseconds(500))]
 #[case(-0.5, Duration::milliseconds(-500))]
@@ -397,6 +425,36 @@ fn nanoseconds(#[case] input: i64, #[case] expected: Duration) {
     assert_eq!(Duration::nanoseconds(input), expected);
 }
 
+#[rstest]
+#[case(Duration::try_weeks, 2, Duration::weeks(2))]
+#[case(Duration::try_days, -2, Duration::days(-2))]
+#[case(Duration::try_hours, 2, Duration::hours(2))]
+#[case(Duration::try_minutes, -2, Duration::minutes(-2))]
+#[case(Duration::try_seconds, i64::MAX, Duration::seconds(i64::MAX))]
+#[case(Duration::try_milliseconds, i64::MIN, Duration::milliseconds(i64::MIN))]
+#[case(Duration::try_microseconds, i64::MAX, Duration::microseconds(i64::MAX))]
+#[case(Duration::try_nanoseconds, i64::MIN, Duration::nanoseconds(i64::MIN))]
+fn try_constructors(
+    #[case] constructor: fn(i64) -> Result<Duration, error::ConversionRange>,
+    #[case] input: i64,
+    #[case] expected: Duration,
+) {
+    assert_eq!(constructor(input), Ok(expected));
+}
+
+#[rstest]
+#[case(Duration::try_weeks, i64::MAX)]
+#[case(Duration::try_weeks, i64::MIN)]
+#[case(Duration::try_days, i64::MAX)]
+#[case(Duration::try_hours, i64::MIN)]
+#[case(Duration::try_minutes, i64::MAX)]
+fn try_constructors_overflow(
+    #[case] constructor: fn(i64) -> Result<Duration, error::ConversionRange>,
+    #[case] input: i64,
+) {
+    assert_eq!(constructor(input), Err(error::ConversionRange));
+}
+
 #[rstest]
 #[case(1.microseconds(), 1_000)]
 #[case((-1).microseconds(), -1_000)]
@@ -413,6 +471,41 @@ fn subsec_nanoseconds(#[case] duration: Duration, #[case] expected: i32) {
     assert_eq!(duration.subsec_nanoseconds(), expected);
 }
 
+#[rstest]
+#[case(1.000_000_4.seconds(), (1, 400))]
+#[case((-1.000_000_4).seconds(), (-1, -400))]
+#[case(Duration::MAX, (i64::MAX, 999_999_999))]
+#[case(Duration::MIN, (i64::MIN, -999_999_999))]
+fn whole_seconds_and_subsec_nanoseconds(#[case] duration: Duration, #[case] expected: (i64, i32)) {
+    assert_eq!(duration.whole_seconds_and_subsec_nanoseconds(), expected);
+}
+
+#[rstest]
+#[case((-1.5).seconds(), -1, -500, -500_000, -500_000_000)]
+#[case(1.5.seconds(), 1, 500, 500_000, 500_000_000)]
+#[case((-1).seconds(), -1, 0, 0, 0)]
+#[case((-1).nanoseconds(), 0, 0, 0, -1)]
+#[case((-999_999_999).nanoseconds(), 0, -999, -999_999, -999_999_999)]
+#[case((-1_000_000_001).nanoseconds(), -1, 0, 0, -1)]
+#[case((-2).seconds() + 1.nanoseconds(), -1, -999, -999_999, -999_999_999)]
+fn subsec_sign_convention(
+    #[case] duration: Duration,
+    #[case] whole_seconds: i64,
+    #[case] milliseconds: i16,
+    #[case] microseconds: i32,
+    #[case] nanoseconds: i32,
+) {
+    assert_eq!(duration.whole_seconds(), whole_seconds);
+    assert_eq!(duration.subsec_milliseconds(), milliseconds);
+    assert_eq!(duration.subsec_microseconds(), microseconds);
+    assert_eq!(duration.subsec_nanoseconds(), nanoseconds);
+    assert_eq!(
+        Duration::new(whole_seconds, nanoseconds),
+        duration,
+        "the parts recompose the duration"
+    );
+}
+
 #[rstest]
 #[case(5.seconds(), 5.seconds(), 10.seconds())]
 #[case((-5).seconds(), 5.seconds(), 0.seconds())]
@@ -612,6 +705,85 @@ fn display_precision(#[case] duration: Duration, #[case] precision: usize, #[cas
     assert_eq!(format!("{duration:.precision$}"), expected);
 }
 
+#[rstest]
+#[case(0.seconds(), "0s", "0s")]
+#[case(1.seconds(), "1s", "1s")]
+#[case(90.minutes(), "1h30m", "1h30m")]
+#[case(5_405.seconds(), "1h30m5s", "1h30m5s")]
+#[case(51.hours(), "2d3h", "2d3h")]
+#[case(93_784.seconds(), "1d2h3m4s", "1d2h3m4s")]
+#[case(10.weeks(), "70d", "70d")]
+#[case((-5_405).seconds(), "-1h30m5s", "-1h30m5s")]
+#[case(1.5.seconds(), "1s", "1.5s")]
+#[case((-1.5).seconds(), "-1s", "-1.5s")]
+#[case(500.milliseconds(), "0s", "0.5s")]
+#[case((-500).milliseconds(), "0s", "-0.5s")]
+#[case(1.nanoseconds(), "0s", "0.000000001s")]
+#[case(61.seconds() + 20.milliseconds(), "1m1s", "1m1.02s")]
+#[case(1.hours() + 123_456_789.nanoseconds(), "1h", "1h0.123456789s")]
+#[case(
+    Duration::MAX,
+    "106751991167300d15h30m7s",
+    "106751991167300d15h30m7.999999999s"
+)]
+#[case(
+    Duration::MIN,
+    "-106751991167300d15h30m8s",
+    "-106751991167300d15h30m8.999999999s"
+)]
+fn format_human(#[case] duration: Duration, #[case] expected: &str, #[case] expected_subsec: &str) {
+    assert_eq!(duration.format_human(), expected);
+    assert_eq!(duration.format_human_subsec(), expected_subsec);
+    assert_eq!(
+        Duration::parse_human(expected),
+        Ok(Duration::seconds(duration.whole_seconds()))
+    );
+    assert_eq!(Duration::parse_human(expected_subsec), Ok(duration));
+}
+
+#[rstest]
+#[case("90m", 90.minutes())]
+#[case("36h", 36.hours())]
+#[case("1d90s", 1.days() + 90.seconds())]
+#[case("0h0s", 0.seconds())]
+#[case("-0s", 0.seconds())]
+#[case("007s", 7.seconds())]
+#[case("1.250s", 1.25.seconds())]
+#[case("1.000000000s", 1.seconds())]
+fn parse_human_success(#[case] input: &str, #[case] expected: Duration) {
+    assert_eq!(Duration::parse_human(input), Ok(expected));
+}
+
+#[rstest]
+#[case("")]
+#[case("-")]
+#[case("5")]
+#[case("s")]
+#[case("1w")]
+#[case("1ms")]
+#[case("1s1m")]
+#[case("1h1h")]
+#[case("1.5h")]
+#[case("1.s")]
+#[case(".5s")]
+#[case("1.0000000001s")]
+#[case("1h 30m")]
+#[case(" 1h")]
+#[case("+1h")]
+#[case("--1s")]
+#[case("1h-30m")]
+#[case("106751991167300d15h30m8s")]
+#[case("-106751991167300d15h30m9s")]
+#[case("99999999999999999999999999999999999999999d")]
+fn parse_human_error(#[case] input: &str) {
+    assert!(matches!(
+        Duration::parse_human(input),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("duration")
+        ))
+    ));
+}
+
 #[rstest]
 #[case(0.std_seconds(), 0.seconds())]
 #[case(1.std_seconds(), 1.seconds())]
@@ -628,6 +800,34 @@ fn try_from_std_duration_error(
     assert_eq!(Duration::try_from(std_duration), Err(expected));
 }
 
+#[rstest]
+#[case(0.std_seconds(), 0.seconds())]
+#[case(StdDuration::new(1, 500_000_000), 1.5.seconds())]
+#[case(StdDuration::new(i64::MAX as u64, 999_999_999), Duration::MAX)]
+fn try_from_std(#[case] std_duration: StdDuration, #[case] expected: Duration) {
+    assert_eq!(Duration::try_from_std(std_duration), Ok(expected));
+}
+
+#[rstest]
+#[case(StdDuration::new(i64::MAX as u64 + 1, 0))]
+#[case(StdDuration::MAX)]
+fn try_from_std_error(#[case] std_duration: StdDuration) {
+    assert_eq!(
+        Duration::try_from_std(std_duration),
+        Err(error::ConversionRange)
+    );
+}
+
+#[rstest]
+#[case(0.std_seconds(), 0.seconds())]
+#[case(StdDuration::new(1, 500_000_000), 1.5.seconds())]
+#[case(StdDuration::new(i64::MAX as u64, 999_999_999), Duration::MAX)]
+#[case(StdDuration::new(i64::MAX as u64 + 1, 0), Duration::MAX)]
+#[case(StdDuration::MAX, Duration::MAX)]
+fn saturating_from_std(#[case] std_duration: StdDuration, #[case] expected: Duration) {
+    assert_eq!(Duration::saturating_from_std(std_duration), expected);
+}
+
 #[rstest]
 #[case(0.seconds(), 0.std_seconds())]
 #[case(1.seconds(), 1.std_seconds())]
@@ -846,6 +1046,203 @@ fn std_div(#[case] lhs: StdDuration, #[case] rhs: Duration, #[case] expected: f6
     assert_eq!(lhs / rhs, expected);
 }
 
+#[rstest]
+#[case(1.seconds(), 0.5.seconds(), 2.)]
+#[case(2.seconds(), 0.25.seconds(), 8.)]
+#[case((-3).seconds(), 2.seconds(), -1.5)]
+#[case(1.nanoseconds(), 2.nanoseconds(), 0.5)]
+#[case(Duration::MAX, Duration::MAX, 1.)]
+#[case(1.seconds(), 0.seconds(), f64::INFINITY)]
+#[case((-1).seconds(), 0.seconds(), f64::NEG_INFINITY)]
+#[allow(clippy::float_cmp)]
+fn div_duration_f64(#[case] lhs: Duration, #[case] rhs: Duration, #[case] expected: f64) {
+    assert_eq!(lhs.div_duration_f64(rhs), expected);
+}
+
+#[rstest]
+#[case(1.seconds(), 0.5.seconds(), 2.)]
+#[case(2.seconds(), 0.25.seconds(), 8.)]
+#[case((-3).seconds(), 2.seconds(), -1.5)]
+#[case(1.nanoseconds(), 2.nanoseconds(), 0.5)]
+#[case(Duration::MAX, Duration::MAX, 1.)]
+#[case(1.seconds(), 0.seconds(), f32::INFINITY)]
+#[case((-1).seconds(), 0.seconds(), f32::NEG_INFINITY)]
+#[allow(clippy::float_cmp)]
+fn div_duration_f32(#[case] lhs: Duration, #[case] rhs: Duration, #[case] expected: f32) {
+    assert_eq!(lhs.div_duration_f32(rhs), expected);
+}
+
+#[rstest]
+fn div_duration_zero() {
+    assert!(0.seconds().div_duration_f64(0.seconds()).is_nan());
+    assert!(0.seconds().div_duration_f32(0.seconds()).is_nan());
+}
+
+#[rstest]
+#[case(45.minutes(), 15.minutes(), Some((3, 0.minutes())))]
+#[case(50.minutes(), 15.minutes(), Some((3, 5.minutes())))]
+#[case((-45).minutes(), 15.minutes(), Some((-3, 0.minutes())))]
+#[case((-50).minutes(), 15.minutes(), Some((-4, 10.minutes())))]
+#[case(50.minutes(), (-15).minutes(), Some((-3, 5.minutes())))]
+#[case((-50).minutes(), (-15).minutes(), Some((4, 10.minutes())))]
+#[case(1.seconds(), 1.seconds() - 1.nanoseconds(), Some((1, 1.nanoseconds())))]
+#[case(1.seconds() - 1.nanoseconds(), 1.seconds(), Some((0, 999_999_999.nanoseconds())))]
+#[case(
+    (-1).nanoseconds(),
+    1.seconds(),
+    Some((-1, 999_999_999.nanoseconds()))
+)]
+#[case(Duration::MAX, Duration::MAX, Some((1, Duration::ZERO)))]
+#[case(Duration::MIN, Duration::MAX, Some((-2, Duration::MAX - 1.seconds())))]
+#[case(Duration::MAX, 1.seconds(), Some((i64::MAX, 999_999_999.nanoseconds())))]
+#[case(Duration::MAX, 1.nanoseconds(), None)]
+#[case(1.seconds(), Duration::ZERO, None)]
+fn div_mod(
+    #[case] lhs: Duration,
+    #[case] rhs: Duration,
+    #[case] expected: Option<(i64, Duration)>,
+) {
+    assert_eq!(lhs.div_mod(rhs), expected);
+}
+
+#[rstest]
+#[case(1.seconds(), 0.5, 500.milliseconds())]
+#[case(1.seconds(), 1.5, 1_500.milliseconds())]
+#[case(2.seconds(), -1.25, (-2.5).seconds())]
+#[case(1.nanoseconds(), 0.5, 1.nanoseconds())]
+#[case((-1).nanoseconds(), 0.5, (-1).nanoseconds())]
+#[case(1.nanoseconds(), 0.4, Duration::ZERO)]
+#[case(1.seconds(), 0., Duration::ZERO)]
+fn checked_mul_f64_some(#[case] duration: Duration, #[case] rhs: f64, #[case] expected: Duration) {
+    assert_eq!(duration.mul_f64(rhs), expected);
+    assert_eq!(duration.checked_mul_f64(rhs), Some(expected));
+}
+
+#[rstest]
+#[case(Duration::MAX, 2.)]
+#[case(Duration::MIN, 2.)]
+#[case(1.seconds(), 1e30)]
+#[case(1.seconds(), f64::NAN)]
+#[case(1.seconds(), f64::INFINITY)]
+#[case(1.seconds(), f64::NEG_INFINITY)]
+fn checked_mul_f64_none(#[case] duration: Duration, #[case] rhs: f64) {
+    assert_eq!(duration.checked_mul_f64(rhs), None);
+}
+
+#[rstest]
+#[case(1.seconds(), 2., 500.milliseconds())]
+#[case(1.seconds(), -0.5, (-2).seconds())]
+#[case(3.nanoseconds(), 2., 2.nanoseconds())]
+#[case(1.seconds(), f64::INFINITY, Duration::ZERO)]
+fn checked_div_f64_some(#[case] duration: Duration, #[case] rhs: f64, #[case] expected: Duration) {
+    assert_eq!(duration.div_f64(rhs), expected);
+    assert_eq!(duration.checked_div_f64(rhs), Some(expected));
+}
+
+#[rstest]
+#[case(Duration::MAX, 0.5)]
+#[case(1.seconds(), 0.)]
+#[case(Duration::ZERO, 0.)]
+#[case(1.seconds(), f64::NAN)]
+fn checked_div_f64_none(#[case] duration: Duration, #[case] rhs: f64) {
+    assert_eq!(duration.checked_div_f64(rhs), None);
+}
+
+#[test]
+#[should_panic = "overflow when multiplying duration by float"]
+fn mul_f64_overflow() {
+    let _ = Duration::MAX.mul_f64(2.);
+}
+
+#[test]
+#[should_panic = "overflow when dividing duration by float"]
+fn div_f64_overflow() {
+    let _ = 1.seconds().div_f64(0.);
+}
+
+#[rstest]
+#[case(7.minutes(), 15.minutes(), 0.minutes())]
+#[case(450.seconds(), 15.minutes(), 15.minutes())]
+#[case((-450).seconds(), 15.minutes(), (-15).minutes())]
+#[case((-449).seconds(), 15.minutes(), 0.minutes())]
+#[case(22.minutes(), 15.minutes(), 15.minutes())]
+#[case((-23).minutes(), 15.minutes(), (-30).minutes())]
+#[case(1_500.milliseconds(), 1.seconds(), 2.seconds())]
+#[case((-1_500).milliseconds(), 1.seconds(), (-2).seconds())]
+#[case(15.minutes(), 15.minutes(), 15.minutes())]
+#[case(0.seconds(), 1.seconds(), 0.seconds())]
+#[case(Duration::MAX, 1.nanoseconds(), Duration::MAX)]
+#[case(Duration::MIN, 1.nanoseconds(), Duration::MIN)]
+fn round_to(#[case] duration: Duration, #[case] granularity: Duration, #[case] expected: Duration) {
+    assert_eq!(duration.round_to(granularity), expected);
+}
+
+#[rstest]
+#[case(14.minutes(), 15.minutes(), 0.minutes())]
+#[case((-14).minutes(), 15.minutes(), 0.minutes())]
+#[case(16.minutes(), 15.minutes(), 15.minutes())]
+#[case((-16).minutes(), 15.minutes(), (-15).minutes())]
+#[case(15.minutes(), 15.minutes(), 15.minutes())]
+#[case(Duration::MIN, 1.seconds(), Duration::MIN + 999_999_999.nanoseconds())]
+fn round_down_to(
+    #[case] duration: Duration,
+    #[case] granularity: Duration,
+    #[case] expected: Duration,
+) {
+    assert_eq!(duration.round_down_to(granularity), expected);
+}
+
+#[rstest]
+#[case(1.minutes(), 15.minutes(), 15.minutes())]
+#[case((-1).minutes(), 15.minutes(), (-15).minutes())]
+#[case(16.minutes(), 15.minutes(), 30.minutes())]
+#[case((-16).minutes(), 15.minutes(), (-30).minutes())]
+#[case(15.minutes(), 15.minutes(), 15.minutes())]
+#[case(0.seconds(), 15.minutes(), 0.seconds())]
+fn round_up_to(
+    #[case] duration: Duration,
+    #[case] granularity: Duration,
+    #[case] expected: Duration,
+) {
+    assert_eq!(duration.round_up_to(granularity), expected);
+}
+
+#[rstest]
+#[case(Duration::ZERO)]
+#[case((-1).seconds())]
+#[should_panic]
+fn round_to_panic(#[case] granularity: Duration) {
+    let _ = 1.seconds().round_to(granularity);
+}
+
+#[rstest]
+#[should_panic]
+fn round_up_to_panic() {
+    let _ = Duration::MAX.round_up_to(1.seconds());
+}
+
+#[rstest]
+#[case(90.seconds(), 0.seconds(), 1.minutes(), 1.minutes())]
+#[case((-5).seconds(), 0.seconds(), 1.minutes(), 0.seconds())]
+#[case(30.seconds(), 0.seconds(), 1.minutes(), 30.seconds())]
+#[case(0.seconds(), 0.seconds(), 0.seconds(), 0.seconds())]
+#[case(Duration::MAX, Duration::MIN, Duration::MAX, Duration::MAX)]
+#[case(1.nanoseconds(), (-1).seconds(), 0.seconds(), 0.seconds())]
+fn clamp(
+    #[case] duration: Duration,
+    #[case] min: Duration,
+    #[case] max: Duration,
+    #[case] expected: Duration,
+) {
+    assert_eq!(duration.clamp(min, max), expected);
+}
+
+#[rstest]
+#[should_panic]
+fn clamp_panic() {
+    let _ = 0.seconds().clamp(1.seconds(), 0.seconds());
+}
+
 #[rstest]
 #[case(1.seconds(), 1.5, 1_500.milliseconds())]
 #[case(1.seconds(), 2.5, 2_500.milliseconds())]
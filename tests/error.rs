@@ -3,7 +3,8 @@ use std::io;
 
 use time::error::{
     ComponentRange, ConversionRange, DifferentVariant, Error, Format, IndeterminateOffset,
-    InvalidFormatDescription, InvalidVariant, Parse, ParseFromDescription, TryFromParsed,
+    IndeterminateOffsetReason, InvalidFormatDescription, InvalidVariant, Parse,
+    ParseFromDescription, TryFromParsed,
 };
 use time::macros::format_description;
 use time::parsing::Parsed;
@@ -40,6 +41,12 @@ fn insufficient_type_information() -> Format {
         .expect_err("missing date and UTC offset")
 }
 
+fn buffer_too_small() -> Format {
+    Time::MIDNIGHT
+        .format_into_slice(&mut [0; 2], format_description!("[hour]:[minute]"))
+        .expect_err("buffer is too small")
+}
+
 fn unexpected_trailing_characters() -> Parse {
     Time::parse("a", format_description!("")).expect_err("should fail to parse")
 }
@@ -70,6 +77,11 @@ fn display() {
     assert_display_eq!(component_range(), Error::from(component_range()));
     assert_display_eq!(component_range(), TryFromParsed::from(component_range()));
     assert_display_eq!(IndeterminateOffset, Error::from(IndeterminateOffset));
+    assert_display_eq!(
+        IndeterminateOffsetReason::Indeterminate,
+        IndeterminateOffset
+    );
+    assert_display_eq!(buffer_too_small(), Error::from(buffer_too_small()));
     assert_display_eq!(
         TryFromParsed::InsufficientInformation,
         Error::from(TryFromParsed::InsufficientInformation)
@@ -120,6 +132,7 @@ fn source() {
     assert_source!(TryFromParsed::InsufficientInformation, None);
     assert_source!(insufficient_type_information(), None);
     assert_source!(Format::InvalidComponent("a"), None);
+    assert_source!(buffer_too_small(), None);
     assert_source!(Error::from(insufficient_type_information()), Format);
     assert_source!(Error::from(IndeterminateOffset), IndeterminateOffset);
     assert_source!(
@@ -57,6 +57,45 @@ mod numerical_duration {
     }
 }
 
+mod try_numerical_duration {
+    use time::error::ConversionRange;
+    use time::ext::TryNumericalDuration;
+    use time::Duration;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(5.try_nanoseconds(), Ok(Duration::nanoseconds(5)));
+        assert_eq!(5.try_microseconds(), Ok(Duration::microseconds(5)));
+        assert_eq!(5.try_milliseconds(), Ok(Duration::milliseconds(5)));
+        assert_eq!(5.try_seconds(), Ok(Duration::seconds(5)));
+        assert_eq!(5.try_minutes(), Ok(Duration::minutes(5)));
+        assert_eq!(5.try_hours(), Ok(Duration::hours(5)));
+        assert_eq!(5.try_days(), Ok(Duration::days(5)));
+        assert_eq!(5.try_weeks(), Ok(Duration::weeks(5)));
+        assert_eq!((-5).try_weeks(), Ok(Duration::weeks(-5)));
+        assert_eq!(i64::MAX.try_seconds(), Ok(Duration::seconds(i64::MAX)));
+        assert_eq!(
+            i64::MIN.try_nanoseconds(),
+            Ok(Duration::nanoseconds(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(i64::MAX.try_minutes(), Err(ConversionRange));
+        assert_eq!(i64::MAX.try_hours(), Err(ConversionRange));
+        assert_eq!(i64::MAX.try_days(), Err(ConversionRange));
+        assert_eq!(i64::MAX.try_weeks(), Err(ConversionRange));
+        assert_eq!(i64::MIN.try_minutes(), Err(ConversionRange));
+        assert_eq!(i64::MIN.try_weeks(), Err(ConversionRange));
+        assert_eq!(
+            (i64::MAX / 604_800).try_weeks(),
+            Ok(Duration::weeks(i64::MAX / 604_800))
+        );
+        assert_eq!((i64::MAX / 604_800 + 1).try_weeks(), Err(ConversionRange));
+    }
+}
+
 mod numerical_std_duration {
     use std::time::Duration as StdDuration;
 
@@ -66,3 +66,128 @@ fn owned_format_item_equality() {
         compound
     );
 }
+
+#[test]
+fn builder() -> time::Result<()> {
+    use time::format_description::modifier::{
+        MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+    };
+    use time::format_description::{self as fd, FormatDescriptionBuilder};
+
+    assert!(FormatDescriptionBuilder::new().build().is_empty());
+    assert_eq!(
+        FormatDescriptionBuilder::default(),
+        FormatDescriptionBuilder::new()
+    );
+
+    let built = FormatDescriptionBuilder::new()
+        .weekday(WeekdayRepr::Short)
+        .literal(" ")
+        .year(YearRepr::Full, Padding::Zero)
+        .literal("-")
+        .month(MonthRepr::Numerical, Padding::Zero)
+        .literal("-")
+        .day(Padding::Space)
+        .literal(" ")
+        .ordinal(Padding::None)
+        .literal(" ")
+        .week_number(WeekNumberRepr::Iso, Padding::Zero)
+        .literal(" ")
+        .hour(true, Padding::Zero)
+        .literal(":")
+        .minute(Padding::Zero)
+        .literal(":")
+        .second(Padding::Zero)
+        .literal(".")
+        .subsecond(SubsecondDigits::Three)
+        .literal(" ")
+        .period(false)
+        .literal(" ")
+        .offset_hour(true, Padding::Zero)
+        .literal(":")
+        .offset_minute(Padding::Zero)
+        .literal(":")
+        .offset_second(Padding::Zero)
+        .optional(|b| b.literal("Z"))
+        .build();
+    let parsed = fd::parse_owned::<2>(
+        "[weekday repr:short] [year]-[month]-[day padding:space] [ordinal padding:none] \
+         [week_number] [hour repr:12]:[minute]:[second].[subsecond digits:3] [period case:lower] \
+         [offset_hour sign:mandatory]:[offset_minute]:[offset_second][optional [Z]]",
+    )?;
+    assert_eq!(OwnedFormatItem::from(built), parsed);
+
+    assert_eq!(
+        FormatDescriptionBuilder::new()
+            .optional(|b| b.literal("T").hour(false, Padding::Zero))
+            .build(),
+        vec![fd::parse_owned::<2>("[optional [T[hour]]]")?]
+    );
+
+    let year = Component::Year(modifier::Year::default());
+    assert_eq!(
+        FormatDescriptionBuilder::new()
+            .component(year)
+            .item(BorrowedFormatItem::Literal(b"-"))
+            .build(),
+        vec![
+            OwnedFormatItem::Component(year),
+            OwnedFormatItem::Literal(Box::new(*b"-")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn requirements() -> time::Result<()> {
+    use time::format_description as fd;
+    use time::macros::format_description;
+
+    let year = Component::Year(modifier::Year::default());
+    let month = Component::Month(modifier::Month::default());
+    let day = Component::Day(modifier::Day::default());
+    let hour = Component::Hour(modifier::Hour::default());
+    let minute = Component::Minute(modifier::Minute::default());
+    let offset_hour = Component::OffsetHour(modifier::OffsetHour::default());
+
+    let requirements = BorrowedFormatItem::Compound(format_description!(
+        version = 2,
+        "[year]-[month]-[day][optional [T[hour]:[minute]]]"
+    ))
+    .requirements();
+    assert!(requirements.is_required(year));
+    assert!(requirements.is_required(month));
+    assert!(requirements.is_required(day));
+    assert!(!requirements.is_optional(day));
+    assert!(requirements.is_optional(hour));
+    assert!(requirements.is_optional(minute));
+    assert!(!requirements.is_required(offset_hour));
+    assert!(!requirements.is_optional(offset_hour));
+
+    // Modifiers are ignored.
+    let requirements = fd::parse_owned::<2>("[year repr:last_two]")?.requirements();
+    assert!(requirements.is_required(year));
+
+    // A component is only required if it is required by every alternative.
+    let requirements =
+        fd::parse_owned::<2>("[first [[hour]:[minute]] [[hour padding:none]h]]")?.requirements();
+    assert!(requirements.is_required(hour));
+    assert!(requirements.is_optional(minute));
+
+    // Padding does not affect whether a component is required.
+    let requirements = OwnedFormatItem::Padded(day, modifier::MinWidth::default()).requirements();
+    assert!(requirements.is_required(day));
+
+    assert!(!BorrowedFormatItem::First(&[])
+        .requirements()
+        .is_required(year));
+    assert!(!BorrowedFormatItem::First(&[])
+        .requirements()
+        .is_optional(year));
+    assert!(!BorrowedFormatItem::Literal(b"a")
+        .requirements()
+        .is_optional(year));
+
+    Ok(())
+}
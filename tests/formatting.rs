@@ -1,8 +1,9 @@
+use std::fmt;
 use std::io;
 use std::num::NonZeroU8;
 
 use time::format_description::well_known::iso8601::{DateKind, OffsetPrecision, TimePrecision};
-use time::format_description::well_known::{iso8601, Iso8601, Rfc2822, Rfc3339};
+use time::format_description::well_known::{iso8601, HttpDate, Iso8601, Rfc2822, Rfc3339};
 use time::format_description::{self, BorrowedFormatItem, OwnedFormatItem};
 use time::macros::{date, datetime, format_description as fd, offset, time};
 use time::{OffsetDateTime, Time};
@@ -34,6 +35,45 @@ fn rfc_2822() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!(1994-11-06 08:49:37 UTC).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.999 UTC).format(&HttpDate)?,
+        "Sat, 02 Jan 2021 03:04:05 GMT"
+    );
+    assert_eq!(
+        datetime!(2021-01-01 22:04:05 -06:07).format(&HttpDate)?,
+        "Sat, 02 Jan 2021 04:11:05 GMT"
+    );
+    assert_eq!(
+        datetime!(0000-01-01 00:00 UTC).format(&HttpDate)?,
+        "Sat, 01 Jan 0000 00:00:00 GMT"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-12-31 23:59:59 UTC).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(0000-01-01 00:30 +01:00).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(+10_000-01-01 00:00 UTC).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05).format(&HttpDate),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -97,6 +137,61 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_3339_max_subsecond_digits() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC).format(&Rfc3339.max_subsecond_digits(3))?,
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC).format(&Rfc3339)?,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3339_subsecond_digits() -> time::Result<()> {
+    let dt = datetime!(2021-01-02 03:04:05.123_456_789 UTC);
+    assert_eq!(
+        dt.format(&Rfc3339.subsecond_digits(9))?,
+        "2021-01-02T03:04:05.123456789Z"
+    );
+    assert_eq!(
+        dt.format(&Rfc3339.subsecond_digits(6))?,
+        "2021-01-02T03:04:05.123456Z"
+    );
+    assert_eq!(
+        dt.format(&Rfc3339.subsecond_digits(1))?,
+        "2021-01-02T03:04:05.1Z"
+    );
+    assert_eq!(
+        dt.format(&Rfc3339.subsecond_digits(0))?,
+        "2021-01-02T03:04:05Z"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 -01:30).format(&Rfc3339.subsecond_digits(9))?,
+        "2021-01-02T03:04:05.000000000-01:30"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.000_001 UTC).format(&Rfc3339.subsecond_digits(3))?,
+        "2021-01-02T03:04:05.000Z"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.5 UTC).format(&Rfc3339.subsecond_digits(4))?,
+        "2021-01-02T03:04:05.5000Z"
+    );
+    assert!(matches!(
+        datetime!(+10_000-01-02 03:04:05 UTC).format(&Rfc3339.subsecond_digits(3)),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn rfc_3339_subsecond_digits_panic() {
+    let _ = Rfc3339.subsecond_digits(10);
+}
+
 #[test]
 fn iso_8601() -> time::Result<()> {
     macro_rules! assert_format_config {
@@ -280,6 +375,8 @@ fn format_time() -> time::Result<()> {
         (fd!("[subsecond digits:8]"), "45678901"),
         (fd!("[subsecond digits:9]"), "456789012"),
         (fd!("[subsecond digits:1+]"), "456789012"),
+        (fd!("[seconds_of_day]"), "46923"),
+        (fd!("[seconds_of_day padding:none]"), "46923"),
     ];
 
     for &(format_description, output) in &format_output {
@@ -375,9 +472,14 @@ fn format_date() -> time::Result<()> {
         (fd!("[month]"), "12"),
         (fd!("[month repr:short]"), "Dec"),
         (fd!("[month repr:long]"), "December"),
+        (fd!("[month repr:short case:upper]"), "DEC"),
+        (fd!("[month repr:long case:lower]"), "december"),
         (fd!("[ordinal]"), "365"),
         (fd!("[weekday]"), "Tuesday"),
         (fd!("[weekday repr:short]"), "Tue"),
+        (fd!("[weekday repr:short case:upper]"), "TUE"),
+        (fd!("[weekday repr:short case:lower]"), "tue"),
+        (fd!("[weekday case:title]"), "Tuesday"),
         (fd!("[weekday repr:sunday]"), "3"),
         (fd!("[weekday repr:sunday one_indexed:false]"), "2"),
         (fd!("[weekday repr:monday]"), "2"),
@@ -778,6 +880,116 @@ fn end() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn era() -> time::Result<()> {
+    let format = fd!("[year era:true] [era]");
+    assert_eq!(date!(2019 - 12 - 31).format(&format)?, "2019 AD");
+    assert_eq!(date!(0001 - 01 - 01).format(&format)?, "0001 AD");
+    assert_eq!(date!(0000 - 12 - 31).format(&format)?, "0001 BC");
+    assert_eq!(date!(-0001 - 12 - 31).format(&format)?, "0002 BC");
+    assert_eq!(date!(-0044 - 03 - 15).format(&format)?, "0045 BC");
+    assert_eq!(
+        date!(0000 - 01 - 01).format(fd!("[year era:true padding:none] [era case:lower]"))?,
+        "1 bc"
+    );
+    assert_eq!(
+        date!(-0099 - 01 - 01).format(fd!("[year era:true repr:last_two]"))?,
+        "00"
+    );
+    assert_eq!(
+        date!(0000 - 01 - 01).format(fd!("[year era:true sign:mandatory]"))?,
+        "0001"
+    );
+    assert_eq!(date!(0000 - 01 - 01).format(fd!("[year]"))?, "0000");
+
+    Ok(())
+}
+
+#[test]
+fn min_width() -> time::Result<()> {
+    let date = date!(2019 - 05 - 03);
+
+    assert_eq!(
+        date.format(fd!("[month repr:long min_width:9]|"))?,
+        "      May|"
+    );
+    assert_eq!(
+        date.format(fd!("[month repr:long min_width:9 align:left fill:.]|"))?,
+        "May......|"
+    );
+    assert_eq!(date.format(fd!("[weekday min_width:3 fill:*]"))?, "Friday");
+    assert_eq!(
+        date.format(fd!("[day padding:none min_width:4 fill:0]"))?,
+        "0003"
+    );
+    assert_eq!(date.format(fd!("[day fill:*]"))?, "03");
+    assert_eq!(
+        date.format(&format_description::parse(
+            "[month repr:short min_width:5 align:right fill:-]"
+        )?)?,
+        "--May"
+    );
+    assert_eq!(
+        date.format(&format_description::parse_owned::<2>(
+            "[month repr:short min_width:5 align:left]"
+        )?)?,
+        "May  "
+    );
+    assert_eq!(
+        time!(13:02).format(fd!("[hour repr:12 padding:none min_width:2]:[minute]"))?,
+        " 1:02"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn quarter() -> time::Result<()> {
+    let format = fd!("[year]-Q[quarter]");
+    assert_eq!(date!(2019 - 01 - 01).format(&format)?, "2019-Q1");
+    assert_eq!(date!(2019 - 03 - 31).format(&format)?, "2019-Q1");
+    assert_eq!(date!(2019 - 04 - 01).format(&format)?, "2019-Q2");
+    assert_eq!(date!(2019 - 09 - 30).format(&format)?, "2019-Q3");
+    assert_eq!(date!(2019 - 12 - 31).format(&format)?, "2019-Q4");
+    assert_eq!(
+        date!(2019 - 05 - 01).format(fd!("[quarter repr:numerical]"))?,
+        "2"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fractional_hour_and_minute() -> time::Result<()> {
+    assert_eq!(
+        time!(12:30:30).format(fd!("[hour]:[minute fraction:1]"))?,
+        "12:30.5"
+    );
+    assert_eq!(
+        time!(12:30:20).format(fd!("[hour]:[minute fraction:3]"))?,
+        "12:30.333"
+    );
+    assert_eq!(
+        time!(12:30:15).format(fd!("[hour]:[minute fraction:1+]"))?,
+        "12:30.25"
+    );
+    assert_eq!(
+        time!(12:30).format(fd!("[hour]:[minute fraction:1+]"))?,
+        "12:30.0"
+    );
+    assert_eq!(
+        time!(12:30:00.000_000_001).format(fd!("[hour]:[minute fraction:9]"))?,
+        "12:30.000000000"
+    );
+    assert_eq!(time!(12:45).format(fd!("[hour fraction:2]"))?, "12.75");
+    assert_eq!(
+        time!(18:06:36).format(fd!("[hour repr:12 fraction:1+] [period]"))?,
+        "06.11 PM"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn unix_timestamp() -> time::Result<()> {
     let dt = datetime!(2009-02-13 23:31:30.123456789 UTC);
@@ -806,3 +1018,105 @@ fn unix_timestamp() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn format_into_fmt() -> time::Result<()> {
+    struct Wrapper(OffsetDateTime);
+
+    impl fmt::Display for Wrapper {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("at ")?;
+            self.0
+                .format_into_fmt(f, &Rfc3339)
+                .map_err(|_| fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    struct FailingWriter;
+
+    impl fmt::Write for FailingWriter {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    let mut buf = String::new();
+    assert_eq!(
+        date!(2021 - 01 - 02).format_into_fmt(&mut buf, fd!("[year]-[month]-[day]"))?,
+        10
+    );
+    assert_eq!(
+        time!(3:04:05).format_into_fmt(&mut buf, fd!(" [hour]:[minute]"))?,
+        6
+    );
+    assert_eq!(
+        offset!(+6:07).format_into_fmt(&mut buf, fd!(" [offset_hour sign:mandatory]"))?,
+        4
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05).format_into_fmt(&mut buf, fd!(" [ordinal]T[second]"))?,
+        7
+    );
+    assert_eq!(buf, "2021-01-02 03:04 +06 002T05");
+
+    let value = datetime!(2021-01-02 03:04:05 +06:07);
+    assert_eq!(
+        Wrapper(value).to_string(),
+        format!("at {}", value.format(&Rfc3339)?)
+    );
+
+    assert!(matches!(
+        value.format_into_fmt(&mut FailingWriter, &Rfc3339),
+        Err(time::error::Format::StdIo(_))
+    ));
+
+    let mut buf = String::new();
+    assert!(matches!(
+        Time::MIDNIGHT.format_into_fmt(&mut buf, &BorrowedFormatItem::Literal(b"\xFF")),
+        Err(time::error::Format::StdIo(err)) if err.kind() == io::ErrorKind::InvalidData
+    ));
+    assert_eq!(buf, "");
+
+    Ok(())
+}
+
+#[test]
+fn format_into_slice() -> time::Result<()> {
+    let mut buf = [0; 32];
+    let len = date!(2021 - 01 - 02).format_into_slice(&mut buf, fd!("[year]-[month]-[day]"))?;
+    assert_eq!(&buf[..len], b"2021-01-02");
+    let len = time!(3:04:05).format_into_slice(&mut buf, fd!("[hour]:[minute]"))?;
+    assert_eq!(&buf[..len], b"03:04");
+    let len = offset!(+6:07).format_into_slice(&mut buf, fd!("[offset_hour sign:mandatory]"))?;
+    assert_eq!(&buf[..len], b"+06");
+    let len =
+        datetime!(2021-01-02 03:04:05).format_into_slice(&mut buf, fd!("[ordinal]T[second]"))?;
+    assert_eq!(&buf[..len], b"002T05");
+    let len = date!(2021 - 01 - 02).format_into_slice(&mut buf, fd!("[day min_width:4 fill:*]"))?;
+    assert_eq!(&buf[..len], b"**02");
+
+    let value = datetime!(2021-01-02 03:04:05 +06:07);
+    let len = value.format_into_slice(&mut buf, &Rfc3339)?;
+    assert_eq!(&buf[..len], value.format(&Rfc3339)?.as_bytes());
+    assert_eq!(value.format_into_slice(&mut buf[..len], &Rfc3339)?, len);
+
+    assert!(matches!(
+        value.format_into_slice(&mut buf[..len - 1], &Rfc3339),
+        Err(time::error::Format::BufferTooSmall { .. })
+    ));
+    assert!(matches!(
+        date!(2021 - 01 - 02).format_into_slice(&mut [], fd!("[year]")),
+        Err(time::error::Format::BufferTooSmall { .. })
+    ));
+    assert!(matches!(
+        date!(2021 - 01 - 02).format_into_slice(&mut buf[..3], fd!("[day min_width:4 fill:*]")),
+        Err(time::error::Format::BufferTooSmall { .. })
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format_into_slice(&mut buf, &Rfc3339),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
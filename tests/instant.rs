@@ -14,6 +14,24 @@ fn elapsed() {
     assert!(instant.elapsed() >= 1.milliseconds());
 }
 
+#[test]
+fn checked_duration_since() {
+    let now = Instant::now();
+    let later = now + 5.seconds();
+    assert_eq!(now.checked_duration_since(now), Some(Duration::ZERO));
+    assert_eq!(later.checked_duration_since(now), Some(5.seconds()));
+    assert_eq!(now.checked_duration_since(later), None);
+}
+
+#[test]
+fn saturating_duration_since() {
+    let now = Instant::now();
+    let later = now + 5.seconds();
+    assert_eq!(now.saturating_duration_since(now), Duration::ZERO);
+    assert_eq!(later.saturating_duration_since(now), 5.seconds());
+    assert_eq!(now.saturating_duration_since(later), Duration::ZERO);
+}
+
 #[test]
 fn checked_add() {
     let now = Instant::now();
@@ -162,6 +162,27 @@ fn first() {
     );
 }
 
+#[rstest]
+fn comment() {
+    assert_eq!(
+        format_description!("[# the year ][year][#]"),
+        &[BorrowedFormatItem::Component(Component::Year(
+            Default::default()
+        ))]
+    );
+    assert_eq!(
+        format_description!(
+            version = 2,
+            "[year][#comment]-[# [nested] brackets ][month]"
+        ),
+        &[
+            BorrowedFormatItem::Component(Component::Year(Default::default())),
+            BorrowedFormatItem::Literal(b"-"),
+            BorrowedFormatItem::Component(Component::Month(Default::default())),
+        ]
+    );
+}
+
 #[rstest]
 fn backslash_escape() {
     assert_eq!(
@@ -222,6 +243,23 @@ fn backslash_escape() {
     );
 }
 
+#[rstest]
+fn decoded_escape() {
+    assert_eq!(
+        format_description!(version = 2, r"\t\u{2236}"),
+        &[
+            BorrowedFormatItem::Literal(b"\t"),
+            BorrowedFormatItem::Literal("\u{2236}".as_bytes()),
+        ]
+    );
+    assert_eq!(
+        format_description!(version = 2, r"[optional [\n]]"),
+        &[BorrowedFormatItem::Optional(&BorrowedFormatItem::Literal(
+            b"\n"
+        ))]
+    );
+}
+
 #[rstest]
 fn format_description_coverage() {
     assert_eq!(
@@ -350,6 +388,54 @@ fn format_description_coverage() {
             End
         )))]
     );
+    assert_eq!(
+        format_description!("[year repr:last_two range_start:1900]"),
+        &[BorrowedFormatItem::Component(Component::Year(modifier!(
+            Year {
+                repr: YearRepr::LastTwo,
+                range_start: 1900,
+            }
+        )))]
+    );
+    assert_eq!(
+        format_description!("[year repr:last_two range_start:-50]"),
+        &[BorrowedFormatItem::Component(Component::Year(modifier!(
+            Year {
+                repr: YearRepr::LastTwo,
+                range_start: -50,
+            }
+        )))]
+    );
+    assert_eq!(
+        format_description!("[period optional:true]"),
+        &[BorrowedFormatItem::Component(Component::Period(modifier!(
+            Period {
+                is_uppercase: true,
+                case_sensitive: true,
+                is_optional: true,
+            }
+        )))]
+    );
+    assert_eq!(
+        format_description!("[month repr:long min_width:9 fill:. align:left]"),
+        &[BorrowedFormatItem::Padded(
+            Component::Month(modifier!(Month {
+                repr: MonthRepr::Long,
+            })),
+            modifier!(MinWidth {
+                width: 9,
+                fill: b'.',
+                is_left_aligned: true,
+            })
+        )]
+    );
+    assert_eq!(
+        format_description!("[day min_width:3]"),
+        &[BorrowedFormatItem::Padded(
+            Component::Day(modifier!(Day)),
+            modifier!(MinWidth { width: 3 })
+        )]
+    );
 }
 
 #[rstest]
@@ -14,9 +14,11 @@ use quickcheck::Arbitrary;
 use rand::distributions::{Distribution, Standard};
 use serde::{Deserialize, Serialize};
 use time::format_description::well_known::iso8601;
-use time::format_description::{modifier, well_known, BorrowedFormatItem, Component};
+use time::format_description::{
+    modifier, well_known, BorrowedFormatItem, Brackets, Component, ParseOptions,
+};
 use time::formatting::Formattable;
-use time::parsing::{Parsable, Parsed};
+use time::parsing::{Parsable, Parsed, ParsedDefaults};
 #[allow(deprecated)]
 use time::Instant;
 use time::{
@@ -50,8 +52,12 @@ fn alignment() {
     assert_alignment!(error::ConversionRange, 1);
     assert_alignment!(error::DifferentVariant, 1);
     assert_alignment!(error::IndeterminateOffset, 1);
+    assert_alignment!(error::IndeterminateOffsetReason, 1);
     assert_alignment!(modifier::Day, 1);
+    assert_alignment!(modifier::Era, 1);
+    assert_alignment!(modifier::Quarter, 1);
     assert_alignment!(modifier::Hour, 1);
+    assert_alignment!(modifier::MinWidth, 1);
     assert_alignment!(modifier::Minute, 1);
     assert_alignment!(modifier::Month, 1);
     assert_alignment!(modifier::OffsetHour, 1);
@@ -61,9 +67,10 @@ fn alignment() {
     assert_alignment!(modifier::Period, 1);
     assert_alignment!(modifier::Second, 1);
     assert_alignment!(modifier::Subsecond, 1);
+    assert_alignment!(modifier::SecondsOfDay, 1);
     assert_alignment!(modifier::WeekNumber, 1);
     assert_alignment!(modifier::Weekday, 1);
-    assert_alignment!(modifier::Year, 1);
+    assert_alignment!(modifier::Year, 2);
     assert_alignment!(well_known::Rfc2822, 1);
     assert_alignment!(well_known::Rfc3339, 1);
     assert_alignment!(
@@ -76,6 +83,7 @@ fn alignment() {
     assert_alignment!(iso8601::OffsetPrecision, 1);
     assert_alignment!(iso8601::TimePrecision, 1);
     assert_alignment!(Parsed, ::core::mem::align_of::<u128>());
+    assert_alignment!(ParsedDefaults, 4);
     assert_alignment!(Month, 1);
     assert_alignment!(Weekday, 1);
     assert_alignment!(Error, 8);
@@ -86,9 +94,13 @@ fn alignment() {
     assert_alignment!(error::TryFromParsed, 8);
     assert_alignment!(Component, 2);
     assert_alignment!(BorrowedFormatItem<'_>, 8);
+    assert_alignment!(Brackets, 1);
+    assert_alignment!(ParseOptions, 1);
     assert_alignment!(modifier::MonthRepr, 1);
+    assert_alignment!(modifier::NameCase, 1);
     assert_alignment!(modifier::Padding, 1);
     assert_alignment!(modifier::SubsecondDigits, 1);
+    assert_alignment!(modifier::QuarterRepr, 1);
     assert_alignment!(modifier::WeekNumberRepr, 1);
     assert_alignment!(modifier::WeekdayRepr, 1);
     assert_alignment!(modifier::YearRepr, 1);
@@ -128,20 +140,25 @@ fn size() {
     assert_size!(error::ConversionRange, 0, 1);
     assert_size!(error::DifferentVariant, 0, 1);
     assert_size!(error::IndeterminateOffset, 0, 1);
+    assert_size!(error::IndeterminateOffsetReason, 1, 1);
     assert_size!(modifier::Day, 1, 1);
-    assert_size!(modifier::Hour, 2, 2);
-    assert_size!(modifier::Minute, 1, 1);
-    assert_size!(modifier::Month, 3, 3);
-    assert_size!(modifier::OffsetHour, 2, 2);
+    assert_size!(modifier::Era, 1, 1);
+    assert_size!(modifier::Quarter, 0, 1);
+    assert_size!(modifier::Hour, 3, 3);
+    assert_size!(modifier::MinWidth, 3, 3);
+    assert_size!(modifier::Minute, 2, 2);
+    assert_size!(modifier::Month, 4, 4);
+    assert_size!(modifier::OffsetHour, 3, 3);
     assert_size!(modifier::OffsetMinute, 1, 1);
     assert_size!(modifier::OffsetSecond, 1, 1);
     assert_size!(modifier::Ordinal, 1, 1);
-    assert_size!(modifier::Period, 2, 2);
+    assert_size!(modifier::Period, 3, 3);
     assert_size!(modifier::Second, 1, 1);
     assert_size!(modifier::Subsecond, 1, 1);
+    assert_size!(modifier::SecondsOfDay, 1, 1);
     assert_size!(modifier::WeekNumber, 2, 2);
-    assert_size!(modifier::Weekday, 3, 3);
-    assert_size!(modifier::Year, 4, 4);
+    assert_size!(modifier::Weekday, 4, 4);
+    assert_size!(modifier::Year, 8, 8);
     assert_size!(well_known::Rfc2822, 0, 1);
     assert_size!(well_known::Rfc3339, 0, 1);
     assert_size!(
@@ -149,12 +166,13 @@ fn size() {
         0,
         1
     );
-    assert_size!(iso8601::Config, 7, 7);
+    assert_size!(iso8601::Config, 8, 8);
     assert_size!(iso8601::DateKind, 1, 1);
     assert_size!(iso8601::FormattedComponents, 1, 1);
     assert_size!(iso8601::OffsetPrecision, 1, 1);
     assert_size!(iso8601::TimePrecision, 2, 2);
-    assert_size!(Parsed, 56, 56);
+    assert_size!(Parsed, 64, 64);
+    assert_size!(ParsedDefaults, 16, 16);
     assert_size!(Month, 1, 1);
     assert_size!(Weekday, 1, 1);
     assert_size!(Error, 56, 56);
@@ -163,11 +181,15 @@ fn size() {
     assert_size!(error::Parse, 48, 48);
     assert_size!(error::ParseFromDescription, 24, 24);
     assert_size!(error::TryFromParsed, 48, 48);
-    assert_size!(Component, 6, 6); // TODO Size is 4 starting with rustc 1.71.
+    assert_size!(Component, 8, 8); // TODO Size is 4 starting with rustc 1.71.
     assert_size!(BorrowedFormatItem<'_>, 24, 24);
+    assert_size!(Brackets, 2, 3);
+    assert_size!(ParseOptions, 4, 4);
     assert_size!(modifier::MonthRepr, 1, 1);
+    assert_size!(modifier::NameCase, 1, 1);
     assert_size!(modifier::Padding, 1, 1);
     assert_size!(modifier::SubsecondDigits, 1, 1);
+    assert_size!(modifier::QuarterRepr, 0, 1);
     assert_size!(modifier::WeekNumberRepr, 1, 1);
     assert_size!(modifier::WeekdayRepr, 1, 1);
     assert_size!(modifier::YearRepr, 1, 1);
@@ -181,6 +203,7 @@ macro_rules! assert_obj_safe {
 
 assert_obj_safe!(ext::NumericalDuration);
 assert_obj_safe!(ext::NumericalStdDuration);
+assert_obj_safe!(ext::TryNumericalDuration);
 // `Parsable` is not object safe.
 // `Formattable` is not object safe.
 
@@ -495,6 +518,20 @@ assert_impl! { error::IndeterminateOffset:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { error::IndeterminateOffsetReason:
+    Clone,
+    Debug,
+    Display,
+    StdError,
+    PartialEq<error::IndeterminateOffsetReason>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Day:
     Clone,
     Debug,
@@ -508,6 +545,32 @@ assert_impl! { modifier::Day:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::Era:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Era>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::Quarter:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Quarter>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Hour:
     Clone,
     Debug,
@@ -521,6 +584,19 @@ assert_impl! { modifier::Hour:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::MinWidth:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::MinWidth>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Minute:
     Clone,
     Debug,
@@ -625,6 +701,19 @@ assert_impl! { modifier::Second:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::SecondsOfDay:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::SecondsOfDay>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Subsecond:
     Clone,
     Debug,
@@ -785,6 +874,18 @@ assert_impl! { Parsed:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { ParsedDefaults:
+    Clone,
+    Debug,
+    PartialEq<ParsedDefaults>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; Month:
     Arbitrary,
     Clone,
@@ -924,6 +1025,32 @@ assert_impl! { @'a; Component:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { Brackets:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<Brackets>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { ParseOptions:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<ParseOptions>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; BorrowedFormatItem<'_>:
     Clone,
     Debug,
@@ -958,6 +1085,19 @@ assert_impl! { modifier::MonthRepr:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::NameCase:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::NameCase>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Padding:
     Clone,
     Debug,
@@ -984,6 +1124,19 @@ assert_impl! { modifier::SubsecondDigits:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::QuarterRepr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::QuarterRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::WeekNumberRepr:
     Clone,
     Debug,
@@ -101,6 +101,48 @@ fn nth_prev(#[case] month: Month, #[case] n: u8, #[case] expected: Month) {
     assert_eq!(month.nth_prev(n), expected);
 }
 
+#[rstest]
+#[case(January, 2021, 31)]
+#[case(February, 2021, 28)]
+#[case(February, 2020, 29)]
+#[case(February, 1900, 28)]
+#[case(February, 2000, 29)]
+#[case(April, 2021, 30)]
+#[case(December, 2021, 31)]
+fn length(#[case] month: Month, #[case] year: i32, #[case] expected: u8) {
+    assert_eq!(month.length(year), expected);
+}
+
+#[rstest]
+#[case(January, 2021, 0)]
+#[case(January, 2020, 0)]
+#[case(February, 2021, 31)]
+#[case(February, 2020, 31)]
+#[case(March, 2021, 59)]
+#[case(March, 2020, 60)]
+#[case(December, 2021, 334)]
+#[case(December, 2020, 335)]
+fn days_in_year_before(#[case] month: Month, #[case] year: i32, #[case] expected: u16) {
+    assert_eq!(month.days_in_year_before(year), expected);
+}
+
+#[test]
+fn all() {
+    assert_eq!(
+        Month::all().collect::<Vec<_>>(),
+        [
+            January, February, March, April, May, June, July, August, September, October, November,
+            December
+        ]
+    );
+    assert_eq!(Month::all().len(), 12);
+    assert_eq!(Month::all().next_back(), Some(December));
+
+    let mut months = Month::all();
+    months.next();
+    assert_eq!(months.len(), 11);
+}
+
 #[rstest]
 #[case(January, "January")]
 #[case(February, "February")]
@@ -131,6 +173,29 @@ fn display(#[case] month: Month, #[case] expected: &str) {
 #[case("October", Ok(October))]
 #[case("November", Ok(November))]
 #[case("December", Ok(December))]
+#[case("january", Ok(January))]
+#[case("DECEMBER", Ok(December))]
+#[case("Jan", Ok(January))]
+#[case("feb", Ok(February))]
+#[case("MAR", Ok(March))]
+#[case("apr", Ok(April))]
+#[case("may", Ok(May))]
+#[case("jun", Ok(June))]
+#[case("Jul", Ok(July))]
+#[case("aug", Ok(August))]
+#[case("sep", Ok(September))]
+#[case("oct", Ok(October))]
+#[case("nov", Ok(November))]
+#[case("dec", Ok(December))]
+#[case("1", Ok(January))]
+#[case("3", Ok(March))]
+#[case("03", Ok(March))]
+#[case("12", Ok(December))]
+#[case("0", Err(time::error::InvalidVariant))]
+#[case("13", Err(time::error::InvalidVariant))]
+#[case("-1", Err(time::error::InvalidVariant))]
+#[case("Sept", Err(time::error::InvalidVariant))]
+#[case("", Err(time::error::InvalidVariant))]
 #[case("foo", Err(time::error::InvalidVariant))]
 fn from_str(#[case] s: &str, #[case] expected: Result<Month, time::error::InvalidVariant>) {
     assert_eq!(s.parse::<Month>(), expected);
@@ -151,6 +216,7 @@ fn from_str(#[case] s: &str, #[case] expected: Result<Month, time::error::Invali
 #[case(December, 12)]
 fn to_u8(#[case] month: Month, #[case] expected: u8) {
     assert_eq!(u8::from(month), expected);
+    assert_eq!(month.to_u8(), expected);
 }
 
 #[rstest]
@@ -168,6 +234,7 @@ fn to_u8(#[case] month: Month, #[case] expected: u8) {
 #[case(12, December)]
 fn try_from_u8_success(#[case] input: u8, #[case] expected: Month) {
     assert_eq!(Month::try_from(input), Ok(expected));
+    assert_eq!(Month::from_u8(input), Ok(expected));
 }
 
 #[rstest]
@@ -175,4 +242,9 @@ fn try_from_u8_success(#[case] input: u8, #[case] expected: Month) {
 #[case(13)]
 fn try_from_u8_error(#[case] input: u8) {
     assert!(matches!(Month::try_from(input), Err(err) if err.name() == "month"));
+    assert!(matches!(Month::from_u8(input), Err(err) if err.name() == "month"));
+    assert_eq!(
+        Month::from_u8(input).map_err(|err| err.to_string()),
+        Err("month must be in the range 1..=12".to_owned())
+    );
 }
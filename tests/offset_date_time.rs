@@ -3,7 +3,7 @@ use std::time::{Duration as StdDuration, SystemTime};
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Weekday};
+use time::{error, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Weekday};
 
 #[test]
 fn new_utc() {
@@ -28,6 +28,35 @@ fn now_utc() {
     assert_eq!(OffsetDateTime::now_utc().offset(), offset!(UTC));
 }
 
+#[test]
+fn now_utc_truncated() {
+    for granularity in [
+        1.nanoseconds(),
+        7.milliseconds(),
+        1.seconds(),
+        1.5.seconds(),
+        1.hours(),
+    ] {
+        let before = OffsetDateTime::now_utc();
+        let truncated = OffsetDateTime::now_utc_truncated(granularity);
+        let after = OffsetDateTime::now_utc();
+
+        assert_eq!(truncated.offset(), offset!(UTC));
+        assert_eq!(
+            truncated.unix_timestamp_nanos() % granularity.whole_nanoseconds(),
+            0
+        );
+        assert!(truncated <= after);
+        assert!(truncated > before - granularity);
+    }
+}
+
+#[test]
+#[should_panic]
+fn now_utc_truncated_zero_granularity() {
+    let _ = OffsetDateTime::now_utc_truncated(Duration::ZERO);
+}
+
 #[cfg_attr(miri, ignore)]
 #[test]
 fn now_local() {
@@ -43,6 +72,21 @@ fn now_local() {
     unsafe { set_soundness(Soundness::Sound) };
 }
 
+#[cfg_attr(miri, ignore)]
+#[test]
+fn now_local_detailed() {
+    use time::util::local_offset::*;
+
+    let _guard = crate::SOUNDNESS_LOCK.lock().expect("lock is poisoned");
+
+    // Safety: Technically not sound. However, this is a test, and it's highly improbable that we
+    // will run into issues with setting an environment variable a few times.
+    unsafe { set_soundness(Soundness::Unsound) };
+    assert!(OffsetDateTime::now_local_detailed().is_ok());
+    // Safety: We're setting it back to sound.
+    unsafe { set_soundness(Soundness::Sound) };
+}
+
 #[test]
 fn to_offset() {
     assert_eq!(
@@ -132,6 +176,50 @@ fn from_unix_timestamp_nanos() {
     assert!(OffsetDateTime::from_unix_timestamp_nanos(i128::MAX).is_err());
 }
 
+#[test]
+fn from_unix_timestamp_millis() {
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(0),
+        Ok(OffsetDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(1_546_300_800_123),
+        Ok(datetime!(2019-01-01 0:00:00.123 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(-1),
+        Ok(datetime!(1969-12-31 23:59:59.999 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(-1_001),
+        Ok(datetime!(1969-12-31 23:59:58.999 UTC)),
+    );
+    assert!(OffsetDateTime::from_unix_timestamp_millis(i64::MAX).is_err());
+    assert!(OffsetDateTime::from_unix_timestamp_millis(i64::MIN).is_err());
+}
+
+#[test]
+fn from_unix_timestamp_micros() {
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(0),
+        Ok(OffsetDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(1_546_300_800_123_456),
+        Ok(datetime!(2019-01-01 0:00:00.123_456 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(-1),
+        Ok(datetime!(1969-12-31 23:59:59.999_999 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(-1_000_001),
+        Ok(datetime!(1969-12-31 23:59:58.999_999 UTC)),
+    );
+    assert!(OffsetDateTime::from_unix_timestamp_micros(i128::MAX).is_err());
+    assert!(OffsetDateTime::from_unix_timestamp_micros(i128::MIN).is_err());
+}
+
 #[test]
 fn offset() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).offset(), offset!(UTC));
@@ -167,6 +255,54 @@ fn unix_timestamp_nanos() {
     );
 }
 
+#[test]
+fn unix_timestamp_millis() {
+    assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_millis(), 0);
+    assert_eq!(
+        datetime!(1970-01-01 1:00:00.123_999 UTC)
+            .to_offset(offset!(-1))
+            .unix_timestamp_millis(),
+        3_600_123,
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999 UTC).unix_timestamp_millis(),
+        -1
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999 UTC).unix_timestamp_millis(),
+        -1
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:58.999_999 UTC).unix_timestamp_millis(),
+        -1_001
+    );
+}
+
+#[test]
+fn unix_timestamp_micros() {
+    assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_micros(), 0);
+    assert_eq!(
+        datetime!(1970-01-01 1:00:00.123_456_999 UTC)
+            .to_offset(offset!(-1))
+            .unix_timestamp_micros(),
+        3_600_123_456,
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999 UTC).unix_timestamp_micros(),
+        -1
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999_999 UTC).unix_timestamp_micros(),
+        -1
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(
+            datetime!(1900-01-01 0:00:00.000_001 UTC).unix_timestamp_micros()
+        ),
+        Ok(datetime!(1900-01-01 0:00:00.000_001 UTC))
+    );
+}
+
 #[test]
 fn date() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).date(), date!(2019 - 01 - 01));
@@ -310,6 +446,77 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00 UTC).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn to_julian_date() {
+    assert_eq!(datetime!(-4713-11-24 12:00 UTC).to_julian_date(), 0.0);
+    assert_eq!(
+        datetime!(2000-01-01 12:00 UTC).to_julian_date(),
+        2_451_545.0
+    );
+    assert_eq!(
+        datetime!(2000-01-01 6:00 UTC).to_julian_date(),
+        2_451_544.75
+    );
+    // The offset is taken into account, unlike with the Julian day.
+    assert_eq!(datetime!(2000-01-01 0:00 -6).to_julian_date(), 2_451_544.75);
+    assert_eq!(
+        datetime!(2000-01-02 0:00 +18).to_julian_date(),
+        2_451_544.75
+    );
+    assert_eq!(
+        datetime!(2000-01-01 12:00 UTC).to_julian_date(),
+        datetime!(2000-01-01 12:00 UTC)
+            .date()
+            .with_time(time!(12:00))
+            .to_julian_date()
+    );
+    assert_eq!(OffsetDateTime::UNIX_EPOCH.to_julian_date(), 2_440_587.5);
+}
+
+#[test]
+fn from_julian_date() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::from_julian_date(0.0),
+        Ok(datetime!(-4713-11-24 12:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(-0.5),
+        Ok(datetime!(-4713-11-24 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_440_587.5),
+        Ok(OffsetDateTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_544.25),
+        Ok(datetime!(1999-12-31 18:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_545.75),
+        Ok(datetime!(2000-01-02 6:00 UTC))
+    );
+
+    // Round trip within the documented precision.
+    for datetime in [
+        datetime!(2000-01-01 0:00:00.123_456 UTC),
+        datetime!(1969-12-31 23:59:59.999 UTC),
+        datetime!(2024-02-29 13:14:15.161_718 +5:30),
+        datetime!(-1000-06-15 1:02:03 UTC),
+    ] {
+        let round_trip = OffsetDateTime::from_julian_date(datetime.to_julian_date())?;
+        assert!((round_trip - datetime).abs() < 100.microseconds());
+        assert_eq!(round_trip.offset(), offset!(UTC));
+    }
+
+    assert!(OffsetDateTime::from_julian_date(f64::NAN).is_err());
+    assert!(OffsetDateTime::from_julian_date(f64::INFINITY).is_err());
+    assert!(OffsetDateTime::from_julian_date(f64::NEG_INFINITY).is_err());
+    assert!(OffsetDateTime::from_julian_date(1e20).is_err());
+    assert!(OffsetDateTime::from_julian_date(-1e12).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn to_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03 UTC).to_hms(), (1, 2, 3));
@@ -339,6 +546,19 @@ fn to_hms_nano() {
     );
 }
 
+#[test]
+fn destructuring_in_const() {
+    const DATETIME: OffsetDateTime = datetime!(2020-01-01 1:02:03.004_005_006 -5);
+    const CALENDAR_DATE: (i32, Month, u8) = DATETIME.to_calendar_date();
+    const HMS: (u8, u8, u8) = DATETIME.to_hms();
+    const HMS_NANO: (u8, u8, u8, u32) = DATETIME.to_hms_nano();
+
+    // Values are in the stored offset, not UTC.
+    assert_eq!(CALENDAR_DATE, (2020, Month::January, 1));
+    assert_eq!(HMS, (1, 2, 3));
+    assert_eq!(HMS_NANO, (1, 2, 3, 4_005_006));
+}
+
 #[test]
 fn hour() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).hour(), 0);
@@ -442,6 +662,14 @@ fn replace_offset() {
         datetime!(2020-01-01 0:00 UTC).replace_offset(offset!(-5)),
         datetime!(2020-01-01 0:00 -5)
     );
+
+    let original = datetime!(2020-01-01 23:30:15.5 +1);
+    let replaced = original.replace_offset(offset!(-5));
+    assert_eq!(replaced.date(), original.date());
+    assert_eq!(replaced.time(), original.time());
+    assert_eq!(replaced.offset(), offset!(-5));
+    assert_eq!(replaced - original, 6.hours());
+    assert_ne!(replaced, original.to_offset(offset!(-5)));
 }
 
 #[test]
@@ -586,6 +814,22 @@ fn replace_nanosecond() {
     ); // 1_000_000_000 isn't a valid nanosecond
 }
 
+#[test]
+fn replace_chained() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2022 - 02 - 18 01:02:03.004_005_006 +01)
+            .replace_year(2023)?
+            .replace_month(Month::March)?
+            .replace_day(31)?
+            .replace_hour(9)?
+            .replace_minute(0)?
+            .replace_second(0)?
+            .replace_nanosecond(0)?,
+        datetime!(2023 - 03 - 31 9:00 +01)
+    );
+    Ok(())
+}
+
 #[test]
 fn partial_eq() {
     assert_eq!(
@@ -944,6 +1188,96 @@ fn sub_self() {
     );
 }
 
+#[test]
+fn duration_since() {
+    assert_eq!(
+        datetime!(2019-01-02 0:00 UTC).duration_since(datetime!(2019-01-01 0:00 UTC)),
+        1.days()
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC).duration_since(datetime!(2019-01-02 0:00 UTC)),
+        (-1).days()
+    );
+    assert_eq!(
+        datetime!(2019-01-01 1:00 +1).duration_since(datetime!(2019-01-01 0:00 UTC)),
+        Duration::ZERO
+    );
+    assert_eq!(
+        datetime!(+999_999-12-31 23:59:59.999_999_999 -23:59:59)
+            .duration_since(datetime!(-999_999-01-01 0:00 +23:59:59)),
+        Duration::new(63_113_872_550_397, 999_999_999),
+    );
+}
+
+#[test]
+fn checked_duration_since() {
+    assert_eq!(
+        datetime!(2019-01-02 0:00 UTC).checked_duration_since(datetime!(2019-01-01 0:00 UTC)),
+        Some(1.days())
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC).checked_duration_since(datetime!(2019-01-02 0:00 UTC)),
+        None
+    );
+    assert_eq!(
+        datetime!(2019-01-01 1:00 +1).checked_duration_since(datetime!(2019-01-01 0:00 UTC)),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 +1).checked_duration_since(datetime!(2019-01-01 0:00 UTC)),
+        None
+    );
+}
+
+#[test]
+fn abs_diff() {
+    assert_eq!(
+        datetime!(2019-01-02 0:00 UTC).abs_diff(datetime!(2019-01-01 0:00 UTC)),
+        1.days()
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC).abs_diff(datetime!(2019-01-02 0:00 UTC)),
+        1.days()
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 +1).abs_diff(datetime!(2019-01-01 0:00 UTC)),
+        1.hours()
+    );
+    assert_eq!(
+        datetime!(2019-01-01 1:00 +1).abs_diff(datetime!(2019-01-01 0:00 UTC)),
+        Duration::ZERO
+    );
+}
+
+#[test]
+fn clamp() {
+    let (min, max) = (
+        datetime!(2020-01-01 0:00 UTC),
+        datetime!(2020-01-01 12:00 UTC),
+    );
+    assert_eq!(datetime!(2019-12-31 23:59 UTC).clamp(min, max), min);
+    assert_eq!(datetime!(2020-01-01 12:01 UTC).clamp(min, max), max);
+    // The offset is taken into account when comparing, but the value is not converted.
+    let value = datetime!(2020-01-01 0:30 -1).clamp(min, max);
+    assert_eq!(value, datetime!(2020-01-01 1:30 UTC));
+    assert_eq!(value.offset(), offset!(-1));
+    assert_eq!(datetime!(2020-01-01 0:30 +1).clamp(min, max), min);
+    assert_eq!(datetime!(2020-01-01 12:30 -1).clamp(min, max), max);
+    assert_eq!(
+        datetime!(2020-01-01 12:30 +1).clamp(min, max).offset(),
+        offset!(+1)
+    );
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = OffsetDateTime::UNIX_EPOCH.clamp(
+        datetime!(1970-01-01 0:00 -1),
+        datetime!(1970-01-01 0:00 UTC),
+    );
+}
+
 #[test]
 fn std_sub() {
     assert_eq!(
@@ -1084,6 +1418,59 @@ fn to_std() {
     );
 }
 
+#[test]
+fn try_from_system_time() {
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH),
+        Ok(OffsetDateTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH + 1.std_nanoseconds()),
+        Ok(datetime!(1970-01-01 0:00:00.000_000_001 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH - 1.std_nanoseconds()),
+        Ok(datetime!(1969-12-31 23:59:59.999_999_999 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH + (1 << 45).std_seconds()),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH - (1 << 45).std_seconds()),
+        Err(error::ConversionRange)
+    );
+}
+
+#[test]
+fn try_to_system_time() {
+    assert_eq!(
+        OffsetDateTime::UNIX_EPOCH.try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        datetime!(1970-01-01 0:00:00.000_000_001 UTC).try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH + 1.std_nanoseconds())
+    );
+    assert_eq!(
+        datetime!(1969-12-31 22:59:59.000_000_001 -1).try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH - 999_999_999.std_nanoseconds())
+    );
+
+    // Nanoseconds survive the round trip in both directions.
+    let datetime = datetime!(2024-02-29 12:34:56.789_012_345 +5:30);
+    let system_time = datetime.try_to_system_time().expect("in range");
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(system_time),
+        Ok(datetime)
+    );
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(system_time)
+            .and_then(OffsetDateTime::try_to_system_time),
+        Ok(system_time)
+    );
+}
+
 #[test]
 fn checked_add_duration() {
     // Successful addition
@@ -1234,6 +1621,42 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_months() {
+    assert_eq!(
+        datetime!(2021-01-31 23:30 -5).checked_add_months(1),
+        Some(datetime!(2021-02-28 23:30 -5))
+    );
+    assert_eq!(
+        datetime!(2020-01-31 23:30 -5).checked_add_months(1),
+        Some(datetime!(2020-02-29 23:30 -5))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX
+            .assume_offset(offset!(+1))
+            .checked_add_months(1),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_months() {
+    assert_eq!(
+        datetime!(2021-03-31 23:30 -5).checked_sub_months(1),
+        Some(datetime!(2021-02-28 23:30 -5))
+    );
+    assert_eq!(
+        datetime!(2020-03-31 23:30 -5).checked_sub_months(1),
+        Some(datetime!(2020-02-29 23:30 -5))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN
+            .assume_offset(offset!(-1))
+            .checked_sub_months(1),
+        None
+    );
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -1268,6 +1691,28 @@ fn saturating_add_duration() {
     );
 }
 
+#[test]
+fn arithmetic_near_max_year() {
+    let dt = datetime!(9999 - 12 - 31 12:00 UTC);
+    let max = PrimitiveDateTime::MAX.assume_utc();
+    let min = PrimitiveDateTime::MIN.assume_utc();
+
+    assert_eq!(dt.checked_add(Duration::MAX), None);
+    assert_eq!(dt.checked_sub(Duration::MIN), None);
+    assert_eq!(dt.saturating_add(Duration::MAX), max);
+    assert_eq!(dt.saturating_sub(Duration::MIN), max);
+
+    assert_eq!(dt.checked_add(Duration::MIN), None);
+    assert_eq!(dt.checked_sub(Duration::MAX), None);
+    assert_eq!(dt.saturating_add(Duration::MIN), min);
+    assert_eq!(dt.saturating_sub(Duration::MAX), min);
+
+    assert_eq!(
+        dt.checked_add(11.hours()),
+        Some(datetime!(9999 - 12 - 31 23:00 UTC))
+    );
+}
+
 #[test]
 fn saturating_sub_duration() {
     assert_eq!(
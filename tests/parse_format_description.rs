@@ -4,7 +4,9 @@ use rstest::rstest;
 use rstest_reuse::{apply, template};
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::*;
-use time::format_description::{self, BorrowedFormatItem, Component, OwnedFormatItem};
+use time::format_description::{
+    self, BorrowedFormatItem, Brackets, Component, OwnedFormatItem, ParseOptions,
+};
 
 /// Identical to `modifier!`, but obtains the value from `M<T>` automagically.
 macro_rules! modifier_m {
@@ -39,17 +41,34 @@ fn modifiers(
         (true, "repr:12"),
     )]
     hour_is_12_hour_clock: _,
+    #[values(
+        (None, "fraction:none"),
+        (Some(SubsecondDigits::Three), "fraction:3"),
+        (Some(SubsecondDigits::OneOrMore), "fraction:1+"),
+    )]
+    fraction: _,
     #[values(
         (true, "case:upper"),
         (false, "case:lower"),
     )]
     period_is_uppercase: _,
+    #[values(
+        (true, "optional:true"),
+        (false, "optional:false"),
+    )]
+    period_is_optional: _,
     #[values(
         (MonthRepr::Numerical, "repr:numerical"),
         (MonthRepr::Long, "repr:long"),
         (MonthRepr::Short, "repr:short"),
     )]
     month_repr: _,
+    #[values(
+        (NameCase::Title, "case:title"),
+        (NameCase::Upper, "case:upper"),
+        (NameCase::Lower, "case:lower"),
+    )]
+    name_case: _,
     #[values(
         (SubsecondDigits::One, "digits:1"),
         (SubsecondDigits::Two, "digits:2"),
@@ -91,6 +110,27 @@ fn modifiers(
         (true, "sign:mandatory"),
     )]
     sign_is_mandatory: _,
+    #[values(
+        (false, "utc_alias:false"),
+        (true, "utc_alias:true"),
+    )]
+    utc_alias_allowed: _,
+    #[values(
+        (false, "era:false"),
+        (true, "era:true"),
+    )]
+    year_is_era_based: _,
+    #[values(
+        (true, "case:upper"),
+        (false, "case:lower"),
+    )]
+    era_is_uppercase: _,
+    #[values(
+        (1950, "range_start:1950"),
+        (1900, "range_start:1900"),
+        (-50, "range_start:-50"),
+    )]
+    year_range_start: _,
     #[values(
         (true, "one_indexed:true"),
         (false, "one_indexed:false"),
@@ -148,6 +188,8 @@ fn only_literal<const N: usize>(#[case] format_description: &str, #[case] expect
 #[rstest]
 #[case("[day]", Component::Day(modifier!(Day)))]
 #[case("[end]", Component::End(modifier!(End)))]
+#[case("[era]", Component::Era(modifier!(Era)))]
+#[case("[quarter]", Component::Quarter(modifier!(Quarter)))]
 #[case("[hour]", Component::Hour(modifier!(Hour)))]
 #[case("[minute]", Component::Minute(modifier!(Minute)))]
 #[case("[month]", Component::Month(modifier!(Month)))]
@@ -157,6 +199,7 @@ fn only_literal<const N: usize>(#[case] format_description: &str, #[case] expect
 #[case("[ordinal]", Component::Ordinal(modifier!(Ordinal)))]
 #[case("[period]", Component::Period(modifier!(Period)))]
 #[case("[second]", Component::Second(modifier!(Second)))]
+#[case("[seconds_of_day]", Component::SecondsOfDay(modifier!(SecondsOfDay)))]
 #[case("[subsecond]", Component::Subsecond(modifier!(Subsecond)))]
 #[case("[unix_timestamp]", Component::UnixTimestamp(modifier!(UnixTimestamp)))]
 #[case("[weekday]", Component::Weekday(modifier!(Weekday)))]
@@ -169,6 +212,123 @@ fn simple_component(#[case] format_description: &str, #[case] component: Compone
     );
 }
 
+#[rstest]
+#[case("{year}-{month}", Brackets::CURLY)]
+#[case("<year>-<month>", Brackets::ANGLE)]
+#[case("(year)-(month)", Brackets::new('(', ')'))]
+#[case("[year]-[month]", Brackets::SQUARE)]
+fn custom_brackets(#[case] format_description: &str, #[case] brackets: Brackets) {
+    let mut options = ParseOptions::default();
+    options.brackets = brackets;
+    let expected = [
+        BorrowedFormatItem::Component(Component::Year(modifier!(Year))),
+        BorrowedFormatItem::Literal(b"-"),
+        BorrowedFormatItem::Component(Component::Month(modifier!(Month))),
+    ];
+    assert_eq!(
+        format_description::parse_borrowed_with_options::<1>(format_description, options),
+        Ok(expected.to_vec())
+    );
+    assert_eq!(
+        format_description::parse_borrowed_with_options::<2>(format_description, options),
+        Ok(expected.to_vec())
+    );
+    assert_eq!(
+        format_description::parse_owned_with_options::<2>(format_description, options),
+        Ok(OwnedFormatItem::from(expected.as_slice()))
+    );
+}
+
+#[rstest]
+fn custom_brackets_escape() {
+    let mut options = ParseOptions::default();
+    options.brackets = Brackets::CURLY;
+
+    assert_eq!(
+        format_description::parse_borrowed_with_options::<1>("[{{]", options),
+        Ok(vec![
+            BorrowedFormatItem::Literal(b"["),
+            BorrowedFormatItem::Literal(b"{"),
+            BorrowedFormatItem::Literal(b"]"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_borrowed_with_options::<2>(r"[\{\}]", options),
+        Ok(vec![
+            BorrowedFormatItem::Literal(b"["),
+            BorrowedFormatItem::Literal(b"{"),
+            BorrowedFormatItem::Literal(b"}"),
+            BorrowedFormatItem::Literal(b"]"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_owned_with_options::<2>("{optional {[hour]}}", options),
+        Ok(OwnedFormatItem::Optional(Box::new(
+            OwnedFormatItem::Literal(Box::new(*b"[hour]"))
+        )))
+    );
+    assert!(matches!(
+        format_description::parse_borrowed_with_options::<2>(r"\[", options),
+        Err(InvalidFormatDescription::Expected {
+            what: "valid escape sequence",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_borrowed_with_options::<2>("{year", options),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0, .. })
+    ));
+}
+
+#[rstest]
+#[case('a', '}')]
+#[case('{', ' ')]
+#[case('\\', '}')]
+#[case('{', '{')]
+#[case('é', '}')]
+#[should_panic]
+fn custom_brackets_invalid(#[case] opening: char, #[case] closing: char) {
+    let _ = Brackets::new(opening, closing);
+}
+
+#[rstest]
+#[case("[month min_width:9]", Component::Month(modifier!(Month)), 9, b' ', false)]
+#[case("[month fill:*]", Component::Month(modifier!(Month)), 0, b'*', false)]
+#[case("[month align:left]", Component::Month(modifier!(Month)), 0, b' ', true)]
+#[case(
+    "[month MIN_WIDTH:4 FILL:. ALIGN:RIGHT]",
+    Component::Month(modifier!(Month)),
+    4, b'.', false
+)]
+#[case(
+    "[month repr:short min_width:5 align:left fill:0]",
+    Component::Month(modifier!(Month { repr: MonthRepr::Short })),
+    5, b'0', true
+)]
+#[case("[end min_width:1]", Component::End(modifier!(End)), 1, b' ', false)]
+fn padded_component(
+    #[case] format_description: &str,
+    #[case] component: Component,
+    #[case] width: u8,
+    #[case] fill: u8,
+    #[case] is_left_aligned: bool,
+) {
+    let mut min_width = MinWidth::default();
+    min_width.width = width;
+    min_width.fill = fill;
+    min_width.is_left_aligned = is_left_aligned;
+
+    assert_eq!(
+        format_description::parse(format_description),
+        Ok(vec![BorrowedFormatItem::Padded(component, min_width)])
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>(format_description),
+        Ok(OwnedFormatItem::Padded(component, min_width))
+    );
+}
+
 #[allow(clippy::cognitive_complexity)] // all test the same thing
 #[rstest]
 fn errors() {
@@ -199,9 +359,67 @@ fn errors() {
         "[day padding:invalid]", InvalidModifier { value, index: 13, .. } if value == "invalid",
         "[ignore]", MissingRequiredModifier { name: "count", index: 1, .. },
         "[ignore count:70000]", InvalidModifier { value, index: 14, .. } if value == "70000",
+        "[year range_start:19x0]", InvalidModifier { value, index: 18, .. } if value == "19x0",
+        "[day min_width:300]", InvalidModifier { value, index: 15, .. } if value == "300",
+        "[day fill:ab]", InvalidModifier { value, index: 10, .. } if value == "ab",
+        "[day fill:é]", InvalidModifier { value, index: 10, .. } if value == "é",
+        "[day align:center]", InvalidModifier { value, index: 11, .. } if value == "center",
     }
 }
 
+#[rstest]
+#[case("[day sign:mandatory]", (5, 9))]
+#[case("[day sign:]", (10, 10))]
+#[case("[day :mandatory]", (5, 5))]
+#[case("[day padding:invalid]", (13, 20))]
+#[case("[day padding]", (5, 12))]
+#[case("[year [month]]", (6, 7))]
+fn invalid_modifier_range(#[case] format_description: &str, #[case] expected: (usize, usize)) {
+    use InvalidFormatDescription::*;
+
+    assert!(matches!(
+        format_description::parse(format_description),
+        Err(InvalidModifier { index_range, .. }) if index_range == expected
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>(format_description),
+        Err(InvalidModifier { index_range, .. }) if index_range == expected
+    ));
+}
+
+#[rstest]
+#[case("[day sign:mandatory", (0, 1))]
+#[case("[optional []", (0, 1))]
+#[case("[optional [", (10, 11))]
+#[case("[optional [[year", (11, 12))]
+#[case("[first [[year]", (7, 8))]
+fn unclosed_opening_bracket_range(
+    #[case] format_description: &str,
+    #[case] expected: (usize, usize),
+) {
+    use InvalidFormatDescription::*;
+
+    assert!(matches!(
+        format_description::parse_owned::<2>(format_description),
+        Err(UnclosedOpeningBracket { index_range, .. }) if index_range == expected
+    ));
+}
+
+#[rstest]
+#[case("[optional[]]", (8, 9))]
+#[case("[first[]]", (5, 6))]
+#[case("[optional ", (9, 10))]
+#[case(r"\a", (0, 2))]
+#[case(r"\", (0, 1))]
+fn expected_range(#[case] format_description: &str, #[case] expected: (usize, usize)) {
+    use InvalidFormatDescription::*;
+
+    assert!(matches!(
+        format_description::parse_owned::<2>(format_description),
+        Err(Expected { index_range, .. }) if index_range == expected
+    ));
+}
+
 // region: individual components
 macro_rules! placeholder {
     ($($x:tt)*) => {
@@ -236,11 +454,11 @@ fn day_component(padding: M<Padding>) {
 }
 
 #[apply(modifiers)]
-fn minute_component(padding: M<Padding>) {
+fn minute_component(padding: M<Padding>, fraction: M<Option<SubsecondDigits>>) {
     assert_eq!(
-        parse_with_modifiers!("minute", padding),
+        parse_with_modifiers!("minute", padding, fraction),
         Ok(vec![BorrowedFormatItem::Component(Component::Minute(
-            modifier_m!(Minute { padding })
+            modifier_m!(Minute { padding, fraction })
         ))])
     );
 }
@@ -286,26 +504,47 @@ fn second_component(padding: M<Padding>) {
 }
 
 #[apply(modifiers)]
-fn hour_component(padding: M<Padding>, hour_is_12_hour_clock: M<bool>) {
+fn seconds_of_day_component(padding: M<Padding>) {
+    assert_eq!(
+        parse_with_modifiers!("seconds_of_day", padding),
+        Ok(vec![BorrowedFormatItem::Component(
+            Component::SecondsOfDay(modifier_m!(SecondsOfDay { padding }))
+        )])
+    );
+}
+
+#[apply(modifiers)]
+fn hour_component(
+    padding: M<Padding>,
+    hour_is_12_hour_clock: M<bool>,
+    fraction: M<Option<SubsecondDigits>>,
+) {
     assert_eq!(
-        parse_with_modifiers!("hour", padding, hour_is_12_hour_clock),
+        parse_with_modifiers!("hour", padding, hour_is_12_hour_clock, fraction),
         Ok(vec![BorrowedFormatItem::Component(Component::Hour(
             modifier_m!(Hour {
                 padding,
-                is_12_hour_clock: hour_is_12_hour_clock
+                is_12_hour_clock: hour_is_12_hour_clock,
+                fraction,
             })
         ))])
     );
 }
 
 #[apply(modifiers)]
-fn month_component(padding: M<Padding>, case_sensitive: M<bool>, month_repr: M<MonthRepr>) {
+fn month_component(
+    padding: M<Padding>,
+    case_sensitive: M<bool>,
+    month_repr: M<MonthRepr>,
+    name_case: M<NameCase>,
+) {
     assert_eq!(
-        parse_with_modifiers!("month", padding, case_sensitive, month_repr),
+        parse_with_modifiers!("month", padding, case_sensitive, month_repr, name_case),
         Ok(vec![BorrowedFormatItem::Component(Component::Month(
             modifier_m!(Month {
                 padding,
                 repr: month_repr,
+                case: name_case,
                 case_sensitive
             })
         ))])
@@ -313,13 +552,23 @@ fn month_component(padding: M<Padding>, case_sensitive: M<bool>, month_repr: M<M
 }
 
 #[apply(modifiers)]
-fn period_component(case_sensitive: M<bool>, period_is_uppercase: M<bool>) {
+fn period_component(
+    case_sensitive: M<bool>,
+    period_is_uppercase: M<bool>,
+    period_is_optional: M<bool>,
+) {
     assert_eq!(
-        parse_with_modifiers!("period", period_is_uppercase, case_sensitive),
+        parse_with_modifiers!(
+            "period",
+            period_is_uppercase,
+            case_sensitive,
+            period_is_optional
+        ),
         Ok(vec![BorrowedFormatItem::Component(Component::Period(
             modifier_m!(Period {
                 is_uppercase: period_is_uppercase,
-                case_sensitive
+                case_sensitive,
+                is_optional: period_is_optional,
             })
         ))])
     );
@@ -330,18 +579,21 @@ fn weekday_component(
     case_sensitive: M<bool>,
     weekday_is_one_indexed: M<bool>,
     weekday_repr: M<WeekdayRepr>,
+    name_case: M<NameCase>,
 ) {
     assert_eq!(
         parse_with_modifiers!(
             "weekday",
             case_sensitive,
             weekday_is_one_indexed,
-            weekday_repr
+            weekday_repr,
+            name_case
         ),
         Ok(vec![BorrowedFormatItem::Component(Component::Weekday(
             modifier_m!(Weekday {
                 repr: weekday_repr,
                 one_indexed: weekday_is_one_indexed,
+                case: name_case,
                 case_sensitive
             })
         ))])
@@ -362,13 +614,18 @@ fn week_number_component(padding: M<Padding>, week_number_repr: M<WeekNumberRepr
 }
 
 #[apply(modifiers)]
-fn offset_hour_component(padding: M<Padding>, sign_is_mandatory: M<bool>) {
+fn offset_hour_component(
+    padding: M<Padding>,
+    sign_is_mandatory: M<bool>,
+    utc_alias_allowed: M<bool>,
+) {
     assert_eq!(
-        parse_with_modifiers!("offset_hour", padding, sign_is_mandatory),
+        parse_with_modifiers!("offset_hour", padding, sign_is_mandatory, utc_alias_allowed),
         Ok(vec![BorrowedFormatItem::Component(Component::OffsetHour(
             modifier_m!(OffsetHour {
                 padding,
-                sign_is_mandatory
+                sign_is_mandatory,
+                utc_alias_allowed,
             })
         ))])
     );
@@ -380,6 +637,7 @@ fn year_component(
     year_repr: M<YearRepr>,
     year_is_iso_week_based: M<bool>,
     sign_is_mandatory: M<bool>,
+    year_is_era_based: M<bool>,
 ) {
     assert_eq!(
         parse_with_modifiers!(
@@ -387,14 +645,41 @@ fn year_component(
             padding,
             year_repr,
             year_is_iso_week_based,
-            sign_is_mandatory
+            sign_is_mandatory,
+            year_is_era_based
         ),
         Ok(vec![BorrowedFormatItem::Component(Component::Year(
             modifier_m!(Year {
                 padding,
                 repr: year_repr,
                 iso_week_based: year_is_iso_week_based,
-                sign_is_mandatory
+                sign_is_mandatory,
+                era_based: year_is_era_based,
+            })
+        ))])
+    );
+}
+
+#[apply(modifiers)]
+fn year_range_start_component(year_repr: M<YearRepr>, year_range_start: M<i16>) {
+    assert_eq!(
+        parse_with_modifiers!("year", year_repr, year_range_start),
+        Ok(vec![BorrowedFormatItem::Component(Component::Year(
+            modifier_m!(Year {
+                repr: year_repr,
+                range_start: year_range_start,
+            })
+        ))])
+    );
+}
+
+#[apply(modifiers)]
+fn era_component(era_is_uppercase: M<bool>) {
+    assert_eq!(
+        parse_with_modifiers!("era", era_is_uppercase),
+        Ok(vec![BorrowedFormatItem::Component(Component::Era(
+            modifier_m!(Era {
+                is_uppercase: era_is_uppercase
             })
         ))])
     );
@@ -534,6 +819,38 @@ fn first() {
     );
 }
 
+#[rstest]
+fn comment() {
+    assert_eq!(
+        format_description::parse("[# the year ][year][#]"),
+        Ok(vec![BorrowedFormatItem::Component(Component::Year(
+            Default::default()
+        ))])
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[year][#comment]-[# [nested] brackets ][month]"),
+        Ok(OwnedFormatItem::Compound(Box::new([
+            OwnedFormatItem::Component(Component::Year(Default::default())),
+            OwnedFormatItem::Literal(Box::new(*b"-")),
+            OwnedFormatItem::Component(Component::Month(Default::default())),
+        ])))
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[optional [[# comment ][year]]]"),
+        Ok(OwnedFormatItem::Optional(Box::new(
+            OwnedFormatItem::Component(Component::Year(Default::default()))
+        )))
+    );
+    assert!(matches!(
+        format_description::parse("[year][# unclosed"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 6, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[# [unbalanced ]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0, .. })
+    ));
+}
+
 #[rstest]
 fn backslash_escape() {
     assert_eq!(
@@ -594,9 +911,69 @@ fn backslash_escape() {
     );
 }
 
+#[rstest]
+fn decoded_escape() {
+    assert_eq!(
+        format_description::parse_borrowed::<2>(r"[hour]\t[minute]"),
+        Ok(vec![
+            BorrowedFormatItem::Component(Component::Hour(Hour::default())),
+            BorrowedFormatItem::Literal(b"\t"),
+            BorrowedFormatItem::Component(Component::Minute(Minute::default())),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_borrowed::<2>(r"\n\r\u{41}"),
+        Ok(vec![
+            BorrowedFormatItem::Literal(b"\n"),
+            BorrowedFormatItem::Literal(b"\r"),
+            BorrowedFormatItem::Literal(b"A"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>(r"\u{2236}"),
+        Ok(OwnedFormatItem::Literal("\u{2236}".as_bytes().into()))
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>(r"[optional [\t]]"),
+        Ok(OwnedFormatItem::Optional(Box::new(
+            OwnedFormatItem::Literal(Box::new(*b"\t"))
+        )))
+    );
+    assert!(matches!(
+        format_description::parse_borrowed::<2>(r"\u{2236}"),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "non-ASCII escape sequence",
+            context: "borrowed format descriptions",
+            index: 0,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+fn raw_backslash() {
+    let mut options = ParseOptions::default();
+    options.raw = true;
+
+    assert_eq!(
+        format_description::parse_borrowed_with_options::<2>(r"\t\", options),
+        Ok(vec![BorrowedFormatItem::Literal(br"\t\")])
+    );
+    assert_eq!(
+        format_description::parse_owned_with_options::<2>(r"\u{41}", options),
+        Ok(OwnedFormatItem::Literal(Box::new(*br"\u{41}")))
+    );
+}
+
 #[rstest]
 #[case(r"\a", 1)]
 #[case(r"\", 0)]
+#[case(r"\u", 1)]
+#[case(r"\u{}", 3)]
+#[case(r"\u{12", 4)]
+#[case(r"\u{1234567}", 8)]
+#[case(r"\u{110000}", 9)]
+#[case(r"\u{d800}", 7)]
 fn backslash_escape_error(#[case] format_description: &str, #[case] expected_index: usize) {
     assert!(matches!(
         format_description::parse_owned::<2>(format_description),
@@ -628,7 +1005,7 @@ fn nested_v1_error() {
     ));
     assert!(matches!(
         format_description::parse_owned::<2>("[first [a][[[]]"),
-        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0, .. })
+        Err(InvalidFormatDescription::MissingComponentName { index: 11, .. })
     ));
 }
 
@@ -700,6 +1077,47 @@ fn nested_error() {
     ));
 }
 
+#[rstest]
+fn nesting_too_deep() {
+    let nested = |depth| "[optional [".repeat(depth) + &"]]".repeat(depth);
+
+    assert!(format_description::parse_owned::<2>(&nested(32)).is_ok());
+    assert!(matches!(
+        format_description::parse_owned::<2>(&nested(33)),
+        Err(InvalidFormatDescription::NestingTooDeep { index: 362, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>(&nested(1_000)),
+        Err(InvalidFormatDescription::NestingTooDeep { index: 362, .. })
+    ));
+    assert_eq!(
+        format_description::parse_owned::<2>(&nested(33))
+            .expect_err("nesting should be too deep")
+            .to_string(),
+        "format description nested too deeply at byte index 362"
+    );
+
+    let nested_first = |depth| "[first [".repeat(depth) + &"]]".repeat(depth);
+    assert!(format_description::parse_owned::<2>(&nested_first(32)).is_ok());
+    assert!(matches!(
+        format_description::parse_owned::<2>(&nested_first(33)),
+        Err(InvalidFormatDescription::NestingTooDeep { index: 263, .. })
+    ));
+
+    let mut options = ParseOptions::default();
+    options.max_nesting_depth = 2;
+    assert!(format_description::parse_owned_with_options::<2>(&nested(2), options).is_ok());
+    assert!(matches!(
+        format_description::parse_owned_with_options::<2>(&nested(3), options),
+        Err(InvalidFormatDescription::NestingTooDeep { index: 32, .. })
+    ));
+    options.max_nesting_depth = 0;
+    assert!(matches!(
+        format_description::parse_borrowed_with_options::<2>("[first [a]]", options),
+        Err(InvalidFormatDescription::NestingTooDeep { index: 7, .. })
+    ));
+}
+
 #[rstest]
 #[case("[", "missing component name at byte index 0")]
 #[case("[foo", "unclosed opening bracket at byte index 0")]
@@ -2,7 +2,8 @@ use std::num::{NonZeroU16, NonZeroU8};
 
 use time::format_description::modifier::WeekNumberRepr;
 use time::format_description::{BorrowedFormatItem, Component};
-use time::parsing::Parsed;
+use time::macros::{date, datetime, offset, time};
+use time::parsing::{Parsed, ParsedDefaults};
 use time::{error, Month, Time, Weekday};
 
 #[test]
@@ -127,6 +128,62 @@ fn builder_methods() {
     }
 }
 
+#[test]
+fn into_offset_date_time_or() {
+    let defaults = ParsedDefaults {
+        time: time!(6:15),
+        offset: offset!(-5),
+        ..ParsedDefaults::new(date!(2000 - 01 - 01))
+    };
+
+    assert_eq!(
+        Parsed::new().into_offset_date_time_or(defaults),
+        Ok(datetime!(2000-01-01 6:15 -5))
+    );
+    assert_eq!(
+        Parsed::new()
+            .with_year(2024)
+            .and_then(|parsed| parsed.with_month(Month::February))
+            .and_then(|parsed| parsed.with_day(NonZeroU8::new(29)?))
+            .map(|parsed| parsed.into_offset_date_time_or(defaults)),
+        Some(Ok(datetime!(2024-02-29 6:15 -5)))
+    );
+    assert_eq!(
+        Parsed::new()
+            .with_hour_24(12)
+            .and_then(|parsed| parsed.with_minute(30))
+            .map(|parsed| parsed.into_offset_date_time_or(defaults)),
+        Some(Ok(datetime!(2000-01-01 12:30 -5)))
+    );
+    assert_eq!(
+        Parsed::new()
+            .with_offset_hour(1)
+            .map(|parsed| parsed
+                .into_offset_date_time_or(ParsedDefaults::new(date!(2000 - 01 - 01)))),
+        Some(Ok(datetime!(2000-01-01 0:00 +1)))
+    );
+    assert_eq!(
+        Parsed::new()
+            .with_unix_timestamp_nanos(0)
+            .map(|parsed| parsed.into_offset_date_time_or(defaults)),
+        Some(Ok(datetime!(1970-01-01 0:00 UTC)))
+    );
+
+    // Components that are only partially present are not filled in.
+    assert_eq!(
+        Parsed::new()
+            .with_year(2024)
+            .map(|parsed| parsed.into_offset_date_time_or(defaults)),
+        Some(Err(error::TryFromParsed::InsufficientInformation))
+    );
+    assert_eq!(
+        Parsed::new()
+            .with_second(30)
+            .map(|parsed| parsed.into_offset_date_time_or(defaults)),
+        Some(Err(error::TryFromParsed::InsufficientInformation))
+    );
+}
+
 #[test]
 fn single_item_parse() {
     assert!(Time::parse("a", &BorrowedFormatItem::Literal(b"a")).is_err());
@@ -1,10 +1,10 @@
 use std::num::{NonZeroU16, NonZeroU8};
 
 use time::format_description::modifier::Ignore;
-use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339};
 use time::format_description::{modifier, BorrowedFormatItem, Component, OwnedFormatItem};
 use time::macros::{date, datetime, offset, time};
-use time::parsing::Parsed;
+use time::parsing::{Parsable, Parsed};
 use time::{
     error, format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
     UtcOffset, Weekday,
@@ -230,6 +230,92 @@ fn rfc_2822_err() {
     ));
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Wed Nov 16 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-16 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Thursday, 06-Nov-49 08:49:37 GMT", &HttpDate)?,
+        datetime!(2049-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 31 Dec 2016 23:59:60 GMT", &HttpDate)?,
+        datetime!(2016-12-31 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37),
+    );
+    assert_eq!(
+        Date::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        date!(1994 - 11 - 06),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn http_date_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("sun, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 6 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 94 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 +0000", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sunday, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov 06 08:49:37 1994 GMT", &HttpDate),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 31 Feb 1994 08:49:37 GMT", &HttpDate),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "day"
+    ));
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -521,6 +607,93 @@ fn rfc_3339_err() {
     ));
 }
 
+#[test]
+fn rfc_3339_max_subsecond_digits() -> time::Result<()> {
+    let format = Rfc3339.max_subsecond_digits(3);
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &format)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123Z", &format)?,
+        datetime!(2021-01-02 03:04:05.123 UTC),
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:05.12+01:00", &format)?,
+        datetime!(2021-01-02 03:04:05.12),
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1234Z", &format),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "subsecond digits"
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:05.1234Z", &format),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "subsecond digits"
+    ));
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1234Z", &format).map_err(|err| err.to_string()),
+        Err("subsecond digits must be in the range 0..=3".to_owned())
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.Z", &format),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123", &format),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:5x.1234Z", &format),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:5x.1234Z", &format),
+        invalid_component!("second")
+    ));
+
+    let format = Rfc3339.max_subsecond_digits(0);
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &format)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &format),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "subsecond digits"
+    ));
+
+    let long_subsecond = "2021-01-02T03:04:05.123456789123Z";
+    assert!(OffsetDateTime::parse(long_subsecond, &Rfc3339.max_subsecond_digits(11)).is_err());
+    assert_eq!(
+        OffsetDateTime::parse(long_subsecond, &Rfc3339.max_subsecond_digits(12))?,
+        OffsetDateTime::parse(long_subsecond, &Rfc3339)?,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3339_subsecond_digits() -> time::Result<()> {
+    // The number of subsecond digits only affects formatting.
+    let format = Rfc3339.subsecond_digits(3);
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &format)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789Z", &format)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC),
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:05.1+01:00", &format)?,
+        datetime!(2021-01-02 03:04:05.1),
+    );
+
+    Ok(())
+}
+
 #[test]
 fn iso_8601() {
     assert_eq!(
@@ -640,6 +813,105 @@ fn iso_8601_error() {
     ));
 }
 
+#[test]
+fn iso_8601_ordinal() -> time::Result<()> {
+    use time::format_description::well_known::iso8601::{
+        Config, DateKind, EncodedConfig, FormattedComponents,
+    };
+
+    const EXTENDED: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Date)
+        .set_date_kind(DateKind::Ordinal)
+        .encode();
+    const BASIC: EncodedConfig = Config::DEFAULT
+        .set_formatted_components(FormattedComponents::Date)
+        .set_date_kind(DateKind::Ordinal)
+        .set_use_separators(false)
+        .encode();
+
+    for (date, extended, basic) in [
+        (date!(2024 - 001), "2024-001", "2024001"),
+        (date!(2024 - 060), "2024-060", "2024060"),
+        (date!(2024 - 366), "2024-366", "2024366"),
+        (date!(2023 - 365), "2023-365", "2023365"),
+    ] {
+        assert_eq!(date.format(&Iso8601::<EXTENDED>)?, extended);
+        assert_eq!(date.format(&Iso8601::<BASIC>)?, basic);
+        assert_eq!(Date::parse(extended, &Iso8601::<EXTENDED>)?, date);
+        assert_eq!(Date::parse(basic, &Iso8601::<BASIC>)?, date);
+    }
+
+    assert!(matches!(
+        Date::parse("2023-366", &Iso8601::<EXTENDED>),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "ordinal"
+    ));
+    assert!(matches!(
+        Date::parse("2023366", &Iso8601::<BASIC>),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "ordinal"
+    ));
+    assert!(Date::parse("2024-000", &Iso8601::<EXTENDED>).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_end_of_day() -> time::Result<()> {
+    use time::format_description::well_known::iso8601::{Config, EncodedConfig};
+
+    const END_OF_DAY: EncodedConfig = Config::DEFAULT.set_end_of_day_allowed(true).encode();
+
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T24:00:00", &Iso8601::<END_OF_DAY>)?,
+        datetime!(2021-01-03 0:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-12-31T24:00", &Iso8601::<END_OF_DAY>)?,
+        datetime!(2022-01-01 0:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("20210102T240000.000", &Iso8601::<END_OF_DAY>)?,
+        datetime!(2021-01-03 0:00)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T24:00:00+01:00", &Iso8601::<END_OF_DAY>)?,
+        datetime!(2021-01-03 0:00 +1)
+    );
+    assert_eq!(
+        Time::parse("24:00:00", &Iso8601::<END_OF_DAY>)?,
+        Time::MIDNIGHT
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T23:59:59", &Iso8601::<END_OF_DAY>)?,
+        datetime!(2021-01-02 23:59:59)
+    );
+
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T24:00:01", &Iso8601::<END_OF_DAY>),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T24:01:00", &Iso8601::<END_OF_DAY>),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T24:00:00.1", &Iso8601::<END_OF_DAY>),
+        invalid_component!("hour")
+    ));
+    assert!(PrimitiveDateTime::parse("2021-01-02T24:00:00", &Iso8601::DEFAULT).is_err());
+    assert!(matches!(
+        PrimitiveDateTime::parse("+999999-12-31T24:00:00", &Iso8601::<END_OF_DAY>),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "year"
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn parse_time() -> time::Result<()> {
     let format_input_output = [
@@ -664,6 +936,21 @@ fn parse_time() -> time::Result<()> {
         (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
         (fd::parse("[hour repr:12] [period]")?, "12 AM", time!(12 AM)),
         (fd::parse("[hour repr:12] [period]")?, "12 PM", time!(12 PM)),
+        (
+            fd::parse("[hour repr:12]:[minute][period optional:true]")?,
+            "07:30",
+            time!(7:30 AM),
+        ),
+        (
+            fd::parse("[hour repr:12]:[minute][period optional:true]")?,
+            "07:30PM",
+            time!(7:30 PM),
+        ),
+        (
+            fd::parse("[hour repr:12]:[minute][period optional:true]")?,
+            "12:00",
+            time!(12:00 AM),
+        ),
     ];
 
     for (format_description, input, output) in &format_input_output {
@@ -684,6 +971,31 @@ fn parse_time() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn parse_optional_period() -> time::Result<()> {
+    let format_description = fd::parse("[hour repr:12]:[minute] [period optional:true]")?;
+    assert_eq!(Time::parse("07:30 ", &format_description)?, time!(7:30 AM));
+    assert_eq!(
+        Time::parse("07:30 PM", &format_description)?,
+        time!(7:30 PM)
+    );
+    assert_eq!(time!(7:30 AM).format(&format_description)?, "07:30 AM");
+    assert_eq!(time!(7:30 PM).format(&format_description)?, "07:30 PM");
+
+    assert!(matches!(
+        Time::parse("07:30 XM", &format_description),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        Time::parse("07:30 ", &fd::parse("[hour repr:12]:[minute] [period]")?),
+        invalid_component!("period")
+    ));
+
+    Ok(())
+}
+
 #[allow(clippy::cognitive_complexity)] // all test the same thing
 #[test]
 fn parse_time_err() -> time::Result<()> {
@@ -1043,6 +1355,62 @@ fn parse_offset_err() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn parse_offset_utc_alias() -> time::Result<()> {
+    let format = fd::parse_owned::<2>("[offset_hour utc_alias:true][optional [:[offset_minute]]]")?;
+    assert_eq!(UtcOffset::parse("GMT", &format)?, offset!(UTC));
+    assert_eq!(UtcOffset::parse("utc", &format)?, offset!(UTC));
+    assert_eq!(UtcOffset::parse("Utc", &format)?, offset!(UTC));
+    assert_eq!(UtcOffset::parse("-03:30", &format)?, offset!(-3:30));
+    assert_eq!(
+        UtcOffset::parse(
+            "GMT",
+            &fd::parse("[offset_hour sign:mandatory utc_alias:true]")?
+        )?,
+        offset!(UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2024-01-02 03:04:05 GMT",
+            &fd::parse(
+                "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour utc_alias:true]"
+            )?
+        )?,
+        datetime!(2024-01-02 03:04:05 UTC)
+    );
+    assert_eq!(
+        UtcOffset::parse(
+            "gmt",
+            &time::macros::format_description!("[offset_hour utc_alias:true]")
+        )?,
+        offset!(UTC)
+    );
+
+    // The aliases are not accepted by default.
+    assert!(UtcOffset::parse("UTC", &fd::parse("[offset_hour sign:mandatory]")?).is_err());
+    let format = fd::parse_owned::<2>("[offset_hour][optional [:[offset_minute]]]")?;
+    assert_eq!(UtcOffset::parse("+01:00", &format)?, offset!(+1));
+    for input in ["GMT", "utc"] {
+        assert!(matches!(
+            UtcOffset::parse(input, &format),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponent("offset hour")
+            ))
+        ));
+    }
+    assert!(matches!(
+        UtcOffset::parse("UTC", &time::macros::format_description!("[offset_hour]")),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("offset hour")
+        ))
+    ));
+    // Only the exact aliases are accepted.
+    assert!(UtcOffset::parse("UT", &fd::parse("[offset_hour utc_alias:true]")?).is_err());
+    assert!(UtcOffset::parse("UTC+1", &fd::parse("[offset_hour utc_alias:true]")?).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn parse_primitive_date_time() -> time::Result<()> {
     assert_eq!(
@@ -1110,51 +1478,158 @@ fn parse_offset_date_time_err() -> time::Result<()> {
     Ok(())
 }
 
-#[allow(clippy::cognitive_complexity)] // all test the same thing
 #[test]
-fn parse_components() -> time::Result<()> {
-    macro_rules! parse_component {
-        ($component:expr, $input:expr,_. $property:ident() == $expected:expr) => {
-            let mut parsed = Parsed::new();
-            parsed.parse_component($input, $component)?;
-            assert_eq!(parsed.$property(), $expected);
-        };
-    }
-
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        })),
-        b"2021",
-        _.year() == Some(2021)
+fn parse_interval() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00Z/2024-01-02T00:00Z", &Iso8601::DEFAULT)?,
+        (
+            datetime!(2024-01-01 0:00 UTC),
+            datetime!(2024-01-02 0:00 UTC)
+        )
     );
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::LastTwo,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        })),
-        b"21",
-        _.year_last_two() == Some(21)
+    assert_eq!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00Z/P1D", &Iso8601::DEFAULT)?,
+        (
+            datetime!(2024-01-01 0:00 UTC),
+            datetime!(2024-01-02 0:00 UTC)
+        )
     );
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            iso_week_based: true,
-            sign_is_mandatory: false,
-        })),
-        b"2021",
-        _.iso_year() == Some(2021)
+    assert_eq!(
+        OffsetDateTime::parse_interval("P1W/2024-01-08T00:00:00+01:00", &Rfc3339)?,
+        (datetime!(2024-01-01 0:00 +1), datetime!(2024-01-08 0:00 +1))
     );
-    parse_component!(
-        Component::Year(modifier!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::LastTwo,
+    assert_eq!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/PT0.5S", &Rfc3339)?,
+        (
+            datetime!(2024-01-01 0:00 UTC),
+            datetime!(2024-01-01 0:00:00.5 UTC)
+        )
+    );
+    // The end is permitted to be before the start.
+    assert_eq!(
+        OffsetDateTime::parse_interval("2024-01-02T00:00:00Z/2024-01-01T00:00:00Z", &Rfc3339)?,
+        (
+            datetime!(2024-01-02 0:00 UTC),
+            datetime!(2024-01-01 0:00 UTC)
+        )
+    );
+    assert_eq!(
+        OffsetDateTime::parse_interval("-P1D/2024-01-01T00:00:00Z", &Rfc3339)?,
+        (
+            datetime!(2024-01-02 0:00 UTC),
+            datetime!(2024-01-01 0:00 UTC)
+        )
+    );
+    assert_eq!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/+PT1H", &Rfc3339)?,
+        (
+            datetime!(2024-01-01 0:00 UTC),
+            datetime!(2024-01-01 1:00 UTC)
+        )
+    );
+
+    assert!(matches!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00:00Z", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_interval("P1D/PT1H", &Rfc3339),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/P1M", &Rfc3339),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/P999999999D", &Rfc3339),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/P1é", &Rfc3339),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_interval("-P1D/+PT1H", &Rfc3339),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_interval("2024-01-01/P1D", &Rfc3339),
+        Err(error::Parse::ParseFromDescription(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn weekday_number_roundtrip() -> time::Result<()> {
+    for (format, expected) in [
+        ("[weekday repr:sunday one_indexed:false]", "1234560"),
+        ("[weekday repr:sunday one_indexed:true]", "2345671"),
+        ("[weekday repr:monday one_indexed:false]", "0123456"),
+        ("[weekday repr:monday one_indexed:true]", "1234567"),
+    ] {
+        let format = fd::parse(format)?;
+        // 2024-01-01 is a Monday.
+        for (date, expected) in date!(2024 - 01 - 01)
+            .iter_days()
+            .zip(expected.chars().map(String::from))
+        {
+            assert_eq!(date.format(&format)?, expected);
+
+            let mut parsed = Parsed::new();
+            parsed.parse_items(expected.as_bytes(), &format)?;
+            assert_eq!(parsed.weekday(), Some(date.weekday()));
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::cognitive_complexity)] // all test the same thing
+#[test]
+fn parse_components() -> time::Result<()> {
+    macro_rules! parse_component {
+        ($component:expr, $input:expr,_. $property:ident() == $expected:expr) => {
+            let mut parsed = Parsed::new();
+            parsed.parse_component($input, $component)?;
+            assert_eq!(parsed.$property(), $expected);
+        };
+    }
+
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::Full,
+            iso_week_based: false,
+            sign_is_mandatory: false,
+        })),
+        b"2021",
+        _.year() == Some(2021)
+    );
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::LastTwo,
+            iso_week_based: false,
+            sign_is_mandatory: false,
+        })),
+        b"21",
+        _.year_last_two() == Some(21)
+    );
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::Full,
+            iso_week_based: true,
+            sign_is_mandatory: false,
+        })),
+        b"2021",
+        _.iso_year() == Some(2021)
+    );
+    parse_component!(
+        Component::Year(modifier!(Year {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::LastTwo,
             iso_week_based: true,
             sign_is_mandatory: false,
         })),
@@ -1390,6 +1865,16 @@ fn parse_components() -> time::Result<()> {
         b"aM",
         _.hour_12_is_pm() == Some(false)
     );
+    parse_component!(
+        Component::Period(modifier!(Period { is_optional: true })),
+        b"PM",
+        _.hour_12_is_pm() == Some(true)
+    );
+    parse_component!(
+        Component::Period(modifier!(Period { is_optional: true })),
+        b"",
+        _.hour_12_is_pm() == Some(false)
+    );
     let mut parsed = Parsed::new();
     let result = parsed.parse_component(
         b"abcdef",
@@ -1602,6 +2087,510 @@ fn parse_first() -> time::Result<()> {
         .expect_err("parsing should fail");
     assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
 
+    // Ensure alternatives can be provided in a runtime-parsed format description.
+    let format = fd::parse_owned::<2>("[first [[year]-[month]] [[year]/[month]]]")?;
+    for input in [b"2021-01".as_slice(), b"2021/01"] {
+        let mut parsed = Parsed::new();
+        let remaining_input = parsed.parse_item(input, &format)?;
+        assert!(remaining_input.is_empty());
+        assert_eq!(parsed.year(), Some(2021));
+        assert_eq!(parsed.month(), Some(Month::January));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_partial() -> time::Result<()> {
+    assert_eq!(
+        Date::parse_partial("2021-01-02T03:04:05", &fd::parse("[year]-[month]-[day]")?)?,
+        (date!(2021 - 01 - 02), 10)
+    );
+    assert_eq!(
+        Time::parse_partial("03:04:05 and more", &fd::parse("[hour]:[minute]:[second]")?)?,
+        (time!(03:04:05), 8)
+    );
+    assert_eq!(
+        UtcOffset::parse_partial("+01:02", &fd::parse("[offset_hour]:[offset_minute]")?)?,
+        (offset!(+01:02), 6)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_partial(
+            "2021-01-02 03:04:05,",
+            &fd::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?
+        )?,
+        (datetime!(2021-01-02 03:04:05), 19)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_partial("2021-01-02T03:04:05Z\"", &Rfc3339)?,
+        (datetime!(2021-01-02 03:04:05 UTC), 20)
+    );
+    assert!(matches!(
+        Date::parse_partial("2021-01-", &fd::parse("[year]-[month]-[day]")?),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        Date::parse_partial("2021-01-02", &fd::parse("[year]-[month]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation { .. }
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_year_last_two() -> time::Result<()> {
+    let format = fd::parse("[year repr:last_two]-[month]-[day]")?;
+    assert_eq!(Date::parse("68-01-02", &format)?, date!(1968 - 01 - 02));
+    assert_eq!(Date::parse("49-01-02", &format)?, date!(2049 - 01 - 02));
+    assert_eq!(Date::parse("50-01-02", &format)?, date!(1950 - 01 - 02));
+    assert_eq!(Date::parse("00-01-02", &format)?, date!(2000 - 01 - 02));
+    assert_eq!(Date::parse("99-01-02", &format)?, date!(1999 - 01 - 02));
+
+    let format = fd::parse("[year repr:last_two range_start:1900]-[month]-[day]")?;
+    assert_eq!(Date::parse("00-01-02", &format)?, date!(1900 - 01 - 02));
+    assert_eq!(Date::parse("99-01-02", &format)?, date!(1999 - 01 - 02));
+
+    let format = fd::parse("[year repr:last_two range_start:1969]-[month]-[day]")?;
+    assert_eq!(Date::parse("69-01-02", &format)?, date!(1969 - 01 - 02));
+    assert_eq!(Date::parse("68-01-02", &format)?, date!(2068 - 01 - 02));
+
+    let format = fd::parse("[year repr:last_two range_start:-150]-[month]-[day]")?;
+    assert_eq!(Date::parse("50-01-02", &format)?, date!(-0150 - 01 - 02));
+    assert_eq!(Date::parse("49-01-02", &format)?, date!(-0051 - 01 - 02));
+
+    assert!(fd::parse("[year repr:last_two range_start:32768]").is_err());
+
+    let format = fd::parse("[year base:iso_week repr:last_two]-W[week_number]-[weekday]")?;
+    assert_eq!(
+        Date::parse("20-W01-Monday", &format)?,
+        date!(2019 - 12 - 30)
+    );
+
+    // A full year takes precedence over the last two digits, regardless of order.
+    let format = fd::parse("[year repr:last_two] [year]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse("68 2168-01-02", &format)?,
+        date!(2168 - 01 - 02)
+    );
+    let format = fd::parse("[year]-[month]-[day] [year repr:last_two]")?;
+    assert_eq!(
+        Date::parse("2168-01-02 68", &format)?,
+        date!(2168 - 01 - 02)
+    );
+
+    // The full year is only resolved when constructing the date.
+    let (parsed, _) = fd::parse("[year repr:last_two]")?.parse_prefix(b"68")?;
+    assert_eq!(parsed.year_last_two(), Some(68));
+    assert_eq!(parsed.year(), None);
+
+    // Formatting and parsing round-trip within the range.
+    let format = fd::parse("[year repr:last_two range_start:1970]-[month]-[day]")?;
+    for date in [
+        date!(1970 - 01 - 01),
+        date!(1999 - 12 - 31),
+        date!(2000 - 01 - 01),
+        date!(2069 - 12 - 31),
+    ] {
+        assert_eq!(Date::parse(&date.format(&format)?, &format)?, date);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_min_width() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "2019-      May-03",
+            &fd::parse("[year]-[month repr:long min_width:9]-[day]")?
+        )?,
+        date!(2019 - 05 - 03)
+    );
+    assert_eq!(
+        Date::parse(
+            "2019-May......-03",
+            &fd::parse("[year]-[month repr:long min_width:9 align:left fill:.]-[day]")?
+        )?,
+        date!(2019 - 05 - 03)
+    );
+    assert_eq!(
+        Date::parse(
+            "2019-September-03",
+            &fd::parse("[year]-[month repr:long min_width:5]-[day]")?
+        )?,
+        date!(2019 - 09 - 03)
+    );
+    // Fill that is also valid as part of the value is only skipped as far as necessary.
+    assert_eq!(
+        Date::parse(
+            "2019-05-0003",
+            &fd::parse("[year]-[month]-[day min_width:4 fill:0]")?
+        )?,
+        date!(2019 - 05 - 03)
+    );
+    assert_eq!(
+        Date::parse(
+            "2019-05-0012",
+            &fd::parse("[year]-[month]-[day padding:none min_width:4 fill:0]")?
+        )?,
+        date!(2019 - 05 - 12)
+    );
+    // Fill beyond the minimum width is not skipped.
+    assert!(Date::parse(
+        "2019-May.....-03",
+        &fd::parse("[year]-[month repr:short min_width:4 align:left fill:.]-[day]")?
+    )
+    .is_err());
+    assert!(Date::parse(
+        "2019-  May-03",
+        &fd::parse("[year]-[month repr:short min_width:4]-[day]")?
+    )
+    .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parse_prefix() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day]")?;
+    let (parsed, remaining) = format.parse_prefix(b"2021-01-02 rest of line")?;
+    assert_eq!(Date::try_from(parsed)?, date!(2021 - 01 - 02));
+    assert_eq!(remaining, b" rest of line");
+
+    let (parsed, remaining) = format.parse_prefix(b"2021-01-02")?;
+    assert_eq!(Date::try_from(parsed)?, date!(2021 - 01 - 02));
+    assert!(remaining.is_empty());
+
+    let (parsed, remaining) = Rfc3339.parse_prefix(b"2021-01-02T03:04:05Z\nnext")?;
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+    assert_eq!(remaining, b"\nnext");
+
+    assert!(matches!(
+        format.parse_prefix(b"2021-01-"),
+        invalid_component!("day")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_era() -> time::Result<()> {
+    let format = fd::parse("[year era:true] [era] [month]-[day]")?;
+    assert_eq!(
+        Date::parse("2019 AD 12-31", &format)?,
+        date!(2019 - 12 - 31)
+    );
+    assert_eq!(
+        Date::parse("0001 ad 01-01", &format)?,
+        date!(0001 - 01 - 01)
+    );
+    assert_eq!(
+        Date::parse("0001 BC 12-31", &format)?,
+        date!(0000 - 12 - 31)
+    );
+    assert_eq!(
+        Date::parse("0002 Bc 12-31", &format)?,
+        date!(-0001 - 12 - 31)
+    );
+    assert_eq!(
+        Date::parse("0045 bC 03-15", &format)?,
+        date!(-0044 - 03 - 15)
+    );
+
+    // There is no year zero when numbering relative to the era.
+    assert!(matches!(
+        Date::parse("0000 BC 01-01", &format),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Date::parse("-0001 BC 01-01", &format),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Date::parse("0001 CE 01-01", &format),
+        invalid_component!("era")
+    ));
+
+    // Without an era, an era-based year is assumed to be AD.
+    assert_eq!(
+        Date::parse("0001-01-01", &fd::parse("[year era:true]-[month]-[day]")?)?,
+        date!(0001 - 01 - 01)
+    );
+
+    // The era may precede the year.
+    assert_eq!(
+        Date::parse(
+            "BC 0001-01-01",
+            &fd::parse("[era] [year era:true]-[month]-[day]")?
+        )?,
+        date!(0000 - 01 - 01)
+    );
+
+    // An astronomical year is not converted, but must agree with the era.
+    assert!(matches!(
+        Date::parse("2020 BC 01-01", &fd::parse("[year] [era] [month]-[day]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "year"
+    ));
+    let parsed = Parsed::new()
+        .with_year(1)
+        .and_then(|parsed| parsed.with_era_is_bc(true))
+        .and_then(|parsed| parsed.with_ordinal(NonZeroU16::new(1)?))
+        .expect("values are in range");
+    assert!(matches!(
+        Date::try_from(parsed),
+        Err(error::TryFromParsed::ComponentRange(component)) if component.name() == "year"
+    ));
+
+    // The last two digits cannot be resolved relative to an era.
+    assert!(matches!(
+        Date::parse(
+            "45 BC 03-15",
+            &fd::parse("[year era:true repr:last_two] [era] [month]-[day]")?
+        ),
+        invalid_component!("year")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn era_round_trip() -> time::Result<()> {
+    for format in [
+        "[year]-[month]-[day] [era]",
+        "[year era:true]-[month]-[day] [era]",
+        "[year base:iso_week]-W[week_number]-[weekday repr:monday] [era]",
+        "[year base:iso_week era:true]-W[week_number]-[weekday repr:monday] [era]",
+    ] {
+        let format = fd::parse(format)?;
+        for date in [
+            date!(-0044 - 03 - 15),
+            date!(-0005 - 06 - 01),
+            date!(0000 - 06 - 01),
+            date!(0006 - 06 - 01),
+            date!(2024 - 06 - 01),
+        ] {
+            assert_eq!(Date::parse(&date.format(&format)?, &format)?, date);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_name_case() -> time::Result<()> {
+    let date = date!(2019 - 12 - 31);
+    for (case, weekday, month) in [
+        ("title", "Tue", "December"),
+        ("upper", "TUE", "DECEMBER"),
+        ("lower", "tue", "december"),
+    ] {
+        let format = fd::parse_owned::<2>(&format!(
+            "[weekday repr:short case:{case}] [day] [month repr:long case:{case}] [year]"
+        ))?;
+        let formatted = date.format(&format)?;
+        assert_eq!(formatted, format!("{weekday} 31 {month} 2019"));
+        assert_eq!(Date::parse(&formatted, &format)?, date);
+    }
+
+    // When case-sensitive, the casing must match exactly.
+    assert!(matches!(
+        Date::parse(
+            "Tue 31 December 2019",
+            &fd::parse("[weekday repr:short case:upper] [day] [month repr:long] [year]")?
+        ),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        Date::parse(
+            "Tue 31 December 2019",
+            &fd::parse("[weekday repr:short] [day] [month repr:long case:lower] [year]")?
+        ),
+        invalid_component!("month")
+    ));
+
+    // When case-insensitive, any casing is accepted.
+    let format = fd::parse(
+        "[weekday repr:short case:upper case_sensitive:false] [day] [month repr:long case:lower \
+         case_sensitive:false] [year]",
+    )?;
+    assert_eq!(Date::parse("tUe 31 DeCeMbEr 2019", &format)?, date);
+    assert_eq!(Date::parse("Tue 31 December 2019", &format)?, date);
+
+    Ok(())
+}
+
+#[test]
+fn parse_quarter() -> time::Result<()> {
+    let format = fd::parse("[year]-Q[quarter]")?;
+    for (quarter, date) in [
+        (1, date!(2019 - 01 - 01)),
+        (2, date!(2019 - 04 - 01)),
+        (3, date!(2019 - 07 - 01)),
+        (4, date!(2019 - 10 - 01)),
+    ] {
+        let formatted = date.format(&format)?;
+        assert_eq!(formatted, format!("2019-Q{quarter}"));
+        assert_eq!(Date::parse(&formatted, &format)?, date);
+    }
+
+    assert!(matches!(
+        Date::parse("2019-Q0", &format),
+        invalid_component!("quarter")
+    ));
+    assert!(matches!(
+        Date::parse("2019-Q5", &format),
+        invalid_component!("quarter")
+    ));
+
+    // A day may be provided alongside the quarter.
+    assert_eq!(
+        Date::parse("2019-Q3-15", &fd::parse("[year]-Q[quarter]-[day]")?)?,
+        date!(2019 - 07 - 15)
+    );
+    // The month is used when the quarter agrees with it.
+    assert_eq!(
+        Date::parse(
+            "2019-Q3-08-15",
+            &fd::parse("[year]-Q[quarter]-[month]-[day]")?
+        )?,
+        date!(2019 - 08 - 15)
+    );
+    // A quarter that contradicts the month is rejected.
+    assert!(matches!(
+        Date::parse(
+            "2020-05-01 Q4",
+            &fd::parse("[year]-[month]-[day] Q[quarter]")?
+        ),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "quarter"
+    ));
+    // The quarter is not used when the month is present without a day.
+    assert!(matches!(
+        Date::parse("2019-Q3-08", &fd::parse("[year]-Q[quarter]-[month]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_fractional_hour_and_minute() -> time::Result<()> {
+    assert_eq!(
+        Time::parse("12:30.5", &fd::parse("[hour]:[minute fraction:1]")?)?,
+        time!(12:30:30)
+    );
+    assert_eq!(
+        Time::parse("12:30.25", &fd::parse("[hour]:[minute fraction:1+]")?)?,
+        time!(12:30:15)
+    );
+    assert_eq!(
+        Time::parse("12:30.333", &fd::parse("[hour]:[minute fraction:3]")?)?,
+        time!(12:30:19.98)
+    );
+    assert_eq!(
+        Time::parse("12.75", &fd::parse("[hour fraction:2]")?)?,
+        time!(12:45)
+    );
+    assert_eq!(
+        Time::parse(
+            "06.11 PM",
+            &fd::parse("[hour repr:12 fraction:1+] [period]")?
+        )?,
+        time!(18:06:36)
+    );
+
+    // Formatting and parsing round-trip when the fraction is exact.
+    let format = fd::parse("[hour]:[minute fraction:1+]")?;
+    for time in [time!(0:00), time!(12:30:30), time!(23:59:59.94)] {
+        assert_eq!(Time::parse(&time.format(&format)?, &format)?, time);
+    }
+
+    // The decimal point and the specified number of digits are required.
+    assert!(matches!(
+        Time::parse("12:30", &fd::parse("[hour]:[minute fraction:1]")?),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        Time::parse("12:30.5", &fd::parse("[hour]:[minute fraction:2]")?),
+        invalid_component!("minute")
+    ));
+
+    // The lower units may not also be provided explicitly.
+    assert!(matches!(
+        Time::parse(
+            "12:30.5:10",
+            &fd::parse("[hour]:[minute fraction:1]:[second]")?
+        ),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        Time::parse(
+            "10 12:30.5",
+            &fd::parse("[second] [hour]:[minute fraction:1]")?
+        ),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        Time::parse("12.5:30", &fd::parse("[hour fraction:1]:[minute]")?),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        Time::parse("30 12.5", &fd::parse("[minute] [hour fraction:1]")?),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        Time::parse(
+            "12:30.5.123",
+            &fd::parse("[hour]:[minute fraction:1].[subsecond]")?
+        ),
+        invalid_component!("subsecond")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_seconds_of_day() -> time::Result<()> {
+    use time::parsing::AllowLeapSeconds;
+
+    let format = fd::parse("[seconds_of_day]")?;
+    assert_eq!(Time::parse("45296", &format)?, time!(12:34:56));
+    assert_eq!(time!(12:34:56).format(&format)?, "45296");
+    assert_eq!(Time::parse("00000", &format)?, Time::MIDNIGHT);
+    assert_eq!(Time::parse("86399", &format)?, time!(23:59:59));
+    assert_eq!(
+        Time::parse("42", &fd::parse("[seconds_of_day padding:none]")?)?,
+        time!(0:00:42)
+    );
+
+    assert!(matches!(
+        Time::parse("86400", &format),
+        invalid_component!("seconds_of_day")
+    ));
+    assert!(matches!(
+        Time::parse("99999", &format),
+        invalid_component!("seconds_of_day")
+    ));
+
+    // The end of the day is only accepted as a leap second.
+    let format = fd::parse("[year]-[month]-[day] [seconds_of_day] [offset_hour sign:mandatory]")?;
+    assert_eq!(
+        OffsetDateTime::parse("2016-12-31 86400 +00", &AllowLeapSeconds(&format))?,
+        datetime!(2016-12-31 23:59:59.999_999_999 UTC)
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2016-12-31 86400 +00", &format),
+        invalid_component!("seconds_of_day")
+    ));
+
     Ok(())
 }
 
@@ -1632,6 +2621,108 @@ fn parse_unix_timestamp() -> time::Result<()> {
         )?,
         datetime!(2009-02-13 23:31:30.123456789 UTC)
     );
+    assert_eq!(
+        OffsetDateTime::parse("-1", &fd::parse("[unix_timestamp]")?)?,
+        datetime!(1969-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("0", &fd::parse("[unix_timestamp]")?)?,
+        OffsetDateTime::UNIX_EPOCH
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-100000", &fd::parse("[unix_timestamp sign:mandatory]")?)?,
+        datetime!(1969-12-30 20:13:20 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("+0", &fd::parse("[unix_timestamp sign:mandatory]")?)?,
+        OffsetDateTime::UNIX_EPOCH
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unix_timestamp_roundtrip() -> time::Result<()> {
+    let second = fd::parse("[unix_timestamp]")?;
+    let millisecond = fd::parse("[unix_timestamp precision:millisecond]")?;
+    let microsecond = fd::parse("[unix_timestamp precision:microsecond]")?;
+    let nanosecond = fd::parse("[unix_timestamp precision:nanosecond]")?;
+
+    for (dt, precision, format, output) in [
+        (
+            datetime!(2023-11-14 22:13:20 UTC),
+            "second",
+            &second,
+            "1700000000",
+        ),
+        (datetime!(1969-12-31 23:59:59 UTC), "second", &second, "-1"),
+        (
+            datetime!(1900-01-01 0:00 UTC),
+            "second",
+            &second,
+            "-2208988800",
+        ),
+        (
+            datetime!(2023-11-14 22:13:20.123 UTC),
+            "millisecond",
+            &millisecond,
+            "1700000000123",
+        ),
+        (
+            datetime!(1969-12-31 23:59:59.999 UTC),
+            "millisecond",
+            &millisecond,
+            "-1",
+        ),
+        (
+            datetime!(1969-12-31 23:59:58.5 UTC),
+            "millisecond",
+            &millisecond,
+            "-1500",
+        ),
+        (
+            datetime!(2023-11-14 22:13:20.123_456 UTC),
+            "microsecond",
+            &microsecond,
+            "1700000000123456",
+        ),
+        (
+            datetime!(1969-12-31 23:59:59.999_999 UTC),
+            "microsecond",
+            &microsecond,
+            "-1",
+        ),
+        (
+            datetime!(2023-11-14 22:13:20.123_456_789 UTC),
+            "nanosecond",
+            &nanosecond,
+            "1700000000123456789",
+        ),
+        (
+            datetime!(1969-12-31 23:59:59.999_999_999 UTC),
+            "nanosecond",
+            &nanosecond,
+            "-1",
+        ),
+    ] {
+        assert_eq!(dt.format(format)?, output, "formatting at {precision}");
+        assert_eq!(
+            OffsetDateTime::parse(output, format)?,
+            dt,
+            "parsing at {precision}"
+        );
+    }
+
+    // Values more precise than the format are floored, consistent with `unix_timestamp`.
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_5 UTC).format(&millisecond)?,
+        "-1"
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999_5 UTC).format(&microsecond)?,
+        "-1"
+    );
+    assert_eq!(datetime!(1969-12-31 23:59:59.5 UTC).format(&second)?, "-1");
 
     Ok(())
 }
@@ -1714,3 +2805,226 @@ fn end() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn ignore() -> time::Result<()> {
+    let format = fd::parse("[ignore count:3][year]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse("ABC2021-01-02", &format),
+        Ok(date!(2021 - 01 - 02))
+    );
+    assert_eq!(
+        Date::parse("é 2021-01-02", &format),
+        Ok(date!(2021 - 01 - 02))
+    );
+    assert!(matches!(
+        Date::parse("AB", &format),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("ignore")
+        ))
+    ));
+    Ok(())
+}
+
+#[test]
+fn lenient_whitespace() -> time::Result<()> {
+    use time::parsing::LenientWhitespace;
+
+    let format = fd::parse("[year] [month] [day]")?;
+    assert!(Date::parse("2024  01 02", &format).is_err());
+    assert_eq!(
+        Date::parse("2024  01 02", &LenientWhitespace(&format))?,
+        date!(2024 - 01 - 02)
+    );
+    assert_eq!(
+        Date::parse(
+            "2024 01 02",
+            &LenientWhitespace(fd::parse("[year] [month] [day]")?)
+        )?,
+        date!(2024 - 01 - 02)
+    );
+    assert_eq!(
+        Date::parse(
+            "2024 \t\r\n 01  -  02",
+            &LenientWhitespace(fd::parse("[year] [month] - [day]")?)
+        )?,
+        date!(2024 - 01 - 02)
+    );
+    assert_eq!(
+        Date::parse(
+            "2024    01 02",
+            &LenientWhitespace(fd::parse_owned::<2>("[year]   [month] [day]")?)
+        )?,
+        date!(2024 - 01 - 02)
+    );
+    assert_eq!(
+        Time::parse(
+            "12  34",
+            &LenientWhitespace(fd::parse_owned::<2>("[hour][optional [ [minute]]]")?)
+        )?,
+        time!(12:34)
+    );
+
+    // At least one whitespace character is still required.
+    assert!(matches!(
+        Date::parse(
+            "2024 0102",
+            &LenientWhitespace(fd::parse("[year] [month] [day]")?)
+        ),
+        invalid_literal!()
+    ));
+    // Non-whitespace characters must still match exactly.
+    assert!(matches!(
+        Date::parse(
+            "2024 01 / 02",
+            &LenientWhitespace(fd::parse("[year] [month] - [day]")?)
+        ),
+        invalid_literal!()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn reject_conflicts() -> time::Result<()> {
+    use time::parsing::RejectConflicts;
+
+    let format = fd::parse_owned::<2>("[hour]:[minute] [first [[hour]h] [[minute]m]]")?;
+    // The last value wins by default.
+    assert_eq!(Time::parse("12:34 13h", &format)?, time!(13:34));
+    assert!(matches!(
+        Time::parse("12:34 13h", &RejectConflicts(&format)),
+        invalid_component!("hour")
+    ));
+    assert!(Time::parse("12:34 20m", &RejectConflicts(&format)).is_err());
+    // Repeating the same value is not a conflict.
+    assert_eq!(
+        Time::parse("12:34 12h", &RejectConflicts(&format))?,
+        time!(12:34)
+    );
+    assert_eq!(
+        Time::parse("12:34 34m", &RejectConflicts(&format))?,
+        time!(12:34)
+    );
+
+    let format = fd::parse_owned::<2>("[offset_hour]:[offset_minute] [offset_hour]")?;
+    assert_eq!(
+        UtcOffset::parse("+01:30 +01", &RejectConflicts(&format))?,
+        offset!(+01:30)
+    );
+    assert!(matches!(
+        UtcOffset::parse("+00:30 -00", &RejectConflicts(&format)),
+        invalid_component!("offset hour")
+    ));
+
+    // The last two digits of the year do not conflict with a full year in another range.
+    let format = fd::parse("[year repr:last_two] [year]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse("68 2068-01-02", &RejectConflicts(&format))?,
+        date!(2068 - 01 - 02)
+    );
+    let format = fd::parse("[year]-[month]-[day] [year repr:last_two]")?;
+    assert_eq!(
+        Date::parse("2068-01-02 68", &RejectConflicts(&format))?,
+        date!(2068 - 01 - 02)
+    );
+
+    // The mode does not persist beyond the wrapped description.
+    let (mut parsed, _) = RejectConflicts(fd::parse("[year]")?).parse_prefix(b"2024")?;
+    assert_eq!(parsed.set_year(2023), Some(()));
+    assert_eq!(parsed.year(), Some(2023));
+
+    Ok(())
+}
+
+#[test]
+fn allow_leap_seconds() -> time::Result<()> {
+    use time::parsing::AllowLeapSeconds;
+
+    let format =
+        fd::parse("[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]")?;
+    assert!(matches!(
+        OffsetDateTime::parse("2016-12-31T23:59:60+00", &format),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "second"
+    ));
+    assert_eq!(
+        OffsetDateTime::parse("2016-12-31T23:59:60+00", &AllowLeapSeconds(&format))?,
+        datetime!(2016-12-31 23:59:59.999_999_999 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2016-12-31T23:59:59+00", &AllowLeapSeconds(&format))?,
+        datetime!(2016-12-31 23:59:59 UTC)
+    );
+    // Leap seconds only occur at the end of a month UTC.
+    assert!(matches!(
+        OffsetDateTime::parse("2016-12-30T23:59:60+00", &AllowLeapSeconds(&format)),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "second"
+    ));
+    // Other types cannot validate the leap second.
+    assert!(
+        PrimitiveDateTime::parse("2016-12-31T23:59:60+00", &AllowLeapSeconds(&format)).is_err()
+    );
+
+    let format = fd::parse("[hour]:[minute]:[second] [offset_hour sign:mandatory]")?;
+    let (parsed, _) = AllowLeapSeconds(&format).parse_prefix(b"23:59:60 +01")?;
+    assert_eq!(parsed.second(), Some(60));
+    assert!(Time::try_from(parsed).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn unicode_digits() -> time::Result<()> {
+    use time::parsing::UnicodeDigits;
+
+    let format = fd::parse("[year]-[ordinal]")?;
+    assert!(matches!(
+        Date::parse("２０２１-１２３", &format),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert_eq!(
+        Date::parse("２０２１-１２３", &UnicodeDigits(&format))?,
+        date!(2021 - 123)
+    );
+    assert_eq!(
+        Date::parse("2021-१२३", &UnicodeDigits(&format))?,
+        date!(2021 - 123)
+    );
+    assert_eq!(
+        Date::parse("2021-123", &UnicodeDigits(&format))?,
+        date!(2021 - 123)
+    );
+    // Digits from different scripts may be mixed.
+    assert_eq!(
+        Date::parse("２0٢١-๑2३", &UnicodeDigits(&format))?,
+        date!(2021 - 123)
+    );
+    // Other non-ASCII characters are not digits.
+    assert!(Date::parse("2021-12Ⅲ", &UnicodeDigits(&format)).is_err());
+
+    // The remaining input is a suffix of the original input.
+    let format = fd::parse("[hour]:[minute]")?;
+    let (parsed, remaining) = UnicodeDigits(&format).parse_prefix("１２:３４ ５６".as_bytes())?;
+    assert_eq!(parsed.hour_24(), Some(12));
+    assert_eq!(parsed.minute(), Some(34));
+    assert_eq!(remaining, " ５６".as_bytes());
+
+    // Digits in literals are not replaced.
+    let format = fd::parse("[hour]h0")?;
+    assert!(UnicodeDigits(&format)
+        .parse_prefix("１２h０".as_bytes())
+        .is_err());
+
+    // The number of bytes to ignore is not affected.
+    let format = fd::parse("[ignore count:3][year]")?;
+    let (parsed, _) = UnicodeDigits(&format).parse_prefix("１２０２１".as_bytes())?;
+    assert_eq!(parsed.year(), Some(2021));
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{Duration, Month, PrimitiveDateTime, Weekday};
+use time::{Duration, Month, PrimitiveDateTime, UtcOffset, Weekday};
 
 #[test]
 fn new() {
@@ -136,6 +136,19 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn to_julian_date() {
+    assert_eq!(datetime!(-4713-11-24 12:00).to_julian_date(), 0.0);
+    assert_eq!(datetime!(-4713-11-24 0:00).to_julian_date(), -0.5);
+    assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 6:00).to_julian_date(), 2_451_544.75);
+    assert_eq!(datetime!(2019-12-31 18:00).to_julian_date(), 2_458_849.25);
+    assert_eq!(
+        datetime!(2019-12-31 23:59:59.999_999_999).to_julian_date(),
+        2_458_849.5
+    );
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
@@ -231,6 +244,33 @@ fn assume_utc() {
     );
 }
 
+#[test]
+fn possible_offsets() {
+    let datetimes = datetime!(2024-11-03 1:30)
+        .possible_offsets([offset!(-4), offset!(-5)])
+        .collect::<Vec<_>>();
+    assert_eq!(
+        datetimes,
+        [datetime!(2024-11-03 1:30 -4), datetime!(2024-11-03 1:30 -5)]
+    );
+    assert_eq!(datetimes[0].offset(), offset!(-4));
+    assert_eq!(datetimes[1].offset(), offset!(-5));
+    assert_eq!(datetimes[1] - datetimes[0], 1.hours());
+
+    assert_eq!(
+        datetime!(2024-01-01 0:00)
+            .possible_offsets(core::iter::empty())
+            .next(),
+        None
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX
+            .possible_offsets(vec![UtcOffset::UTC])
+            .collect::<Vec<_>>(),
+        [PrimitiveDateTime::MAX.assume_utc()]
+    );
+}
+
 #[test]
 fn replace_time() {
     assert_eq!(
@@ -381,6 +421,32 @@ fn replace_nanosecond() {
     ); // 1_000_000_000 isn't a valid nanosecond
 }
 
+#[test]
+fn replace_chained() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2022 - 02 - 18 01:02:03.004_005_006)
+            .replace_year(2023)?
+            .replace_month(Month::March)?
+            .replace_day(31)?
+            .replace_hour(9)?
+            .replace_minute(0)?
+            .replace_second(0)?
+            .replace_nanosecond(0)?,
+        datetime!(2023 - 03 - 31 9:00)
+    );
+    // The components are validated individually, so the order matters.
+    assert!(datetime!(2022 - 01 - 31 0:00)
+        .replace_month(Month::February)
+        .is_err());
+    assert_eq!(
+        datetime!(2022 - 01 - 31 0:00)
+            .replace_day(28)?
+            .replace_month(Month::February)?,
+        datetime!(2022 - 02 - 28 0:00)
+    );
+    Ok(())
+}
+
 #[test]
 fn add_duration() {
     assert_eq!(
@@ -744,6 +810,42 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_months() {
+    assert_eq!(
+        datetime!(2021-01-31 12:34:56.789).checked_add_months(1),
+        Some(datetime!(2021-02-28 12:34:56.789))
+    );
+    assert_eq!(
+        datetime!(2020-01-31 12:34:56.789).checked_add_months(1),
+        Some(datetime!(2020-02-29 12:34:56.789))
+    );
+    assert_eq!(
+        datetime!(2021-12-15 0:00).checked_add_months(1),
+        Some(datetime!(2022-01-15 0:00))
+    );
+    assert_eq!(PrimitiveDateTime::MAX.checked_add_months(1), None);
+    assert_eq!(PrimitiveDateTime::MIN.checked_add_months(-1), None);
+}
+
+#[test]
+fn checked_sub_months() {
+    assert_eq!(
+        datetime!(2021-03-31 12:34:56.789).checked_sub_months(1),
+        Some(datetime!(2021-02-28 12:34:56.789))
+    );
+    assert_eq!(
+        datetime!(2020-03-31 12:34:56.789).checked_sub_months(1),
+        Some(datetime!(2020-02-29 12:34:56.789))
+    );
+    assert_eq!(
+        datetime!(2022-01-15 0:00).checked_sub_months(1),
+        Some(datetime!(2021-12-15 0:00))
+    );
+    assert_eq!(PrimitiveDateTime::MIN.checked_sub_months(1), None);
+    assert_eq!(PrimitiveDateTime::MAX.checked_sub_months(-1), None);
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -811,3 +913,26 @@ fn saturating_sub_duration() {
         PrimitiveDateTime::MAX
     );
 }
+
+#[test]
+fn clamp() {
+    let (min, max) = (datetime!(2020-01-01 0:00), datetime!(2020-01-01 12:00));
+    assert_eq!(datetime!(2019-12-31 23:59).clamp(min, max), min);
+    assert_eq!(
+        datetime!(2020-01-01 12:00:00.000_000_001).clamp(min, max),
+        max
+    );
+    assert_eq!(
+        datetime!(2020-01-01 6:00).clamp(min, max),
+        datetime!(2020-01-01 6:00)
+    );
+    assert_eq!(PrimitiveDateTime::MIN.clamp(min, max), min);
+    assert_eq!(PrimitiveDateTime::MAX.clamp(min, max), max);
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ =
+        datetime!(2020-01-01 0:00).clamp(datetime!(2020-01-02 0:00), datetime!(2020-01-01 0:00));
+}
@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+use time::ext::NumericalDuration;
+use time::serde::iso8601_duration;
+use time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Test {
+    #[serde(with = "iso8601_duration")]
+    duration: Duration,
+    #[serde(with = "iso8601_duration::option")]
+    option_duration: Option<Duration>,
+}
+
+fn tokens(duration: &'static str, option_duration: Option<&'static str>) -> Vec<Token> {
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Test",
+            len: 2,
+        },
+        Token::Str("duration"),
+        Token::Str(duration),
+        Token::Str("option_duration"),
+    ];
+    match option_duration {
+        Some(value) => tokens.extend([Token::Some, Token::Str(value)]),
+        None => tokens.push(Token::None),
+    }
+    tokens.push(Token::StructEnd);
+    tokens
+}
+
+#[test]
+fn serialize_deserialize() {
+    for (duration, expected) in [
+        (Duration::ZERO, "PT0S"),
+        (1.seconds(), "PT1S"),
+        (90.minutes(), "PT1H30M"),
+        (1.days(), "P1D"),
+        (2.weeks(), "P14D"),
+        (
+            1.days() + 2.hours() + 3.minutes() + 4.seconds(),
+            "P1DT2H3M4S",
+        ),
+        (1.days() + 4.seconds(), "P1DT4S"),
+        (1.5.seconds(), "PT1.5S"),
+        (1.nanoseconds(), "PT0.000000001S"),
+        (123_456.microseconds(), "PT0.123456S"),
+        ((-90).minutes(), "-PT1H30M"),
+        ((-1.5).seconds(), "-PT1.5S"),
+        (Duration::MAX, "P106751991167300DT15H30M7.999999999S"),
+        (Duration::MIN, "-P106751991167300DT15H30M8.999999999S"),
+    ] {
+        assert_tokens(
+            &Test {
+                duration,
+                option_duration: Some(duration),
+            },
+            &tokens(expected, Some(expected)),
+        );
+    }
+
+    assert_tokens(
+        &Test {
+            duration: Duration::ZERO,
+            option_duration: None,
+        },
+        &tokens("PT0S", None),
+    );
+}
+
+#[test]
+fn deserialize_lenient() {
+    for (input, expected) in [
+        ("P2W", 2.weeks()),
+        ("P1W2D", 9.days()),
+        ("PT36H", 36.hours()),
+        ("PT90M", 90.minutes()),
+        ("PT0.5S", 500.milliseconds()),
+        ("PT0,5S", 500.milliseconds()),
+        ("PT1.0000000019S", 1.seconds() + 1.nanoseconds()),
+        ("+PT1S", 1.seconds()),
+        ("-P1D", (-1).days()),
+        ("P0D", Duration::ZERO),
+    ] {
+        assert_de_tokens(
+            &Test {
+                duration: expected,
+                option_duration: None,
+            },
+            &tokens(input, None),
+        );
+    }
+}
+
+#[test]
+fn deserialize_error() {
+    for input in [
+        "",
+        "P",
+        "PT",
+        "1D",
+        "P1",
+        "PD",
+        "P1Y",
+        "P1M",
+        "PT1D",
+        "P1H",
+        "P1DT",
+        "PT1S1M",
+        "P1D1D",
+        "PT1.5M",
+        "PT1.5M1S",
+        "PT1.S",
+        "PT.5S",
+        "P-1D",
+        "P1DX",
+        "--PT1S",
+        "P999999999999999999999D",
+        "P106751991167301D",
+        "PT9223372036854775808S",
+        "-PT9223372036854775809S",
+        "P1é",
+        "PT1é",
+        "P1.5é",
+        "Pé",
+        "P1DTé",
+        "é",
+    ] {
+        assert_de_tokens_error::<Test>(
+            &tokens(input, None)[..3],
+            &format!("invalid value: string {input:?}, expected an ISO 8601-formatted `Duration`"),
+        );
+    }
+}
@@ -6,6 +6,7 @@ use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOf
 
 mod error_conditions;
 mod iso8601;
+mod iso8601_duration;
 mod json;
 mod macros;
 mod rfc2822;
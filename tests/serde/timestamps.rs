@@ -136,3 +136,41 @@ fn serialize_timestamp_nanoseconds() -> serde_json::Result<()> {
     assert_eq!(serialized_nanoseconds, nanoseconds_str);
     Ok(())
 }
+
+#[test]
+fn timestamp_subsecond_before_epoch() -> serde_json::Result<()> {
+    // Sub-unit precision is truncated toward zero.
+    let value = TestMilliseconds {
+        dt: datetime!(1969-12-31 23:59:59.999_5 UTC),
+    };
+    assert_eq!(serde_json::to_string(&value)?, r#"{"dt":0}"#);
+    let value = TestMicroseconds {
+        dt: datetime!(1969-12-31 23:59:59.999_999_5 UTC),
+    };
+    assert_eq!(serde_json::to_string(&value)?, r#"{"dt":0}"#);
+    Ok(())
+}
+
+#[test]
+fn timestamp_out_of_range() {
+    let huge = format!(r#"{{"dt":{}}}"#, i128::MAX / 10);
+    assert!(serde_json::from_str::<TestMilliseconds>(&huge).is_err());
+    assert!(serde_json::from_str::<TestMicroseconds>(&huge).is_err());
+    assert!(serde_json::from_str::<TestNanoseconds>(&huge).is_err());
+    let huge = format!(r#"{{"dt":{}}}"#, i128::MIN / 10);
+    assert!(serde_json::from_str::<TestMilliseconds>(&huge).is_err());
+    assert!(serde_json::from_str::<TestMicroseconds>(&huge).is_err());
+    assert!(serde_json::from_str::<TestNanoseconds>(&huge).is_err());
+
+    // The error reports the value as deserialized, not after scaling.
+    let huge = format!(r#"{{"dt":{}}}"#, i64::MAX);
+    assert!(serde_json::from_str::<TestMilliseconds>(&huge)
+        .expect_err("value should be out of range")
+        .to_string()
+        .contains(&format!("integer `{}`", i64::MAX)));
+    let huge = format!(r#"{{"dt":{}}}"#, i128::MAX / 10);
+    assert!(serde_json::from_str::<TestMicroseconds>(&huge)
+        .expect_err("value should be out of range")
+        .to_string()
+        .starts_with("invalid value: i128"));
+}
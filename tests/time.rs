@@ -1,5 +1,5 @@
 use time::ext::{NumericalDuration, NumericalStdDuration};
-use time::macros::time;
+use time::macros::{date, datetime, time};
 use time::{Result, Time};
 
 #[test]
@@ -63,6 +63,99 @@ fn from_hms_nano() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_hms_nano_clamped() -> Result<()> {
+    assert_eq!(
+        Time::from_hms_nano_clamped(1, 2, 3, 4),
+        Time::from_hms_nano(1, 2, 3, 4)?
+    );
+    assert_eq!(Time::from_hms_nano_clamped(24, 0, 0, 0), time!(23:00));
+    assert_eq!(Time::from_hms_nano_clamped(0, 60, 0, 0), time!(0:59));
+    assert_eq!(Time::from_hms_nano_clamped(0, 0, 60, 0), time!(0:00:59));
+    assert_eq!(
+        Time::from_hms_nano_clamped(0, 0, 0, 1_000_000_000),
+        time!(0:00:00.999_999_999)
+    );
+    assert_eq!(
+        Time::from_hms_nano_clamped(u8::MAX, u8::MAX, u8::MAX, u32::MAX),
+        time!(23:59:59.999_999_999)
+    );
+    Ok(())
+}
+
+#[test]
+fn from_duration_since_midnight() -> Result<()> {
+    assert_eq!(
+        Time::from_duration_since_midnight(0.seconds())?,
+        Time::MIDNIGHT
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight(1.hours() + 2.minutes() + 3.004_005_006.seconds())?,
+        time!(1:02:03.004_005_006)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight(24.hours() - 1.nanoseconds())?,
+        time!(23:59:59.999_999_999)
+    );
+    assert!(Time::from_duration_since_midnight(24.hours()).is_err());
+    assert!(Time::from_duration_since_midnight((-1).nanoseconds()).is_err());
+    assert!(Time::from_duration_since_midnight(time::Duration::MAX).is_err());
+    assert!(Time::from_duration_since_midnight(time::Duration::MIN).is_err());
+    Ok(())
+}
+
+#[test]
+fn from_duration_since_midnight_wrapping() {
+    assert_eq!(
+        Time::from_duration_since_midnight_wrapping(1.hours()),
+        time!(1:00)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight_wrapping(24.hours()),
+        Time::MIDNIGHT
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight_wrapping(49.hours() + 1.nanoseconds()),
+        time!(1:00:00.000_000_001)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight_wrapping((-1).nanoseconds()),
+        time!(23:59:59.999_999_999)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight_wrapping((-25).hours()),
+        time!(23:00)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight_wrapping(time::Duration::MAX),
+        Time::from_duration_since_midnight_wrapping(time::Duration::MAX - 7_000.days())
+    );
+}
+
+#[test]
+fn noon() {
+    assert_eq!(Time::NOON, time!(12:00));
+    assert_eq!(Time::NOON.as_hms_nano(), (12, 0, 0, 0));
+}
+
+#[test]
+fn is_midnight() {
+    assert!(Time::MIDNIGHT.is_midnight());
+    assert!(time!(0:00).is_midnight());
+    assert!(!time!(0:00:00.000_000_001).is_midnight());
+    assert!(!time!(23:59:59.999_999_999).is_midnight());
+    assert!(!Time::NOON.is_midnight());
+}
+
+#[test]
+fn is_noon() {
+    assert!(Time::NOON.is_noon());
+    assert!(time!(12:00).is_noon());
+    assert!(!time!(12:00:00.000_000_001).is_noon());
+    assert!(!time!(11:59:59.999_999_999).is_noon());
+    assert!(!Time::MIDNIGHT.is_noon());
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(time!(1:02:03).as_hms(), (1, 2, 3));
@@ -86,6 +179,25 @@ fn as_hms_nano() {
     );
 }
 
+#[test]
+fn as_duration_since_midnight() {
+    assert_eq!(time!(0:00).as_duration_since_midnight(), 0.seconds());
+    assert_eq!(
+        time!(1:02:03.004_005_006).as_duration_since_midnight(),
+        1.hours() + 2.minutes() + 3.004_005_006.seconds()
+    );
+    assert_eq!(
+        time!(23:59:59.999_999_999).as_duration_since_midnight(),
+        24.hours() - 1.nanoseconds()
+    );
+
+    let time = time!(13:14:15.161_718_192);
+    assert_eq!(
+        Time::from_duration_since_midnight(time.as_duration_since_midnight()),
+        Ok(time)
+    );
+}
+
 #[test]
 fn hour() -> Result<()> {
     for hour in 0..24 {
@@ -219,6 +331,107 @@ fn replace_nanosecond() {
     );
 }
 
+#[test]
+fn round_to() {
+    assert_eq!(time!(12:07).round_to(15.minutes()), time!(12:00));
+    assert_eq!(
+        time!(12:07:29.999_999_999).round_to(15.minutes()),
+        time!(12:00)
+    );
+    assert_eq!(time!(12:07:30).round_to(15.minutes()), time!(12:15));
+    assert_eq!(time!(12:15).round_to(15.minutes()), time!(12:15));
+    assert_eq!(time!(23:52:30).round_to(15.minutes()), time!(0:00));
+    assert_eq!(time!(23:52:29).round_to(15.minutes()), time!(23:45));
+    assert_eq!(time!(23:59:59.5).round_to(1.seconds()), time!(0:00));
+    assert_eq!(
+        time!(0:00:00.000_000_499).round_to(1.microseconds()),
+        time!(0:00)
+    );
+    assert_eq!(
+        time!(0:00:00.000_000_500).round_to(1.microseconds()),
+        time!(0:00:00.000_001)
+    );
+    assert_eq!(
+        time!(1:02:03.456).round_to(100.milliseconds()),
+        time!(1:02:03.5)
+    );
+    assert_eq!(
+        time!(1:02:03.456).round_to(1.nanoseconds()),
+        time!(1:02:03.456)
+    );
+    assert_eq!(time!(23:58).round_to(7.minutes()), time!(23:55));
+    assert_eq!(time!(23:58:30).round_to(7.minutes()), time!(0:02));
+    assert_eq!(time!(23:56).round_to(7.minutes()), time!(23:55));
+    assert_eq!(time!(23:59).round_to(7.minutes()), time!(0:02));
+    assert_eq!(time!(11:59).round_to(1.days()), time!(0:00));
+    assert_eq!(time!(12:00).round_to(1.days()), time!(0:00));
+    assert_eq!(time!(12:00).round_to(2.days()), time!(0:00));
+}
+
+#[test]
+fn round_down_to() {
+    assert_eq!(time!(12:14:59).round_down_to(15.minutes()), time!(12:00));
+    assert_eq!(time!(12:15).round_down_to(15.minutes()), time!(12:15));
+    assert_eq!(
+        time!(23:59:59.999_999_999).round_down_to(1.hours()),
+        time!(23:00)
+    );
+    assert_eq!(time!(0:00:00.25).round_down_to(1.seconds()), time!(0:00));
+    assert_eq!(
+        time!(1:02:03.456_789).round_down_to(1.milliseconds()),
+        time!(1:02:03.456)
+    );
+    assert_eq!(time!(23:59).round_down_to(7.minutes()), time!(23:55));
+    assert_eq!(time!(23:59).round_down_to(2.days()), time!(0:00));
+}
+
+#[test]
+fn round_up_to() {
+    assert_eq!(time!(12:00:01).round_up_to(15.minutes()), time!(12:15));
+    assert_eq!(time!(12:15).round_up_to(15.minutes()), time!(12:15));
+    assert_eq!(time!(23:50).round_up_to(15.minutes()), time!(0:00));
+    assert_eq!(
+        time!(23:59:59.000_000_001).round_up_to(1.seconds()),
+        time!(0:00)
+    );
+    assert_eq!(
+        time!(1:02:03.456_001).round_up_to(1.milliseconds()),
+        time!(1:02:03.457)
+    );
+    assert_eq!(time!(23:56).round_up_to(7.minutes()), time!(0:02));
+    assert_eq!(time!(0:00).round_up_to(7.minutes()), time!(0:00));
+}
+
+#[test]
+#[should_panic]
+fn round_to_zero_granularity() {
+    let _ = time!(0:00).round_to(0.seconds());
+}
+
+#[test]
+#[should_panic]
+fn round_down_to_negative_granularity() {
+    let _ = time!(0:00).round_down_to((-1).seconds());
+}
+
+#[test]
+#[should_panic]
+fn round_up_to_negative_granularity() {
+    let _ = time!(0:00).round_up_to((-1).seconds());
+}
+
+#[test]
+fn on() {
+    assert_eq!(
+        time!(12:30:15.5).on(date!(2021 - 02 - 03)),
+        datetime!(2021-02-03 12:30:15.5)
+    );
+    assert_eq!(
+        time!(0:00).on(date!(1970 - 01 - 01)),
+        date!(1970 - 01 - 01).with_time(time!(0:00))
+    );
+}
+
 #[test]
 fn add_duration() {
     assert_eq!(time!(0:00) + 1.seconds(), time!(0:00:01));
@@ -272,6 +485,85 @@ fn sub_assign_duration() {
     assert_eq!(time, time!(22:58:59));
 }
 
+#[test]
+fn overflowing_add() {
+    assert_eq!(time!(0:00).overflowing_add(25.hours()), (time!(1:00), 1));
+    assert_eq!(time!(12:00).overflowing_add(1.hours()), (time!(13:00), 0));
+    assert_eq!(
+        time!(23:59:59.5).overflowing_add(750.milliseconds()),
+        (time!(0:00:00.25), 1)
+    );
+    assert_eq!(
+        time!(0:00:00.25).overflowing_add((-750).milliseconds()),
+        (time!(23:59:59.5), -1)
+    );
+    assert_eq!(
+        time!(12:00).overflowing_add(3.days() + 12.hours() + 1.nanoseconds()),
+        (time!(0:00:00.000_000_001), 4)
+    );
+    assert_eq!(
+        time!(12:00).overflowing_add(time::Duration::MAX),
+        (
+            time!(12:00) + time::Duration::MAX,
+            time::Duration::MAX.whole_days() + 1,
+        )
+    );
+}
+
+#[test]
+fn overflowing_sub() {
+    assert_eq!(time!(12:00).overflowing_sub(48.hours()), (time!(12:00), -2));
+    assert_eq!(time!(12:00).overflowing_sub(1.hours()), (time!(11:00), 0));
+    assert_eq!(
+        time!(0:00:00.25).overflowing_sub(750.milliseconds()),
+        (time!(23:59:59.5), -1)
+    );
+    assert_eq!(
+        time!(12:00:00.5).overflowing_sub(48.hours() + 500_000_001.nanoseconds()),
+        (time!(11:59:59.999_999_999), -2)
+    );
+    assert_eq!(
+        time!(23:59:59.5).overflowing_sub((-750).milliseconds()),
+        (time!(0:00:00.25), 1)
+    );
+
+    // The number of days matches the change in date of a `PrimitiveDateTime`.
+    let datetime = datetime!(2024-01-01 18:30:15.5);
+    for duration in [
+        (-100).hours(),
+        (-30).minutes(),
+        5.5.seconds(),
+        6.hours(),
+        (3.days() + 5.5.hours()),
+    ] {
+        let (time, days) = datetime.time().overflowing_sub(duration);
+        let expected = datetime - duration;
+        assert_eq!(time, expected.time());
+        assert_eq!(days, (expected.date() - datetime.date()).whole_days());
+        let (time, days) = datetime.time().overflowing_add(duration);
+        let expected = datetime + duration;
+        assert_eq!(time, expected.time());
+        assert_eq!(days, (expected.date() - datetime.date()).whole_days());
+    }
+}
+
+#[test]
+fn clamp() {
+    let (min, max) = (time!(9:00), time!(17:00));
+    assert_eq!(time!(8:59:59.999_999_999).clamp(min, max), min);
+    assert_eq!(time!(17:00:00.000_000_001).clamp(min, max), max);
+    assert_eq!(min.clamp(min, max), min);
+    assert_eq!(max.clamp(min, max), max);
+    assert_eq!(time!(12:00).clamp(min, max), time!(12:00));
+    assert_eq!(Time::MIDNIGHT.clamp(min, min), min);
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = time!(12:00).clamp(time!(13:00), time!(11:00));
+}
+
 #[test]
 fn add_std_duration() {
     assert_eq!(time!(0:00) + 1.std_milliseconds(), time!(0:00:00.001));
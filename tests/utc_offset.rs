@@ -1,6 +1,7 @@
 use rstest::rstest;
-use time::macros::offset;
-use time::{OffsetDateTime, UtcOffset};
+use time::error::IndeterminateOffsetReason;
+use time::macros::{datetime, offset};
+use time::{LocalResult, OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
 #[test]
 fn utc_is_zero() {
@@ -29,6 +30,40 @@ fn from_hms(
     assert_eq!(UtcOffset::from_hms(hours, minutes, seconds), Ok(expected));
 }
 
+#[rstest]
+#[case(0, 0, 0, offset!(UTC))]
+#[case(1, 2, 3, offset!(+1:02:03))]
+#[case(-1, -2, -3, offset!(-1:02:03))]
+#[case(1, -30, 0, offset!(+1:30))]
+#[case(-1, 30, 0, offset!(-1:30))]
+#[case(0, 2, -3, offset!(+0:02:03))]
+#[case(0, -2, 3, offset!(-0:02:03))]
+#[case(0, 90, 0, offset!(+1:30))]
+#[case(1, 0, 120, offset!(+1:02))]
+#[case(25, 59, 59, offset!(+25:59:59))]
+#[case(-25, -59, -59, offset!(-25:59:59))]
+#[case(25, 0, 0, offset!(+25))]
+#[case(26, 0, 0, offset!(+25:59:59))]
+#[case(-26, 0, 0, offset!(-25:59:59))]
+#[case(25, 60, 0, offset!(+25:59:59))]
+#[case(i8::MAX, i8::MAX, i8::MAX, offset!(+25:59:59))]
+#[case(i8::MIN, i8::MIN, i8::MIN, offset!(-25:59:59))]
+#[case(i8::MIN, i8::MAX, i8::MAX, offset!(-25:59:59))]
+fn from_hms_saturating(
+    #[case] hours: i8,
+    #[case] minutes: i8,
+    #[case] seconds: i8,
+    #[case] expected: UtcOffset,
+) {
+    assert_eq!(
+        UtcOffset::from_hms_saturating(hours, minutes, seconds),
+        expected
+    );
+    if let Ok(offset) = UtcOffset::from_hms(hours, minutes, seconds) {
+        assert_eq!(offset, expected);
+    }
+}
+
 #[rstest]
 #[case(0, offset!(UTC))]
 #[case(1, offset!(+0:00:01))]
@@ -141,6 +176,97 @@ fn is_negative(#[case] offset: UtcOffset, #[case] expected: bool) {
     assert_eq!(offset.is_negative(), expected);
 }
 
+#[rstest]
+#[case(offset!(UTC), offset!(UTC))]
+#[case(offset!(+0:00:29), offset!(UTC))]
+#[case(offset!(+0:00:30), offset!(+0:01))]
+#[case(offset!(-0:00:30), offset!(-0:01))]
+#[case(offset!(-0:00:29), offset!(UTC))]
+#[case(offset!(+1:59:30), offset!(+2))]
+#[case(offset!(-1:59:30), offset!(-2))]
+#[case(offset!(+25:59:29), offset!(+25:59))]
+#[case(offset!(+25:59:59), offset!(+25:59))]
+#[case(offset!(-25:59:59), offset!(-25:59))]
+fn round_to_minutes(#[case] offset: UtcOffset, #[case] expected: UtcOffset) {
+    assert_eq!(offset.round_to_minutes(), expected);
+}
+
+#[rstest]
+#[case(offset!(UTC), offset!(UTC))]
+#[case(offset!(+0:29:59), offset!(UTC))]
+#[case(offset!(+0:30), offset!(+1))]
+#[case(offset!(-0:30), offset!(-1))]
+#[case(offset!(+5:45), offset!(+6))]
+#[case(offset!(-9:15), offset!(-9))]
+#[case(offset!(+25:29:59), offset!(+25))]
+#[case(offset!(+25:59:59), offset!(+25))]
+#[case(offset!(-25:59:59), offset!(-25))]
+fn round_to_hours(#[case] offset: UtcOffset, #[case] expected: UtcOffset) {
+    assert_eq!(offset.round_to_hours(), expected);
+}
+
+#[rstest]
+#[case(datetime!(2024-03-10 1:59:59), LocalResult::Single(datetime!(2024-03-10 1:59:59 -5)))]
+#[case(datetime!(2024-03-10 2:00), LocalResult::None)]
+#[case(datetime!(2024-03-10 2:59:59), LocalResult::None)]
+#[case(datetime!(2024-03-10 3:00), LocalResult::Single(datetime!(2024-03-10 3:00 -4)))]
+fn classify_local_gap(#[case] local: PrimitiveDateTime, #[case] expected: LocalResult) {
+    let transition = datetime!(2024-03-10 2:00 -5);
+    assert_eq!(
+        UtcOffset::classify_local(local, transition, offset!(-5), offset!(-4)),
+        expected
+    );
+}
+
+#[rstest]
+#[case(datetime!(2024-11-03 0:59:59), LocalResult::Single(datetime!(2024-11-03 0:59:59 -4)))]
+#[case(
+    datetime!(2024-11-03 1:00),
+    LocalResult::Ambiguous(datetime!(2024-11-03 1:00 -4), datetime!(2024-11-03 1:00 -5))
+)]
+#[case(
+    datetime!(2024-11-03 1:59:59),
+    LocalResult::Ambiguous(datetime!(2024-11-03 1:59:59 -4), datetime!(2024-11-03 1:59:59 -5))
+)]
+#[case(datetime!(2024-11-03 2:00), LocalResult::Single(datetime!(2024-11-03 2:00 -5)))]
+fn classify_local_fold(#[case] local: PrimitiveDateTime, #[case] expected: LocalResult) {
+    let transition = datetime!(2024-11-03 2:00 -4);
+    assert_eq!(
+        UtcOffset::classify_local(local, transition, offset!(-4), offset!(-5)),
+        expected
+    );
+}
+
+#[rstest]
+#[case(datetime!(2023-12-31 23:59), LocalResult::Single(datetime!(2023-12-31 23:59 +1)))]
+#[case(datetime!(2024-01-01 0:00), LocalResult::Single(datetime!(2024-01-01 0:00 +1)))]
+fn classify_local_unchanged(#[case] local: PrimitiveDateTime, #[case] expected: LocalResult) {
+    let transition = datetime!(2024-01-01 0:00 +1);
+    assert_eq!(
+        UtcOffset::classify_local(local, transition, offset!(+1), offset!(+1)),
+        expected
+    );
+}
+
+#[test]
+fn local_result_accessors() {
+    let earlier = datetime!(2024-11-03 1:30 -4);
+    let later = datetime!(2024-11-03 1:30 -5);
+
+    assert_eq!(LocalResult::Single(earlier).single(), Some(earlier));
+    assert_eq!(LocalResult::Single(earlier).earliest(), Some(earlier));
+    assert_eq!(LocalResult::Single(earlier).latest(), Some(earlier));
+    assert_eq!(LocalResult::Ambiguous(earlier, later).single(), None);
+    assert_eq!(
+        LocalResult::Ambiguous(earlier, later).earliest(),
+        Some(earlier)
+    );
+    assert_eq!(LocalResult::Ambiguous(earlier, later).latest(), Some(later));
+    assert_eq!(LocalResult::None.single(), None);
+    assert_eq!(LocalResult::None.earliest(), None);
+    assert_eq!(LocalResult::None.latest(), None);
+}
+
 #[rstest]
 #[case(offset!(UTC), offset!(UTC))]
 #[case(offset!(+0:00:01), offset!(-0:00:01))]
@@ -155,6 +281,55 @@ fn neg(#[case] offset: UtcOffset, #[case] expected: UtcOffset) {
     assert_eq!(-offset, expected);
 }
 
+#[rstest]
+#[case("Z", offset!(UTC))]
+#[case("z", offset!(UTC))]
+#[case("+00", offset!(UTC))]
+#[case("-00:00", offset!(UTC))]
+#[case("+05", offset!(+5))]
+#[case("-05", offset!(-5))]
+#[case("+0530", offset!(+5:30))]
+#[case("-0330", offset!(-3:30))]
+#[case("+05:30", offset!(+5:30))]
+#[case("-00:30", offset!(-0:30))]
+#[case("+01:02:03", offset!(+1:02:03))]
+#[case("-01:02:03", offset!(-1:02:03))]
+#[case("-00:00:01", offset!(-0:00:01))]
+#[case("+23:59:59", offset!(+23:59:59))]
+#[case("+25:59:59", offset!(+25:59:59))]
+#[case("-25:00", offset!(-25))]
+fn from_str(#[case] input: &str, #[case] expected: UtcOffset) {
+    assert_eq!(input.parse::<UtcOffset>(), Ok(expected));
+}
+
+#[rstest]
+#[case("")]
+#[case("Zulu")]
+#[case("05:00")]
+#[case("+5")]
+#[case("+05:")]
+#[case("+05:3")]
+#[case("+05:60")]
+#[case("+0560")]
+#[case("+05:30:60")]
+#[case("+05:30:")]
+#[case("+053000")]
+#[case("+26:00")]
+#[case("+05:30 ")]
+fn from_str_err(#[case] input: &str) {
+    assert!(input.parse::<UtcOffset>().is_err());
+}
+
+#[rstest]
+#[case(offset!(UTC))]
+#[case(offset!(+5:30))]
+#[case(offset!(-0:00:01))]
+#[case(offset!(+25:59:59))]
+#[case(offset!(-25:59:59))]
+fn display_from_str_round_trip(#[case] offset: UtcOffset) {
+    assert_eq!(offset.to_string().parse::<UtcOffset>(), Ok(offset));
+}
+
 #[cfg_attr(miri, ignore)]
 #[test]
 fn local_offset_at() {
@@ -170,6 +345,21 @@ fn local_offset_at() {
     unsafe { set_soundness(Soundness::Sound) };
 }
 
+#[cfg_attr(miri, ignore)]
+#[test]
+fn local_offset_at_detailed() {
+    use time::util::local_offset::*;
+
+    let _guard = crate::SOUNDNESS_LOCK.lock().expect("lock is poisoned");
+
+    // Safety: Technically not sound. However, this is a test, and it's highly improbable that we
+    // will run into issues with setting an environment variable a few times.
+    unsafe { set_soundness(Soundness::Unsound) };
+    assert!(UtcOffset::local_offset_at_detailed(OffsetDateTime::UNIX_EPOCH).is_ok());
+    // Safety: We're setting it back to sound.
+    unsafe { set_soundness(Soundness::Sound) };
+}
+
 #[cfg_attr(miri, ignore)]
 #[test]
 fn current_local_offset() {
@@ -200,6 +390,10 @@ fn local_offset_error_when_multithreaded() {
 
     std::thread::spawn(|| {
         assert!(UtcOffset::current_local_offset().is_err());
+        assert_eq!(
+            UtcOffset::local_offset_at_detailed(OffsetDateTime::now_utc()),
+            Err(IndeterminateOffsetReason::Unsound)
+        );
     })
     .join()
     .expect("failed to join thread");
@@ -71,6 +71,19 @@ fn nth_prev(#[case] current: Weekday, #[case] n: u8, #[case] expected: Weekday)
     assert_eq!(current.nth_prev(n), expected);
 }
 
+#[rstest]
+#[case(Monday, Monday, 0)]
+#[case(Monday, Tuesday, 1)]
+#[case(Monday, Sunday, 6)]
+#[case(Friday, Monday, 3)]
+#[case(Sunday, Monday, 1)]
+#[case(Sunday, Saturday, 6)]
+#[case(Saturday, Friday, 6)]
+fn days_until(#[case] current: Weekday, #[case] other: Weekday, #[case] expected: u8) {
+    assert_eq!(current.days_until(other), expected);
+    assert_eq!(current.nth_next(expected), other);
+}
+
 #[rstest]
 #[case(Monday, 1)]
 #[case(Tuesday, 2)]
@@ -119,6 +132,59 @@ fn number_days_from_sunday(#[case] weekday: Weekday, #[case] expected: u8) {
     assert_eq!(weekday.number_days_from_sunday(), expected);
 }
 
+#[rstest]
+fn from_number_roundtrip() {
+    for weekday in Monday.all_from() {
+        assert_eq!(
+            Weekday::from_monday_one_indexed(weekday.number_from_monday()),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_monday_zero_indexed(weekday.number_days_from_monday()),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_sunday_one_indexed(weekday.number_from_sunday()),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_sunday_zero_indexed(weekday.number_days_from_sunday()),
+            Ok(weekday)
+        );
+    }
+}
+
+#[rstest]
+#[case(Weekday::from_monday_one_indexed, 1, Monday, 7, Sunday)]
+#[case(Weekday::from_monday_zero_indexed, 0, Monday, 6, Sunday)]
+#[case(Weekday::from_sunday_one_indexed, 1, Sunday, 7, Saturday)]
+#[case(Weekday::from_sunday_zero_indexed, 0, Sunday, 6, Saturday)]
+fn from_number_bounds(
+    #[case] constructor: fn(u8) -> Result<Weekday, time::error::ComponentRange>,
+    #[case] min: u8,
+    #[case] min_weekday: Weekday,
+    #[case] max: u8,
+    #[case] max_weekday: Weekday,
+) {
+    assert_eq!(constructor(min), Ok(min_weekday));
+    assert_eq!(constructor(max), Ok(max_weekday));
+    assert!(constructor(max + 1).is_err());
+    assert!(constructor(u8::MAX).is_err());
+    if min > 0 {
+        assert!(constructor(min - 1).is_err());
+    }
+}
+
+#[rstest]
+#[case(Monday, [Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday])]
+#[case(Wednesday, [Wednesday, Thursday, Friday, Saturday, Sunday, Monday, Tuesday])]
+#[case(Sunday, [Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday])]
+fn all_from(#[case] weekday: Weekday, #[case] expected: [Weekday; 7]) {
+    assert_eq!(weekday.all_from().collect::<Vec<_>>(), expected);
+    assert_eq!(weekday.all_from().len(), 7);
+    assert_eq!(weekday.all_from().next_back(), Some(weekday.previous()));
+}
+
 #[rstest]
 #[case(Monday, "Monday")]
 #[case(Tuesday, "Tuesday")]
@@ -139,6 +205,27 @@ fn display(#[case] weekday: Weekday, #[case] expected: &str) {
 #[case("Friday", Ok(Friday))]
 #[case("Saturday", Ok(Saturday))]
 #[case("Sunday", Ok(Sunday))]
+#[case("monday", Ok(Monday))]
+#[case("SUNDAY", Ok(Sunday))]
+#[case("Mon", Ok(Monday))]
+#[case("tue", Ok(Tuesday))]
+#[case("WED", Ok(Wednesday))]
+#[case("thu", Ok(Thursday))]
+#[case("Fri", Ok(Friday))]
+#[case("sat", Ok(Saturday))]
+#[case("sUn", Ok(Sunday))]
+#[case("1", Ok(Monday))]
+#[case("3", Ok(Wednesday))]
+#[case("7", Ok(Sunday))]
+#[case("07", Ok(Sunday))]
+#[case("0", Err(time::error::InvalidVariant))]
+#[case("8", Err(time::error::InvalidVariant))]
+#[case("+1", Err(time::error::InvalidVariant))]
+#[case("256", Err(time::error::InvalidVariant))]
+#[case("Mo", Err(time::error::InvalidVariant))]
+#[case("Tues", Err(time::error::InvalidVariant))]
+#[case("Monday ", Err(time::error::InvalidVariant))]
+#[case("", Err(time::error::InvalidVariant))]
 #[case("foo", Err(time::error::InvalidVariant))]
 fn from_str(#[case] input: &str, #[case] expected: Result<Weekday, time::error::InvalidVariant>) {
     assert_eq!(input.parse::<Weekday>(), expected);
@@ -1,9 +1,12 @@
+use std::borrow::Cow;
 use std::iter;
 
 use super::{lexer, unused, Error, Location, Spanned, SpannedValue, Unused};
 
+const MAX_NESTING_DEPTH: u8 = 32;
+
 pub(super) enum Item<'a> {
-    Literal(Spanned<&'a [u8]>),
+    Literal(Spanned<Cow<'a, [u8]>>),
     EscapedBracket {
         _first: Unused<Location>,
         _second: Unused<Location>,
@@ -32,6 +35,10 @@ pub(super) enum Item<'a> {
         nested_format_descriptions: Box<[NestedFormatDescription<'a>]>,
         closing_bracket: Location,
     },
+    Comment {
+        _opening_bracket: Unused<Location>,
+        _closing_bracket: Unused<Location>,
+    },
 }
 
 pub(super) struct NestedFormatDescription<'a> {
@@ -57,7 +64,7 @@ pub(super) fn parse<
     tokens: &'iter mut lexer::Lexed<I>,
 ) -> impl Iterator<Item = Result<Item<'item>, Error>> + 'iter {
     assert!(version!(1..=2));
-    parse_inner::<_, false, VERSION>(tokens)
+    parse_inner::<_, false, VERSION>(tokens, MAX_NESTING_DEPTH)
 }
 
 fn parse_inner<
@@ -67,6 +74,7 @@ fn parse_inner<
     const VERSION: u8,
 >(
     tokens: &mut lexer::Lexed<I>,
+    remaining_depth: u8,
 ) -> impl Iterator<Item = Result<Item<'item>, Error>> + '_ {
     iter::from_fn(move || {
         if NESTED && tokens.peek_closing_bracket().is_some() {
@@ -79,9 +87,6 @@ fn parse_inner<
         };
 
         Some(match next {
-            lexer::Token::Literal(Spanned { value: _, span: _ }) if NESTED => {
-                bug!("literal should not be present in nested description")
-            }
             lexer::Token::Literal(value) => Ok(Item::Literal(value)),
             lexer::Token::Bracket {
                 kind: lexer::BracketKind::Opening,
@@ -94,10 +99,10 @@ fn parse_inner<
                             _second: unused(second_location),
                         })
                     } else {
-                        parse_component::<_, VERSION>(location, tokens)
+                        parse_component::<_, VERSION>(location, tokens, remaining_depth)
                     }
                 } else {
-                    parse_component::<_, VERSION>(location, tokens)
+                    parse_component::<_, VERSION>(location, tokens, remaining_depth)
                 }
             }
             lexer::Token::Bracket {
@@ -112,17 +117,24 @@ fn parse_inner<
             } => {
                 bug!("closing bracket should have been consumed by `parse_component`")
             }
-            lexer::Token::ComponentPart { kind: _, value } if NESTED => Ok(Item::Literal(value)),
+            lexer::Token::ComponentPart { kind: _, value } if NESTED => {
+                Ok(Item::Literal(Spanned {
+                    value: Cow::Borrowed(value.value),
+                    span: value.span,
+                }))
+            }
             lexer::Token::ComponentPart { kind: _, value: _ } => {
                 bug!("component part should have been consumed by `parse_component`")
             }
         })
     })
+    .filter(|item| !matches!(item, Ok(Item::Comment { .. })))
 }
 
 fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, const VERSION: u8>(
     opening_bracket: Location,
     tokens: &mut lexer::Lexed<I>,
+    remaining_depth: u8,
 ) -> Result<Item<'a>, Error> {
     let leading_whitespace = tokens.next_if_whitespace();
 
@@ -134,12 +146,16 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
         return Err(span.error("expected component name"));
     };
 
+    if name.starts_with(b"#") {
+        return parse_comment(opening_bracket, tokens);
+    }
+
     if *name == b"optional" {
         let Some(whitespace) = tokens.next_if_whitespace() else {
             return Err(name.span.error("expected whitespace after `optional`"));
         };
 
-        let nested = parse_nested::<_, VERSION>(whitespace.span.end, tokens)?;
+        let nested = parse_nested::<_, VERSION>(whitespace.span.end, tokens, remaining_depth)?;
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
             return Err(opening_bracket.error("unclosed bracket"));
@@ -161,8 +177,12 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
         };
 
         let mut nested_format_descriptions = Vec::new();
-        while let Ok(description) = parse_nested::<_, VERSION>(whitespace.span.end, tokens) {
-            nested_format_descriptions.push(description);
+        while tokens.peek_opening_bracket().is_some() {
+            nested_format_descriptions.push(parse_nested::<_, VERSION>(
+                whitespace.span.end,
+                tokens,
+                remaining_depth,
+            )?);
         }
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
@@ -230,14 +250,49 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
     })
 }
 
+fn parse_comment<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>>(
+    opening_bracket: Location,
+    tokens: &mut lexer::Lexed<I>,
+) -> Result<Item<'a>, Error> {
+    let mut depth: usize = 0;
+    let closing_bracket = loop {
+        match tokens.next() {
+            Some(Ok(lexer::Token::Bracket {
+                kind: lexer::BracketKind::Opening,
+                location: _,
+            })) => depth += 1,
+            Some(Ok(lexer::Token::Bracket {
+                kind: lexer::BracketKind::Closing,
+                location,
+            })) => match depth.checked_sub(1) {
+                Some(new_depth) => depth = new_depth,
+                None => break location,
+            },
+            Some(Ok(_)) => {}
+            Some(Err(err)) => return Err(err),
+            None => return Err(opening_bracket.error("unclosed bracket")),
+        }
+    };
+
+    Ok(Item::Comment {
+        _opening_bracket: unused(opening_bracket),
+        _closing_bracket: unused(closing_bracket),
+    })
+}
+
 fn parse_nested<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, const VERSION: u8>(
     last_location: Location,
     tokens: &mut lexer::Lexed<I>,
+    remaining_depth: u8,
 ) -> Result<NestedFormatDescription<'a>, Error> {
     let Some(opening_bracket) = tokens.next_if_opening_bracket() else {
         return Err(last_location.error("expected opening bracket"));
     };
-    let items = parse_inner::<_, true, VERSION>(tokens).collect::<Result<_, _>>()?;
+    if remaining_depth == 0 {
+        return Err(opening_bracket.error("format description is nested too deeply"));
+    }
+    let items =
+        parse_inner::<_, true, VERSION>(tokens, remaining_depth - 1).collect::<Result<_, _>>()?;
     let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
         return Err(opening_bracket.error("unclosed bracket"));
     };
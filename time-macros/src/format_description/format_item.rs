@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::num::NonZeroU16;
 use std::str::{self, FromStr};
 
@@ -10,8 +11,9 @@ pub(super) fn parse<'a>(
 }
 
 pub(super) enum Item<'a> {
-    Literal(&'a [u8]),
+    Literal(Cow<'a, [u8]>),
     Component(Component),
+    Padded(Component, MinWidth),
     Optional {
         value: Box<[Self]>,
         _span: Unused<Span>,
@@ -32,12 +34,18 @@ impl Item<'_> {
                 modifiers,
                 _trailing_whitespace: _,
                 _closing_bracket: _,
-            } => Item::Component(component_from_ast(&name, &modifiers)?),
+            } => {
+                let component = component_from_ast(&name, &modifiers)?;
+                match MinWidth::with_modifiers(&modifiers)? {
+                    Some(min_width) => Item::Padded(component, min_width),
+                    None => Item::Component(component),
+                }
+            }
             ast::Item::Literal(Spanned { value, span: _ }) => Item::Literal(value),
             ast::Item::EscapedBracket {
                 _first: _,
                 _second: _,
-            } => Item::Literal(b"["),
+            } => Item::Literal(Cow::Borrowed(b"[")),
             ast::Item::Optional {
                 opening_bracket,
                 _leading_whitespace: _,
@@ -82,6 +90,10 @@ impl Item<'_> {
                     _span: unused(opening_bracket.to(closing_bracket)),
                 }
             }
+            ast::Item::Comment {
+                _opening_bracket: _,
+                _closing_bracket: _,
+            } => bug!("comment should have been discarded by the AST parser"),
         })
     }
 }
@@ -89,8 +101,9 @@ impl Item<'_> {
 impl From<Item<'_>> for crate::format_description::public::OwnedFormatItem {
     fn from(item: Item<'_>) -> Self {
         match item {
-            Item::Literal(literal) => Self::Literal(literal.to_vec().into_boxed_slice()),
+            Item::Literal(literal) => Self::Literal(literal.into_owned().into_boxed_slice()),
             Item::Component(component) => Self::Component(component.into()),
+            Item::Padded(component, min_width) => Self::Padded(component.into(), min_width.into()),
             Item::Optional { value, _span: _ } => Self::Optional(Box::new(value.into())),
             Item::First { value, _span: _ } => {
                 Self::First(value.into_vec().into_iter().map(Into::into).collect())
@@ -143,6 +156,10 @@ macro_rules! component_definition {
                 };
 
                 for modifier in modifiers {
+                    // Modifiers that apply to all components are handled separately.
+                    if MinWidth::is_modifier_key(&modifier.key) {
+                        continue;
+                    }
                     $(#[allow(clippy::string_lit_as_bytes)]
                     if modifier.key.eq_ignore_ascii_case($parse_field.as_bytes()) {
                         this.$field = component_definition!(@if_from_str $($from_str)?
@@ -208,9 +225,13 @@ component_definition! {
             padding = "padding": Option<Padding> => padding,
         },
         End = "end" {},
+        Era = "era" {
+            case = "case": Option<EraCase> => is_uppercase,
+        },
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
             base = "repr": Option<HourBase> => is_12_hour_clock,
+            fraction = "fraction": Option<Fraction> => fraction,
         },
         Ignore = "ignore" {
             #[required]
@@ -218,15 +239,18 @@ component_definition! {
         },
         Minute = "minute" {
             padding = "padding": Option<Padding> => padding,
+            fraction = "fraction": Option<Fraction> => fraction,
         },
         Month = "month" {
             padding = "padding": Option<Padding> => padding,
             repr = "repr": Option<MonthRepr> => repr,
+            case = "case": Option<NameCase> => case,
             case_sensitive = "case_sensitive": Option<MonthCaseSensitive> => case_sensitive,
         },
         OffsetHour = "offset_hour" {
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
             padding = "padding": Option<Padding> => padding,
+            utc_alias = "utc_alias": Option<OffsetHourUtcAlias> => utc_alias_allowed,
         },
         OffsetMinute = "offset_minute" {
             padding = "padding": Option<Padding> => padding,
@@ -240,10 +264,17 @@ component_definition! {
         Period = "period" {
             case = "case": Option<PeriodCase> => is_uppercase,
             case_sensitive = "case_sensitive": Option<PeriodCaseSensitive> => case_sensitive,
+            optional = "optional": Option<PeriodOptional> => is_optional,
+        },
+        Quarter = "quarter" {
+            repr = "repr": Option<QuarterRepr> => repr,
         },
         Second = "second" {
             padding = "padding": Option<Padding> => padding,
         },
+        SecondsOfDay = "seconds_of_day" {
+            padding = "padding": Option<Padding> => padding,
+        },
         Subsecond = "subsecond" {
             digits = "digits": Option<SubsecondDigits> => digits,
         },
@@ -254,6 +285,7 @@ component_definition! {
         Weekday = "weekday" {
             repr = "repr": Option<WeekdayRepr> => repr,
             one_indexed = "one_indexed": Option<WeekdayOneIndexed> => one_indexed,
+            case = "case": Option<NameCase> => case,
             case_sensitive = "case_sensitive": Option<WeekdayCaseSensitive> => case_sensitive,
         },
         WeekNumber = "week_number" {
@@ -265,6 +297,8 @@ component_definition! {
             repr = "repr": Option<YearRepr> => repr,
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            era = "era": Option<YearEra> => era_based,
+            range_start = "range_start": Option<#[from_str] YearRangeStart> => range_start,
         },
     }
 }
@@ -323,6 +357,33 @@ macro_rules! modifier {
 }
 
 modifier! {
+    enum Align(bool) {
+        Left(true) = b"left",
+        #[default]
+        Right(false) = b"right",
+    }
+
+    enum EraCase(bool) {
+        Lower(false) = b"lower",
+        #[default]
+        Upper(true) = b"upper",
+    }
+
+    enum Fraction(Option<super::public::modifier::SubsecondDigits>) {
+        #[default]
+        None(None) = b"none",
+        One(Some(SubsecondDigits::One.into())) = b"1",
+        Two(Some(SubsecondDigits::Two.into())) = b"2",
+        Three(Some(SubsecondDigits::Three.into())) = b"3",
+        Four(Some(SubsecondDigits::Four.into())) = b"4",
+        Five(Some(SubsecondDigits::Five.into())) = b"5",
+        Six(Some(SubsecondDigits::Six.into())) = b"6",
+        Seven(Some(SubsecondDigits::Seven.into())) = b"7",
+        Eight(Some(SubsecondDigits::Eight.into())) = b"8",
+        Nine(Some(SubsecondDigits::Nine.into())) = b"9",
+        OneOrMore(Some(SubsecondDigits::OneOrMore.into())) = b"1+",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
@@ -342,6 +403,19 @@ modifier! {
         Short = b"short",
     }
 
+    enum NameCase {
+        #[default]
+        Title = b"title",
+        Upper = b"upper",
+        Lower = b"lower",
+    }
+
+    enum OffsetHourUtcAlias(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum Padding {
         Space = b"space",
         #[default]
@@ -361,6 +435,17 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum PeriodOptional(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum QuarterRepr {
+        #[default]
+        Numerical = b"numerical",
+    }
+
     enum SignBehavior(bool) {
         #[default]
         Automatic(false) = b"automatic",
@@ -422,6 +507,12 @@ modifier! {
         IsoWeek(true) = b"iso_week",
     }
 
+    enum YearEra(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum YearRepr {
         #[default]
         Full = b"full",
@@ -429,6 +520,102 @@ modifier! {
     }
 }
 
+struct YearRangeStart(i16);
+
+impl Default for YearRangeStart {
+    fn default() -> Self {
+        Self(1950)
+    }
+}
+
+impl FromStr for YearRangeStart {
+    type Err = <i16 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl From<YearRangeStart> for i16 {
+    fn from(range_start: YearRangeStart) -> Self {
+        range_start.0
+    }
+}
+
+struct Fill(u8);
+
+impl Default for Fill {
+    fn default() -> Self {
+        Self(b' ')
+    }
+}
+
+impl FromStr for Fill {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A string containing a single byte is necessarily ASCII.
+        match s.as_bytes() {
+            &[fill] => Ok(Self(fill)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Fill> for u8 {
+    fn from(fill: Fill) -> Self {
+        fill.0
+    }
+}
+
+pub(super) struct MinWidth {
+    width: Option<u8>,
+    fill: Option<Fill>,
+    align: Option<Align>,
+}
+
+impl MinWidth {
+    fn is_modifier_key(key: &[u8]) -> bool {
+        [b"min_width".as_slice(), b"fill", b"align"]
+            .iter()
+            .any(|known| key.eq_ignore_ascii_case(known))
+    }
+
+    fn with_modifiers(modifiers: &[ast::Modifier<'_>]) -> Result<Option<Self>, Error> {
+        let mut this = Self {
+            width: None,
+            fill: None,
+            align: None,
+        };
+        let mut is_present = false;
+
+        for modifier in modifiers {
+            if modifier.key.eq_ignore_ascii_case(b"min_width") {
+                this.width = parse_from_modifier_value::<u8>(&modifier.value)?;
+            } else if modifier.key.eq_ignore_ascii_case(b"fill") {
+                this.fill = parse_from_modifier_value::<Fill>(&modifier.value)?;
+            } else if modifier.key.eq_ignore_ascii_case(b"align") {
+                this.align = Align::from_modifier_value(&modifier.value)?;
+            } else {
+                continue;
+            }
+            is_present = true;
+        }
+
+        Ok(is_present.then_some(this))
+    }
+}
+
+impl From<MinWidth> for super::public::modifier::MinWidth {
+    fn from(min_width: MinWidth) -> Self {
+        Self {
+            width: min_width.width.unwrap_or_default(),
+            fill: min_width.fill.unwrap_or_default().into(),
+            is_left_aligned: min_width.align.unwrap_or_default().into(),
+        }
+    }
+}
+
 fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Option<T>, Error> {
     str::from_utf8(value)
         .ok()
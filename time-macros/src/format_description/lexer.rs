@@ -1,4 +1,5 @@
-use core::iter;
+use std::borrow::Cow;
+use std::iter;
 
 use super::{Error, Location, Spanned, SpannedValue};
 
@@ -58,6 +59,18 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
         }
     }
 
+    pub(super) fn peek_opening_bracket(&'iter mut self) -> Option<&'iter Location> {
+        if let Some(Ok(Token::Bracket {
+            kind: BracketKind::Opening,
+            location,
+        })) = self.peek()
+        {
+            Some(location)
+        } else {
+            None
+        }
+    }
+
     pub(super) fn peek_closing_bracket(&'iter mut self) -> Option<&'iter Location> {
         if let Some(Ok(Token::Bracket {
             kind: BracketKind::Closing,
@@ -85,7 +98,7 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
 }
 
 pub(super) enum Token<'a> {
-    Literal(Spanned<&'a [u8]>),
+    Literal(Spanned<Cow<'a, [u8]>>),
     Bracket {
         kind: BracketKind,
         location: Location,
@@ -151,7 +164,7 @@ pub(super) fn lex<const VERSION: u8>(
                     let char = &input[1..2];
                     input = &input[2..];
                     if depth == 0 {
-                        Token::Literal(char.spanned(backslash_loc.to(char_loc)))
+                        Token::Literal(Cow::Borrowed(char).spanned(backslash_loc.to(char_loc)))
                     } else {
                         Token::ComponentPart {
                             kind: ComponentKind::NotWhitespace,
@@ -159,6 +172,46 @@ pub(super) fn lex<const VERSION: u8>(
                         }
                     }
                 }
+                Some((&byte @ (b'n' | b'r' | b't' | b'u'), char_loc)) => {
+                    let (char, end_loc, len) = match byte {
+                        b'n' => ('\n', char_loc, 2),
+                        b'r' => ('\r', char_loc, 2),
+                        b't' => ('\t', char_loc, 2),
+                        _ => {
+                            let Some((_, brace_loc)) = iter.next_if(|&(&b, _)| b == b'{') else {
+                                return Some(Err(char_loc.error("invalid escape sequence")));
+                            };
+                            let mut code_point = 0;
+                            let mut digits = 0;
+                            let mut last_loc = brace_loc;
+                            while digits < 6 {
+                                let Some((&digit, loc)) =
+                                    iter.next_if(|&(&b, _)| b.is_ascii_hexdigit())
+                                else {
+                                    break;
+                                };
+                                let Some(digit) = (digit as char).to_digit(16) else {
+                                    bug!("byte should be an ASCII hex digit");
+                                };
+                                code_point = code_point * 16 + digit;
+                                digits += 1;
+                                last_loc = loc;
+                            }
+                            let Some((_, end_loc)) = iter.next_if(|&(&b, _)| b == b'}') else {
+                                return Some(Err(last_loc.error("invalid escape sequence")));
+                            };
+                            let Some(char) = char::from_u32(code_point).filter(|_| digits > 0)
+                            else {
+                                return Some(Err(end_loc.error("invalid escape sequence")));
+                            };
+                            (char, end_loc, digits + 4)
+                        }
+                    };
+                    input = &input[len..];
+
+                    let value = char.encode_utf8(&mut [0; 4]).as_bytes().to_vec();
+                    Token::Literal(Cow::<[u8]>::Owned(value).spanned(backslash_loc.to(end_loc)))
+                }
                 Some((_, loc)) => {
                     return Some(Err(loc.error("invalid escape sequence")));
                 }
@@ -212,7 +265,7 @@ pub(super) fn lex<const VERSION: u8>(
                 let value = &input[..bytes];
                 input = &input[bytes..];
 
-                Token::Literal(value.spanned(start_location.to(end_location)))
+                Token::Literal(Cow::Borrowed(value).spanned(start_location.to(end_location)))
             }
             (byte, start_location) => {
                 let mut bytes = 1;
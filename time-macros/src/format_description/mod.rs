@@ -56,10 +56,10 @@ impl Location {
     fn error(self, message: &'static str) -> Error {
         Error {
             message,
-            _span: unused(Span {
+            span: Span {
                 start: self,
                 end: self,
-            }),
+            },
             proc_span: self.proc_span,
         }
     }
@@ -115,7 +115,7 @@ impl Span {
     fn error(self, message: &'static str) -> Error {
         Error {
             message,
-            _span: unused(self),
+            span: self,
             proc_span: self.start.proc_span,
         }
     }
@@ -147,14 +147,16 @@ impl<T> SpannedValue for T {
 
 struct Error {
     message: &'static str,
-    _span: Unused<Span>,
+    span: Span,
     proc_span: proc_macro::Span,
 }
 
 impl From<Error> for crate::Error {
     fn from(error: Error) -> Self {
+        // The span of a string literal cannot be narrowed on stable, so the position of the error
+        // within the format description is included in the message instead.
         Self::Custom {
-            message: error.message.into(),
+            message: format!("{} at byte index {}", error.message, error.span.start.byte).into(),
             span_start: Some(error.proc_span),
             span_end: Some(error.proc_span),
         }
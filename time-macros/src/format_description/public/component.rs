@@ -36,11 +36,14 @@ declare_component! {
     Weekday
     WeekNumber
     Year
+    Era
+    Quarter
     Hour
     Minute
     Period
     Second
     Subsecond
+    SecondsOfDay
     OffsetHour
     OffsetMinute
     OffsetSecond
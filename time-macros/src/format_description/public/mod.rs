@@ -13,6 +13,7 @@ pub(crate) enum OwnedFormatItem {
     Compound(Box<[Self]>),
     Optional(Box<Self>),
     First(Box<[Self]>),
+    Padded(Component, modifier::MinWidth),
 }
 
 impl ToTokenStream for OwnedFormatItem {
@@ -49,6 +50,12 @@ impl ToTokenStream for OwnedFormatItem {
                     ::time::format_description::BorrowedFormatItem::First { 0: &[#S(items)] }
                 }
             }
+            Self::Padded(component, min_width) => quote_append! { ts
+                ::time::format_description::BorrowedFormatItem::Padded(
+                    #S(component),
+                    #S(min_width)
+                )
+            },
         }
     }
 }
@@ -82,10 +82,19 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum NameCase {
+        Title,
+        Upper,
+        Lower,
+    }
+}
+
 to_tokens! {
     pub(crate) struct Month {
         pub(crate) padding: Padding,
         pub(crate) repr: MonthRepr,
+        pub(crate) case: NameCase,
         pub(crate) case_sensitive: bool,
     }
 }
@@ -109,6 +118,7 @@ to_tokens! {
     pub(crate) struct Weekday {
         pub(crate) repr: WeekdayRepr,
         pub(crate) one_indexed: bool,
+        pub(crate) case: NameCase,
         pub(crate) case_sensitive: bool,
     }
 }
@@ -141,6 +151,26 @@ to_tokens! {
         pub(crate) repr: YearRepr,
         pub(crate) iso_week_based: bool,
         pub(crate) sign_is_mandatory: bool,
+        pub(crate) era_based: bool,
+        pub(crate) range_start: i16,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Era {
+        pub(crate) is_uppercase: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum QuarterRepr {
+        Numerical,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Quarter {
+        pub(crate) repr: QuarterRepr,
     }
 }
 
@@ -148,12 +178,14 @@ to_tokens! {
     pub(crate) struct Hour {
         pub(crate) padding: Padding,
         pub(crate) is_12_hour_clock: bool,
+        pub(crate) fraction: Option<SubsecondDigits>,
     }
 }
 
 to_tokens! {
     pub(crate) struct Minute {
         pub(crate) padding: Padding,
+        pub(crate) fraction: Option<SubsecondDigits>,
     }
 }
 
@@ -161,6 +193,7 @@ to_tokens! {
     pub(crate) struct Period {
         pub(crate) is_uppercase: bool,
         pub(crate) case_sensitive: bool,
+        pub(crate) is_optional: bool,
     }
 }
 
@@ -191,10 +224,17 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) struct SecondsOfDay {
+        pub(crate) padding: Padding,
+    }
+}
+
 to_tokens! {
     pub(crate) struct OffsetHour {
         pub(crate) sign_is_mandatory: bool,
         pub(crate) padding: Padding,
+        pub(crate) utc_alias_allowed: bool,
     }
 }
 
@@ -249,3 +289,11 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct End {}
 }
+
+to_tokens! {
+    pub(crate) struct MinWidth {
+        pub(crate) width: u8,
+        pub(crate) fill: u8,
+        pub(crate) is_left_aligned: bool,
+    }
+}
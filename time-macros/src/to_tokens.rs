@@ -50,6 +50,15 @@ impl ToTokenTree for NonZeroU16 {
     }
 }
 
+impl<T: ToTokenStream> ToTokenTree for Option<T> {
+    fn into_token_tree(self) -> TokenTree {
+        match self {
+            Some(value) => quote_group! {{ ::core::option::Option::Some(#S(value)) }},
+            None => quote_group! {{ ::core::option::Option::None }},
+        }
+    }
+}
+
 macro_rules! impl_for_tree_types {
     ($($type:ty)*) => {$(
         impl ToTokenTree for $type {
@@ -73,6 +82,7 @@ macro_rules! impl_for_int {
 impl_for_int! {
     i8 => i8_unsuffixed
     u8 => u8_unsuffixed
+    i16 => i16_unsuffixed
     u16 => u16_unsuffixed
     i32 => i32_unsuffixed
     u32 => u32_unsuffixed
@@ -2,6 +2,7 @@
 
 #[cfg(feature = "formatting")]
 use alloc::string::String;
+use core::iter::FusedIterator;
 use core::num::NonZeroI32;
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
@@ -9,7 +10,7 @@ use core::{cmp, fmt};
 #[cfg(feature = "formatting")]
 use std::io;
 
-use deranged::RangedI32;
+use deranged::{RangedI32, RangedI64};
 use num_conv::prelude::*;
 use powerfmt::ext::FormatterExt;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
@@ -111,12 +112,6 @@ impl Date {
         month: Month,
         day: u8,
     ) -> Result<Self, error::ComponentRange> {
-        /// Cumulative days through the beginning of a month in both common and leap years.
-        const DAYS_CUMULATIVE_COMMON_LEAP: [[u16; 12]; 2] = [
-            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
-            [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
-        ];
-
         ensure_ranged!(Year: year);
         match day {
             1..=28 => {}
@@ -134,11 +129,7 @@ impl Date {
 
         // Safety: `ordinal` is not zero.
         Ok(unsafe {
-            Self::__from_ordinal_date_unchecked(
-                year,
-                DAYS_CUMULATIVE_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1]
-                    + day as u16,
-            )
+            Self::__from_ordinal_date_unchecked(year, month.days_in_year_before(year) + day as u16)
         })
     }
 
@@ -176,6 +167,9 @@ impl Date {
 
     /// Attempt to create a `Date` from the ISO year, week, and weekday.
     ///
+    /// Week 53 is only accepted if the ISO year has 53 weeks, as determined by
+    /// [`util::weeks_in_year`](crate::util::weeks_in_year).
+    ///
     /// ```rust
     /// # use time::{Date, Weekday::*};
     /// assert!(Date::from_iso_week_date(2019, 1, Monday).is_ok());
@@ -240,8 +234,127 @@ impl Date {
         })
     }
 
+    /// Create a `Date` from the `n`th occurrence of a weekday in the given month. Returns `None`
+    /// if `n` is zero, if the month does not contain `n` occurrences of the weekday, or if the
+    /// year is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2023, Month::November, Weekday::Thursday, 4),
+    ///     Some(date!(2023 - 11 - 23))
+    /// );
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2023, Month::November, Weekday::Friday, 5),
+    ///     None
+    /// );
+    /// ```
+    pub const fn nth_weekday_of_month(
+        year: i32,
+        month: Month,
+        weekday: Weekday,
+        n: u8,
+    ) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+
+        let Ok(first) = Self::from_calendar_date(year, month, 1) else {
+            return None;
+        };
+        let offset =
+            (weekday.number_days_from_monday() + 7 - first.weekday().number_days_from_monday()) % 7;
+        let day = 1 + offset as u16 + 7 * (n as u16 - 1);
+
+        if day > days_in_year_month(year, month) as u16 {
+            return None;
+        }
+        first.checked_add(Duration::days(day as i64 - 1))
+    }
+
+    /// Create a `Date` from the last occurrence of a weekday in the given month. Returns `None` if
+    /// the year is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::last_weekday_of_month(2023, Month::May, Weekday::Monday),
+    ///     Some(date!(2023 - 05 - 29))
+    /// );
+    /// ```
+    pub const fn last_weekday_of_month(year: i32, month: Month, weekday: Weekday) -> Option<Self> {
+        let Ok(last) = Self::from_calendar_date(year, month, days_in_year_month(year, month))
+        else {
+            return None;
+        };
+        let offset =
+            (last.weekday().number_days_from_monday() + 7 - weekday.number_days_from_monday()) % 7;
+
+        // The month has at least 28 days, so this cannot move into the previous month.
+        last.checked_sub(Duration::days(offset as _))
+    }
+
+    /// Create a `Date` from the first day of the `week`th week of the given month, where weeks
+    /// begin on `week_start`. Returns `None` if `week` is zero, if the month does not have that
+    /// many weeks, or if the year is out of range.
+    ///
+    /// The first week is the one containing the first day of the month, even if it begins in the
+    /// previous month. Its first day is therefore always the first of the month. Every other week
+    /// begins on `week_start`. This matches the rows of [`Date::calendar_month`].
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday};
+    /// # use time_macros::date;
+    /// // March 2024 begins on a Friday.
+    /// assert_eq!(
+    ///     Date::first_day_of_week_of_month(2024, Month::March, 1, Weekday::Monday),
+    ///     Some(date!(2024 - 03 - 01))
+    /// );
+    /// assert_eq!(
+    ///     Date::first_day_of_week_of_month(2024, Month::March, 2, Weekday::Monday),
+    ///     Some(date!(2024 - 03 - 04))
+    /// );
+    /// assert_eq!(
+    ///     Date::first_day_of_week_of_month(2024, Month::March, 6, Weekday::Monday),
+    ///     None
+    /// );
+    /// ```
+    pub const fn first_day_of_week_of_month(
+        year: i32,
+        month: Month,
+        week: u8,
+        week_start: Weekday,
+    ) -> Option<Self> {
+        if week == 0 {
+            return None;
+        }
+
+        let Ok(first) = Self::from_calendar_date(year, month, 1) else {
+            return None;
+        };
+        if week == 1 {
+            return Some(first);
+        }
+
+        // The number of days in the first week that precede the first of the month.
+        let leading = (first.weekday().number_days_from_monday() + 7
+            - week_start.number_days_from_monday())
+            % 7;
+        let day = 1 + 7 * (week as u16 - 1) - leading as u16;
+
+        if day > days_in_year_month(year, month) as u16 {
+            return None;
+        }
+        first.checked_add(Duration::days(day as i64 - 1))
+    }
+
     /// Create a `Date` from the Julian day.
     ///
+    /// Julian day zero is November 24, 4714 BCE in the proleptic Gregorian calendar (year -4713 in
+    /// this crate's astronomical numbering). Each Julian day is counted as beginning at midnight.
+    ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
     /// freely available [here](https://www.researchgate.net/publication/316558298_Date_Algorithms).
     ///
@@ -260,6 +373,28 @@ impl Date {
         Ok(Self::from_julian_day_unchecked(julian_day))
     }
 
+    /// Create a `Date` from the Julian day, accepting an `i64`.
+    ///
+    /// This is identical to [`Date::from_julian_day`], but avoids the need to narrow the result of
+    /// intermediate arithmetic performed in `i64`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::from_julian_day_i64(0), Ok(date!(-4713 - 11 - 24)));
+    /// assert_eq!(
+    ///     Date::from_julian_day_i64(2_451_545),
+    ///     Ok(date!(2000 - 01 - 01))
+    /// );
+    /// assert!(Date::from_julian_day_i64(i64::MAX).is_err());
+    /// ```
+    pub const fn from_julian_day_i64(julian_day: i64) -> Result<Self, error::ComponentRange> {
+        type JulianDay =
+            RangedI64<{ Date::MIN.to_julian_day() as i64 }, { Date::MAX.to_julian_day() as i64 }>;
+        ensure_ranged!(JulianDay: julian_day);
+        Ok(Self::from_julian_day_unchecked(julian_day as _))
+    }
+
     /// Create a `Date` from the Julian day.
     ///
     /// This does not check the validity of the provided Julian day, and as such may result in an
@@ -326,6 +461,21 @@ impl Date {
         self.month_day().0
     }
 
+    /// Get the quarter of the year. January through March is the first quarter, April through
+    /// June is the second, and so on.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).quarter(), 1);
+    /// assert_eq!(date!(2019 - 06 - 30).quarter(), 2);
+    /// assert_eq!(date!(2019 - 12 - 31).quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        (self.month() as u8 - 1) / 3 + 1
+    }
+
     /// Get the day of the month.
     ///
     /// The returned value will always be in the range `1..=31`.
@@ -706,8 +856,110 @@ impl Date {
         )
     }
 
+    /// Calculates the first date on or after `self` with the provided month and day. This is
+    /// useful for recurring events such as birthdays and anniversaries.
+    ///
+    /// February 29 falls on February 28 in years that are not leap years.
+    ///
+    /// Returns `None` if the month and day can never occur together (such as February 30) or if
+    /// the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 28).next_anniversary(Month::July, 4),
+    ///     Some(date!(2023 - 07 - 04))
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 07 - 04).next_anniversary(Month::July, 4),
+    ///     Some(date!(2023 - 07 - 04))
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 07 - 05).next_anniversary(Month::July, 4),
+    ///     Some(date!(2024 - 07 - 04))
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 01 - 01).next_anniversary(Month::February, 29),
+    ///     Some(date!(2023 - 02 - 28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 01 - 01).next_anniversary(Month::February, 30),
+    ///     None
+    /// );
+    /// ```
+    pub const fn next_anniversary(self, month: Month, day: u8) -> Option<Self> {
+        let candidate = const_try_opt!(Self::anniversary_in_year(self.year(), month, day));
+        if candidate.value.get() >= self.value.get() {
+            Some(candidate)
+        } else {
+            Self::anniversary_in_year(self.year() + 1, month, day)
+        }
+    }
+
+    /// Calculates the last date on or before `self` with the provided month and day.
+    ///
+    /// February 29 falls on February 28 in years that are not leap years.
+    ///
+    /// Returns `None` if the month and day can never occur together (such as February 30) or if
+    /// the resulting date would be out of range.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 28).previous_anniversary(Month::July, 4),
+    ///     Some(date!(2022 - 07 - 04))
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 07 - 04).previous_anniversary(Month::July, 4),
+    ///     Some(date!(2023 - 07 - 04))
+    /// );
+    /// assert_eq!(
+    ///     date!(2024 - 03 - 01).previous_anniversary(Month::February, 29),
+    ///     Some(date!(2024 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 01 - 01).previous_anniversary(Month::April, 31),
+    ///     None
+    /// );
+    /// ```
+    pub const fn previous_anniversary(self, month: Month, day: u8) -> Option<Self> {
+        let candidate = const_try_opt!(Self::anniversary_in_year(self.year(), month, day));
+        if candidate.value.get() <= self.value.get() {
+            Some(candidate)
+        } else {
+            Self::anniversary_in_year(self.year() - 1, month, day)
+        }
+    }
+
+    /// Obtain the date in the given year with the provided month and day, using February 28 in
+    /// place of February 29 in common years. Returns `None` if the month and day can never occur
+    /// together or if the year is out of range.
+    const fn anniversary_in_year(year: i32, month: Month, day: u8) -> Option<Self> {
+        // 2000 is a leap year, so this is the maximum number of days the month can ever have.
+        if day == 0 || day > days_in_year_month(2000, month) {
+            return None;
+        }
+
+        let days_in_month = days_in_year_month(year, month);
+        let day = if day > days_in_month {
+            days_in_month
+        } else {
+            day
+        };
+
+        match Self::from_calendar_date(year, month, day) {
+            Ok(date) => Some(date),
+            Err(_) => None,
+        }
+    }
+
     /// Get the Julian day for the date.
     ///
+    /// Julian day zero is November 24, 4714 BCE in the proleptic Gregorian calendar (year -4713 in
+    /// this crate's astronomical numbering). Each Julian day is counted as beginning at midnight.
+    ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
     /// freely available [here](https://www.researchgate.net/publication/316558298_Date_Algorithms).
     ///
@@ -726,6 +978,20 @@ impl Date {
             + div_floor!(year, 400)
             + 1_721_425
     }
+
+    /// Get the Julian day for the date as an `i64`.
+    ///
+    /// This is identical to [`Date::to_julian_day`], but allows further arithmetic to be performed
+    /// without concern for overflow.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(-4713 - 11 - 24).to_julian_day_i64(), 0);
+    /// assert_eq!(date!(2000 - 01 - 01).to_julian_day_i64(), 2_451_545);
+    /// ```
+    pub const fn to_julian_day_i64(self) -> i64 {
+        self.to_julian_day() as _
+    }
     // endregion getters
 
     // region: checked arithmetic
@@ -905,6 +1171,84 @@ impl Date {
         }
     }
 
+    /// Computes `self + months`, returning `None` if an overflow occurred.
+    ///
+    /// The year and month are advanced by the given number of calendar months. If the day does not
+    /// exist in the resulting month, it is clamped to the last day of that month. As such, adding
+    /// one month to January 31 results in the last day of February.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2020 - 01 - 15).checked_add_months(1),
+    ///     Some(date!(2020 - 02 - 15))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 01 - 31).checked_add_months(1),
+    ///     Some(date!(2020 - 02 - 29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2021 - 01 - 31).checked_add_months(1),
+    ///     Some(date!(2021 - 02 - 28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 03 - 31).checked_add_months(-13),
+    ///     Some(date!(2019 - 02 - 28))
+    /// );
+    /// assert_eq!(Date::MAX.checked_add_months(1), None);
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        self.checked_add_months_wide(months as _)
+    }
+
+    /// Computes `self - months`, returning `None` if an overflow occurred.
+    ///
+    /// The year and month are moved back by the given number of calendar months. If the day does
+    /// not exist in the resulting month, it is clamped to the last day of that month. As such,
+    /// subtracting one month from March 31 results in the last day of February.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2020 - 03 - 15).checked_sub_months(1),
+    ///     Some(date!(2020 - 02 - 15))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020 - 03 - 31).checked_sub_months(1),
+    ///     Some(date!(2020 - 02 - 29))
+    /// );
+    /// assert_eq!(Date::MIN.checked_sub_months(1), None);
+    /// ```
+    pub const fn checked_sub_months(self, months: i32) -> Option<Self> {
+        self.checked_add_months_wide(-(months as i64))
+    }
+
+    /// Computes `self + months`, returning `None` if an overflow occurred. The day is clamped to
+    /// the length of the resulting month.
+    const fn checked_add_months_wide(self, months: i64) -> Option<Self> {
+        let (year, month, day) = self.to_calendar_date();
+        let total_months = year as i64 * 12 + (month as i64 - 1) + months;
+        let year = div_floor!(total_months, 12);
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None;
+        }
+        let year = year as i32;
+        let month = Month::January.nth_next(total_months.rem_euclid(12) as _);
+        let days_in_month = days_in_year_month(year, month);
+        let day = if day > days_in_month {
+            days_in_month
+        } else {
+            day
+        };
+
+        // Safety: `ordinal` is not zero.
+        Some(unsafe {
+            Self::__from_ordinal_date_unchecked(year, month.days_in_year_before(year) + day as u16)
+        })
+    }
+
     /// Calculates the first occurrence of a weekday that is strictly later than a given `Date`.
     /// Returns `None` if an overflow occurred.
     pub(crate) const fn checked_next_occurrence(self, weekday: Weekday) -> Option<Self> {
@@ -964,6 +1308,108 @@ impl Date {
         const_try_opt!(self.checked_prev_occurrence(weekday))
             .checked_sub(Duration::weeks(n as i64 - 1))
     }
+
+    /// Computes `self` plus the given number of business days (Monday through Friday), skipping
+    /// weekends. A negative value moves backward. Returns `None` if the resulting value is out of
+    /// range. No holidays are taken into account.
+    ///
+    /// If `self` falls on a weekend, counting begins from the adjacent business day in the
+    /// direction of travel, such that one business day after a Saturday is the following Monday.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 09).add_business_days(1), // Friday
+    ///     Some(date!(2023 - 06 - 12))                 // Monday
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 10).add_business_days(1), // Saturday
+    ///     Some(date!(2023 - 06 - 12))                 // Monday
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 12).add_business_days(-1), // Monday
+    ///     Some(date!(2023 - 06 - 09))                  // Friday
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 12).add_business_days(10),
+    ///     Some(date!(2023 - 06 - 26))
+    /// );
+    /// ```
+    pub const fn add_business_days(self, n: i32) -> Option<Self> {
+        if n == 0 {
+            return Some(self);
+        }
+
+        let julian_day = self.to_julian_day() as i64;
+        let weekday = self.weekday().number_days_from_monday() as i64;
+        let n = n as i64;
+
+        let julian_day = if n >= 0 {
+            // Treat a weekend as the preceding Friday, as the next business day is the same.
+            let (base, weekday) = if weekday >= 5 {
+                (julian_day - (weekday - 4), 4)
+            } else {
+                (julian_day, weekday)
+            };
+            let mut days = n / 5 * 7 + n % 5;
+            if weekday + n % 5 >= 5 {
+                days += 2;
+            }
+            base + days
+        } else {
+            // Treat a weekend as the following Monday, as the previous business day is the same.
+            let (base, weekday) = if weekday >= 5 {
+                (julian_day + (7 - weekday), 0)
+            } else {
+                (julian_day, weekday)
+            };
+            let n = -n;
+            let mut days = n / 5 * 7 + n % 5;
+            if weekday - n % 5 < 0 {
+                days += 2;
+            }
+            base - days
+        };
+
+        match Self::from_julian_day_i64(julian_day) {
+            Ok(date) => Some(date),
+            Err(_) => None,
+        }
+    }
+
+    /// Computes the number of business days (Monday through Friday) from `self` up to, but not
+    /// including, `other`. The value is negative if `other` is before `self`. No holidays are
+    /// taken into account.
+    ///
+    /// When both dates are business days, adding the returned value to `self` with
+    /// [`Date::add_business_days`] results in `other`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 05).business_days_between(date!(2023 - 06 - 12)),
+    ///     5
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 12).business_days_between(date!(2023 - 06 - 05)),
+    ///     -5
+    /// );
+    /// assert_eq!(
+    ///     date!(2023 - 06 - 10).business_days_between(date!(2023 - 06 - 12)),
+    ///     0
+    /// );
+    /// ```
+    pub const fn business_days_between(self, other: Self) -> i64 {
+        /// The number of business days before the given Julian day, counting from Julian day zero
+        /// (a Monday).
+        const fn business_days_before(julian_day: i32) -> i64 {
+            let julian_day = julian_day as i64;
+            let remainder = julian_day.rem_euclid(7);
+            julian_day.div_euclid(7) * 5 + if remainder > 5 { 5 } else { remainder }
+        }
+
+        business_days_before(other.to_julian_day()) - business_days_before(self.to_julian_day())
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -1048,6 +1494,36 @@ impl Date {
     }
     // region: saturating arithmetic
 
+    // region: clamping
+    /// Restrict the date to the inclusive range `min..=max`. Unlike [`Ord::clamp`], this method
+    /// can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `min` is later than `max`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// let (min, max) = (date!(2020 - 01 - 01), date!(2020 - 12 - 31));
+    /// assert_eq!(date!(2019 - 06 - 15).clamp(min, max), min);
+    /// assert_eq!(date!(2021 - 06 - 15).clamp(min, max), max);
+    /// assert_eq!(date!(2020 - 06 - 15).clamp(min, max), date!(2020 - 06 - 15));
+    /// ```
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        if min.value.get() > max.value.get() {
+            crate::expect_failed("minimum must not be greater than maximum");
+        }
+
+        if self.value.get() < min.value.get() {
+            min
+        } else if self.value.get() > max.value.get() {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion clamping
+
     // region: replacement
     /// Replace the year. The month and day will be unchanged.
     ///
@@ -1291,6 +1767,218 @@ impl Date {
 }
 // endregion attach time
 
+// region: iteration
+/// Methods to iterate over consecutive dates.
+impl Date {
+    /// Create an iterator over every date in the half-open range `start..end`. No dates are
+    /// yielded if `start >= end`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::range(date!(2020 - 02 - 27), date!(2020 - 03 - 01)).collect::<Vec<_>>(),
+    ///     [
+    ///         date!(2020 - 02 - 27),
+    ///         date!(2020 - 02 - 28),
+    ///         date!(2020 - 02 - 29)
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     Date::range(date!(2020 - 03 - 01), date!(2020 - 02 - 27)).count(),
+    ///     0
+    /// );
+    /// ```
+    pub const fn range(start: Self, end: Self) -> DateRange {
+        DateRange {
+            front: start.to_julian_day(),
+            back: end.to_julian_day(),
+        }
+    }
+
+    /// Create an iterator over every date from `self` through [`Date::MAX`], inclusive.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// let mut days = date!(2019 - 12 - 31).iter_days();
+    /// assert_eq!(days.next(), Some(date!(2019 - 12 - 31)));
+    /// assert_eq!(days.next(), Some(date!(2020 - 01 - 01)));
+    /// ```
+    pub const fn iter_days(self) -> DateRange {
+        DateRange {
+            front: self.to_julian_day(),
+            back: Self::MAX.to_julian_day() + 1,
+        }
+    }
+
+    /// Create an iterator over the grid of days in the given month, as would be displayed in a
+    /// calendar. Each row of the grid is one week beginning on `week_start`. Blank cells before
+    /// the first day and after the last day of the month are `None`, such that the number of items
+    /// is always a multiple of seven. Returns `None` if the year is out of range.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month, Weekday};
+    /// # use time_macros::date;
+    /// // February 2021 begins on a Monday and has exactly four weeks.
+    /// let grid = Date::calendar_month(2021, Month::February, Weekday::Monday).unwrap();
+    /// assert_eq!(grid.len(), 28);
+    ///
+    /// let mut grid = Date::calendar_month(2021, Month::February, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.len(), 35);
+    /// assert_eq!(grid.next(), Some(None));
+    /// assert_eq!(grid.next(), Some(Some(date!(2021 - 02 - 01))));
+    /// assert_eq!(grid.last(), Some(None));
+    /// ```
+    pub const fn calendar_month(
+        year: i32,
+        month: Month,
+        week_start: Weekday,
+    ) -> Option<CalendarMonth> {
+        let Ok(first) = Self::from_calendar_date(year, month, 1) else {
+            return None;
+        };
+        let leading = (first.weekday().number_days_from_monday() + 7
+            - week_start.number_days_from_monday())
+            % 7;
+        let days = days_in_year_month(year, month);
+
+        Some(CalendarMonth {
+            first: first.to_julian_day(),
+            leading,
+            days,
+            front: 0,
+            back: (leading + days + 6) / 7 * 7,
+        })
+    }
+}
+
+/// An iterator over consecutive [`Date`]s.
+///
+/// This struct is created by [`Date::range`] and [`Date::iter_days`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    /// The Julian day of the next date to be yielded from the front.
+    front: i32,
+    /// The Julian day one past the next date to be yielded from the back.
+    back: i32,
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = Date::from_julian_day_unchecked(self.front);
+        self.front += 1;
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+        self.front += n as i32;
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(Date::from_julian_day_unchecked(self.back))
+    }
+}
+
+impl ExactSizeIterator for DateRange {
+    fn len(&self) -> usize {
+        if self.front >= self.back {
+            0
+        } else {
+            (self.back - self.front) as _
+        }
+    }
+}
+
+impl FusedIterator for DateRange {}
+
+/// An iterator over the grid of days in a month, as would be displayed in a calendar.
+///
+/// This struct is created by [`Date::calendar_month`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarMonth {
+    /// The Julian day of the first day of the month.
+    first: i32,
+    /// The number of blank cells before the first day of the month.
+    leading: u8,
+    /// The number of days in the month.
+    days: u8,
+    /// The index of the next cell to be yielded from the front.
+    front: u8,
+    /// The index one past the next cell to be yielded from the back.
+    back: u8,
+}
+
+impl CalendarMonth {
+    /// Obtain the cell at the given index, which must be in range.
+    const fn cell(&self, index: u8) -> Option<Date> {
+        if index < self.leading || index >= self.leading + self.days {
+            None
+        } else {
+            Some(Date::from_julian_day_unchecked(
+                self.first + (index - self.leading) as i32,
+            ))
+        }
+    }
+}
+
+impl Iterator for CalendarMonth {
+    type Item = Option<Date>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let cell = self.cell(self.front);
+        self.front += 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CalendarMonth {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.cell(self.back))
+    }
+}
+
+impl ExactSizeIterator for CalendarMonth {
+    fn len(&self) -> usize {
+        (self.back - self.front) as _
+    }
+}
+
+impl FusedIterator for CalendarMonth {}
+// endregion iteration
+
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
 impl Date {
@@ -1303,6 +1991,54 @@ impl Date {
         format.format_into(output, Some(self), None, None)
     }
 
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing the result to a [`fmt::Write`] implementor.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::date;
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut buf = String::new();
+    /// date!(2020 - 01 - 02).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_fmt(output, Some(self), None, None)
+    }
+
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing the result to the start of a byte slice. The number of bytes written is returned.
+    ///
+    /// If the slice is too small to hold the formatted value, [`Format::BufferTooSmall`] is
+    /// returned. The contents of the slice are unspecified in this situation.
+    ///
+    /// [`Format::BufferTooSmall`]: error::Format::BufferTooSmall
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::date;
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let mut buf = [0; 32];
+    /// let len = date!(2020 - 01 - 02).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02");
+    /// assert!(date!(2020 - 01 - 02)
+    ///     .format_into_slice(&mut buf[..9], &format)
+    ///     .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_slice(output, Some(self), None, None)
+    }
+
     /// Format the `Date` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -1335,6 +2071,30 @@ impl Date {
     ) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the value and the number of bytes
+    /// consumed.
+    ///
+    /// Unlike [`Date::parse`], input may remain after the value is parsed.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// assert_eq!(
+    ///     Date::parse_partial("2020-01-02 rest", &format)?,
+    ///     (date!(2020 - 01 - 02), 10)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_partial(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, usize), error::Parse> {
+        let (parsed, consumed) = description.parse_partial(input.as_bytes())?;
+        Ok((parsed.try_into()?, consumed))
+    }
 }
 
 mod private {
@@ -1490,4 +2250,22 @@ impl Sub for Date {
         Duration::days((self.to_julian_day() - other.to_julian_day()).extend())
     }
 }
+
+impl TryFrom<(i32, Month, u8)> for Date {
+    type Error = error::ComponentRange;
+
+    /// Equivalent to [`Date::from_calendar_date`].
+    fn try_from((year, month, day): (i32, Month, u8)) -> Result<Self, Self::Error> {
+        Self::from_calendar_date(year, month, day)
+    }
+}
+
+impl TryFrom<(i32, u16)> for Date {
+    type Error = error::ComponentRange;
+
+    /// Equivalent to [`Date::from_ordinal_date`].
+    fn try_from((year, ordinal): (i32, u16)) -> Result<Self, Self::Error> {
+        Self::from_ordinal_date(year, ordinal)
+    }
+}
 // endregion trait impls
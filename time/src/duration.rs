@@ -1,5 +1,7 @@
 //! The [`Duration`] struct and its associated `impl`s.
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 use core::cmp::Ordering;
 use core::fmt;
 use core::iter::Sum;
@@ -36,7 +38,19 @@ type Nanoseconds =
 /// Each `Duration` is composed of a whole number of seconds and a fractional part represented in
 /// nanoseconds.
 ///
-/// This implementation allows for negative durations, unlike [`core::time::Duration`].
+/// This implementation allows for negative durations, unlike [`core::time::Duration`]. The whole
+/// and fractional parts always have the same sign, so the value is truncated toward zero when
+/// split. For example, a duration of -1.5 seconds has `-1` whole seconds and `-500_000_000`
+/// subsecond nanoseconds.
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// let duration = (-1.5).seconds();
+/// assert_eq!(duration.whole_seconds(), -1);
+/// assert_eq!(duration.subsec_milliseconds(), -500);
+/// assert_eq!(duration.subsec_microseconds(), -500_000);
+/// assert_eq!(duration.subsec_nanoseconds(), -500_000_000);
+/// ```
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Duration {
     /// Number of whole seconds.
@@ -339,6 +353,25 @@ impl Duration {
             self.nanoseconds.get().unsigned_abs(),
         )
     }
+
+    /// Get the magnitude of the duration as a [`std::time::Duration`], discarding the sign.
+    ///
+    /// This is an alias of [`Duration::unsigned_abs`]. As the standard library's type has an
+    /// unsigned number of seconds, the magnitude of every value, including [`Duration::MIN`], can
+    /// be represented.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time::ext::{NumericalDuration, NumericalStdDuration};
+    /// assert_eq!((-1.5).seconds().abs_std(), 1.5.std_seconds());
+    /// assert_eq!(
+    ///     Duration::MIN.abs_std(),
+    ///     std::time::Duration::new(i64::MIN.unsigned_abs(), 999_999_999)
+    /// );
+    /// ```
+    pub const fn abs_std(self) -> StdDuration {
+        self.unsigned_abs()
+    }
     // endregion abs
 
     // region: constructors
@@ -737,6 +770,160 @@ impl Duration {
         }
     }
 
+    /// Create a new `Duration` with the given number of weeks, returning an error if an overflow
+    /// occurs. This is the non-panicking counterpart to [`Duration::weeks`].
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_weeks(1), Ok(Duration::seconds(604_800)));
+    /// assert!(Duration::try_weeks(i64::MAX).is_err());
+    /// ```
+    pub const fn try_weeks(weeks: i64) -> Result<Self, error::ConversionRange> {
+        match weeks.checked_mul(Second::per(Week) as _) {
+            Some(seconds) => Ok(Self::seconds(seconds)),
+            None => Err(error::ConversionRange),
+        }
+    }
+
+    /// Create a new `Duration` with the given number of days, returning an error if an overflow
+    /// occurs. This is the non-panicking counterpart to [`Duration::days`].
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_days(1), Ok(Duration::seconds(86_400)));
+    /// assert!(Duration::try_days(i64::MAX).is_err());
+    /// ```
+    pub const fn try_days(days: i64) -> Result<Self, error::ConversionRange> {
+        match days.checked_mul(Second::per(Day) as _) {
+            Some(seconds) => Ok(Self::seconds(seconds)),
+            None => Err(error::ConversionRange),
+        }
+    }
+
+    /// Create a new `Duration` with the given number of hours, returning an error if an overflow
+    /// occurs. This is the non-panicking counterpart to [`Duration::hours`].
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_hours(1), Ok(Duration::seconds(3_600)));
+    /// assert!(Duration::try_hours(i64::MAX).is_err());
+    /// ```
+    pub const fn try_hours(hours: i64) -> Result<Self, error::ConversionRange> {
+        match hours.checked_mul(Second::per(Hour) as _) {
+            Some(seconds) => Ok(Self::seconds(seconds)),
+            None => Err(error::ConversionRange),
+        }
+    }
+
+    /// Create a new `Duration` with the given number of minutes, returning an error if an
+    /// overflow occurs. This is the non-panicking counterpart to [`Duration::minutes`].
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_minutes(1), Ok(Duration::seconds(60)));
+    /// assert!(Duration::try_minutes(i64::MAX).is_err());
+    /// ```
+    pub const fn try_minutes(minutes: i64) -> Result<Self, error::ConversionRange> {
+        match minutes.checked_mul(Second::per(Minute) as _) {
+            Some(seconds) => Ok(Self::seconds(seconds)),
+            None => Err(error::ConversionRange),
+        }
+    }
+
+    /// Create a new `Duration` with the given number of seconds. Every `i64` is in range, so this
+    /// never fails. It is provided for symmetry with the other `try_` constructors.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_seconds(1), Ok(Duration::seconds(1)));
+    /// assert_eq!(
+    ///     Duration::try_seconds(i64::MAX),
+    ///     Ok(Duration::seconds(i64::MAX))
+    /// );
+    /// ```
+    pub const fn try_seconds(seconds: i64) -> Result<Self, error::ConversionRange> {
+        Ok(Self::seconds(seconds))
+    }
+
+    /// Create a new `Duration` with the given number of milliseconds. Every `i64` is in range, so
+    /// this never fails. It is provided for symmetry with the other `try_` constructors.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_milliseconds(1), Ok(Duration::milliseconds(1)));
+    /// ```
+    pub const fn try_milliseconds(milliseconds: i64) -> Result<Self, error::ConversionRange> {
+        Ok(Self::milliseconds(milliseconds))
+    }
+
+    /// Create a new `Duration` with the given number of microseconds. Every `i64` is in range, so
+    /// this never fails. It is provided for symmetry with the other `try_` constructors.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_microseconds(1), Ok(Duration::microseconds(1)));
+    /// ```
+    pub const fn try_microseconds(microseconds: i64) -> Result<Self, error::ConversionRange> {
+        Ok(Self::microseconds(microseconds))
+    }
+
+    /// Create a new `Duration` with the given number of nanoseconds. Every `i64` is in range, so
+    /// this never fails. It is provided for symmetry with the other `try_` constructors.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// assert_eq!(Duration::try_nanoseconds(1), Ok(Duration::nanoseconds(1)));
+    /// ```
+    pub const fn try_nanoseconds(nanoseconds: i64) -> Result<Self, error::ConversionRange> {
+        Ok(Self::nanoseconds(nanoseconds))
+    }
+
+    /// Create a new `Duration` from a [`std::time::Duration`](StdDuration).
+    ///
+    /// The only way for the conversion to fail is for the value to be larger than
+    /// [`Duration::MAX`], in which case [`ConversionRange`](error::ConversionRange) is returned.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// # use core::time::Duration as StdDuration;
+    /// assert_eq!(
+    ///     Duration::try_from_std(StdDuration::new(1, 500_000_000)),
+    ///     Ok(1.5.seconds())
+    /// );
+    /// assert!(Duration::try_from_std(StdDuration::MAX).is_err());
+    /// ```
+    pub const fn try_from_std(duration: StdDuration) -> Result<Self, error::ConversionRange> {
+        let seconds = duration.as_secs();
+        if seconds > i64::MAX as u64 {
+            return Err(error::ConversionRange);
+        }
+
+        // Safety: `subsec_nanos` is always less than one billion.
+        Ok(unsafe { Self::new_unchecked(seconds as _, duration.subsec_nanos() as _) })
+    }
+
+    /// Create a new `Duration` from a [`std::time::Duration`](StdDuration), saturating to
+    /// [`Duration::MAX`] if the value is too large to be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// # use core::time::Duration as StdDuration;
+    /// assert_eq!(
+    ///     Duration::saturating_from_std(StdDuration::new(1, 500_000_000)),
+    ///     1.5.seconds()
+    /// );
+    /// assert_eq!(
+    ///     Duration::saturating_from_std(StdDuration::MAX),
+    ///     Duration::MAX
+    /// );
+    /// ```
+    pub const fn saturating_from_std(duration: StdDuration) -> Self {
+        match Self::try_from_std(duration) {
+            Ok(duration) => duration,
+            Err(_) => Self::MAX,
+        }
+    }
+
     /// Create a new `Duration` with the given number of nanoseconds.
     ///
     /// As the input range cannot be fully mapped to the output, this should only be used where it's
@@ -831,6 +1018,41 @@ impl Duration {
         self.seconds as f64 + self.nanoseconds.get() as f64 / Nanosecond::per(Second) as f64
     }
 
+    /// Get the number of fractional seconds in the duration, returning an error if the whole
+    /// number of seconds is not preserved.
+    ///
+    /// An `f64` can represent every integer with a magnitude of at most 2<sup>53</sup>. Beyond
+    /// that, or when the fractional part rounds up to the next second,
+    /// [`as_seconds_f64`](Self::as_seconds_f64) silently returns a different number of whole
+    /// seconds. The fractional part is rounded in the same manner, so precision below one second
+    /// may still be lost. [`whole_seconds_and_subsec_nanoseconds`] obtains the exact value.
+    ///
+    /// [`whole_seconds_and_subsec_nanoseconds`]: Self::whole_seconds_and_subsec_nanoseconds
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(1.5.seconds().as_seconds_f64_exact(), Ok(1.5));
+    /// assert_eq!(
+    ///     Duration::seconds(1 << 53).as_seconds_f64_exact(),
+    ///     Ok(9_007_199_254_740_992.0)
+    /// );
+    /// assert!(Duration::seconds((1 << 53) + 1)
+    ///     .as_seconds_f64_exact()
+    ///     .is_err());
+    /// ```
+    pub fn as_seconds_f64_exact(self) -> Result<f64, error::ConversionRange> {
+        /// 2<sup>63</sup>, above which the conversion to `i64` saturates.
+        const MAX: f64 = 9_223_372_036_854_775_808.0;
+
+        let seconds = self.as_seconds_f64();
+        if seconds < MAX && seconds as i64 == self.seconds {
+            Ok(seconds)
+        } else {
+            Err(error::ConversionRange)
+        }
+    }
+
     /// Get the number of fractional seconds in the duration.
     ///
     /// ```rust
@@ -923,6 +1145,30 @@ impl Duration {
         self.nanoseconds.get()
     }
 
+    /// Get the number of whole seconds and the number of nanoseconds past them. This is exact, and
+    /// is equivalent to calling [`whole_seconds`](Self::whole_seconds) and
+    /// [`subsec_nanoseconds`](Self::subsec_nanoseconds). Both values have the same sign as the
+    /// duration.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(
+    ///     1.000_000_400
+    ///         .seconds()
+    ///         .whole_seconds_and_subsec_nanoseconds(),
+    ///     (1, 400)
+    /// );
+    /// assert_eq!(
+    ///     (-1.000_000_400)
+    ///         .seconds()
+    ///         .whole_seconds_and_subsec_nanoseconds(),
+    ///     (-1, -400)
+    /// );
+    /// ```
+    pub const fn whole_seconds_and_subsec_nanoseconds(self) -> (i64, i32) {
+        (self.seconds, self.nanoseconds.get())
+    }
+
     /// Get the number of nanoseconds past the number of whole seconds.
     #[cfg(feature = "quickcheck")]
     pub(crate) const fn subsec_nanoseconds_ranged(self) -> Nanoseconds {
@@ -1165,6 +1411,520 @@ impl Duration {
     }
     // endregion saturating arithmetic
 
+    // region: ratio
+    /// Divide the duration by another duration, returning the ratio as an `f64`.
+    ///
+    /// Both durations are converted to nanoseconds before dividing, avoiding the precision loss
+    /// of dividing their fractional seconds. As with IEEE division, dividing by a zero duration
+    /// results in an infinite value, or NaN if `self` is also zero.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(3.seconds().div_duration_f64(2.seconds()), 1.5);
+    /// assert_eq!((-1).minutes().div_duration_f64(15.seconds()), -4.0);
+    /// assert_eq!(1.seconds().div_duration_f64(0.seconds()), f64::INFINITY);
+    /// assert!(0.seconds().div_duration_f64(0.seconds()).is_nan());
+    /// ```
+    pub fn div_duration_f64(self, rhs: Self) -> f64 {
+        self.whole_nanoseconds() as f64 / rhs.whole_nanoseconds() as f64
+    }
+
+    /// Divide the duration by another duration, returning the ratio as an `f32`.
+    ///
+    /// Both durations are converted to nanoseconds before dividing, avoiding the precision loss
+    /// of dividing their fractional seconds. As with IEEE division, dividing by a zero duration
+    /// results in an infinite value, or NaN if `self` is also zero.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(3.seconds().div_duration_f32(2.seconds()), 1.5);
+    /// assert_eq!((-1).minutes().div_duration_f32(15.seconds()), -4.0);
+    /// assert_eq!(1.seconds().div_duration_f32(0.seconds()), f32::INFINITY);
+    /// assert!(0.seconds().div_duration_f32(0.seconds()).is_nan());
+    /// ```
+    pub fn div_duration_f32(self, rhs: Self) -> f32 {
+        self.whole_nanoseconds() as f32 / rhs.whole_nanoseconds() as f32
+    }
+
+    /// Divide the duration by another duration, returning the number of whole periods of `rhs`
+    /// along with the remainder. The division is Euclidean, so the remainder is never negative and
+    /// is always less than the magnitude of `rhs`. Returns `None` if `rhs` is zero or if the
+    /// quotient does not fit in an `i64`.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(50.minutes().div_mod(15.minutes()), Some((3, 5.minutes())));
+    /// assert_eq!(
+    ///     (-50).minutes().div_mod(15.minutes()),
+    ///     Some((-4, 10.minutes()))
+    /// );
+    /// assert_eq!(1.seconds().div_mod(0.seconds()), None);
+    /// ```
+    pub const fn div_mod(self, rhs: Self) -> Option<(i64, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+
+        let nanoseconds = self.whole_nanoseconds();
+        let rhs = rhs.whole_nanoseconds();
+        let quotient = nanoseconds.div_euclid(rhs);
+        if quotient > i64::MAX as i128 || quotient < i64::MIN as i128 {
+            return None;
+        }
+
+        Some((
+            quotient as i64,
+            Self::nanoseconds_i128(nanoseconds.rem_euclid(rhs)),
+        ))
+    }
+    // endregion ratio
+
+    // region: floating-point scaling
+    /// Multiply the duration by a floating-point value, rounding to the nearest nanosecond.
+    ///
+    /// The product is computed from the number of nanoseconds rather than fractional seconds, so
+    /// the result is exact whenever the duration and product are representable in an `f64`.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(1.seconds().mul_f64(0.5), 500.milliseconds());
+    /// assert_eq!(2.seconds().mul_f64(-1.25), (-2.5).seconds());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the result is not finite or overflows. [`Duration::checked_mul_f64`] can
+    /// be used to avoid this.
+    pub fn mul_f64(self, rhs: f64) -> Self {
+        expect_opt!(
+            self.checked_mul_f64(rhs),
+            "overflow when multiplying duration by float"
+        )
+    }
+
+    /// Divide the duration by a floating-point value, rounding to the nearest nanosecond.
+    ///
+    /// The quotient is computed from the number of nanoseconds rather than fractional seconds, so
+    /// the result is exact whenever the duration and quotient are representable in an `f64`.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(1.seconds().div_f64(2.0), 500.milliseconds());
+    /// assert_eq!(1.seconds().div_f64(-0.5), (-2).seconds());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the result is not finite or overflows, such as when dividing by zero.
+    /// [`Duration::checked_div_f64`] can be used to avoid this.
+    pub fn div_f64(self, rhs: f64) -> Self {
+        expect_opt!(
+            self.checked_div_f64(rhs),
+            "overflow when dividing duration by float"
+        )
+    }
+
+    /// Multiply the duration by a floating-point value, rounding to the nearest nanosecond.
+    /// Returns `None` if the result is not finite or overflows.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(1.seconds().checked_mul_f64(0.5), Some(500.milliseconds()));
+    /// assert_eq!(Duration::MAX.checked_mul_f64(2.0), None);
+    /// assert_eq!(1.seconds().checked_mul_f64(f64::NAN), None);
+    /// assert_eq!(1.seconds().checked_mul_f64(f64::INFINITY), None);
+    /// ```
+    pub fn checked_mul_f64(self, rhs: f64) -> Option<Self> {
+        Self::checked_nanoseconds_f64(self.whole_nanoseconds() as f64 * rhs)
+    }
+
+    /// Divide the duration by a floating-point value, rounding to the nearest nanosecond. Returns
+    /// `None` if the result is not finite or overflows, such as when dividing by zero.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(1.seconds().checked_div_f64(2.0), Some(500.milliseconds()));
+    /// assert_eq!(Duration::MAX.checked_div_f64(0.5), None);
+    /// assert_eq!(1.seconds().checked_div_f64(0.0), None);
+    /// assert_eq!(0.seconds().checked_div_f64(0.0), None);
+    /// ```
+    pub fn checked_div_f64(self, rhs: f64) -> Option<Self> {
+        Self::checked_nanoseconds_f64(self.whole_nanoseconds() as f64 / rhs)
+    }
+
+    /// Create a `Duration` from a number of nanoseconds, rounding to the nearest nanosecond with
+    /// ties away from zero. Returns `None` if the value is not finite or is out of range.
+    fn checked_nanoseconds_f64(nanoseconds: f64) -> Option<Self> {
+        // Reject values far outside the range of `Duration`, along with NaN and infinities. This
+        // ensures that the conversion below does not saturate. The exact bounds are checked after
+        // rounding.
+        if !(-1e30..=1e30).contains(&nanoseconds) {
+            return None;
+        }
+
+        let mut rounded = nanoseconds as i128;
+        let fraction = nanoseconds - rounded as f64;
+        if fraction >= 0.5 {
+            rounded += 1;
+        } else if fraction <= -0.5 {
+            rounded -= 1;
+        }
+
+        let seconds = rounded / Nanosecond::per(Second) as i128;
+        if seconds > i64::MAX as i128 || seconds < i64::MIN as i128 {
+            return None;
+        }
+        Some(Self::nanoseconds_i128(rounded))
+    }
+    // endregion floating-point scaling
+
+    // region: rounding
+    /// Round the duration to the nearest multiple of `granularity`. Values exactly halfway between
+    /// two multiples are rounded away from zero, so that negative durations are rounded
+    /// symmetrically with positive ones.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive or if the result overflows.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(7.minutes().round_to(15.minutes()), 0.minutes());
+    /// assert_eq!(450.seconds().round_to(15.minutes()), 15.minutes());
+    /// assert_eq!((-450).seconds().round_to(15.minutes()), (-15).minutes());
+    /// assert_eq!(1_499.milliseconds().round_to(1.seconds()), 1.seconds());
+    /// ```
+    pub const fn round_to(self, granularity: Self) -> Self {
+        let (nanoseconds, remainder, granularity) = self.rounding_parts(granularity);
+        if remainder.abs() >= granularity - remainder.abs() {
+            Self::nanoseconds_i128(nanoseconds - remainder + nanoseconds.signum() * granularity)
+        } else {
+            Self::nanoseconds_i128(nanoseconds - remainder)
+        }
+    }
+
+    /// Round the duration toward zero, to a multiple of `granularity`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(14.minutes().round_down_to(15.minutes()), 0.minutes());
+    /// assert_eq!((-16).minutes().round_down_to(15.minutes()), (-15).minutes());
+    /// ```
+    pub const fn round_down_to(self, granularity: Self) -> Self {
+        let (nanoseconds, remainder, _) = self.rounding_parts(granularity);
+        Self::nanoseconds_i128(nanoseconds - remainder)
+    }
+
+    /// Round the duration away from zero, to a multiple of `granularity`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive or if the result overflows.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(1.minutes().round_up_to(15.minutes()), 15.minutes());
+    /// assert_eq!(15.minutes().round_up_to(15.minutes()), 15.minutes());
+    /// assert_eq!((-16).minutes().round_up_to(15.minutes()), (-30).minutes());
+    /// ```
+    pub const fn round_up_to(self, granularity: Self) -> Self {
+        let (nanoseconds, remainder, granularity) = self.rounding_parts(granularity);
+        if remainder == 0 {
+            self
+        } else {
+            Self::nanoseconds_i128(nanoseconds - remainder + nanoseconds.signum() * granularity)
+        }
+    }
+
+    /// Obtain the number of nanoseconds in the duration, the remainder when dividing it by
+    /// `granularity`, and `granularity` in nanoseconds. The remainder has the same sign as the
+    /// duration.
+    const fn rounding_parts(self, granularity: Self) -> (i128, i128, i128) {
+        if !granularity.is_positive() {
+            crate::expect_failed("rounding granularity must be positive");
+        }
+
+        let granularity = granularity.whole_nanoseconds();
+        let nanoseconds = self.whole_nanoseconds();
+        (nanoseconds, nanoseconds % granularity, granularity)
+    }
+    // endregion rounding
+
+    // region: clamping
+    /// Restrict the duration to the inclusive range `min..=max`. Unlike [`Ord::clamp`], this method
+    /// can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `min` is greater than `max`.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.seconds().clamp(0.seconds(), 1.minutes()), 1.minutes());
+    /// assert_eq!((-5).seconds().clamp(0.seconds(), 1.minutes()), 0.seconds());
+    /// assert_eq!(30.seconds().clamp(0.seconds(), 1.minutes()), 30.seconds());
+    /// ```
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        let value = self.whole_nanoseconds();
+        let min_value = min.whole_nanoseconds();
+        let max_value = max.whole_nanoseconds();
+        if min_value > max_value {
+            crate::expect_failed("minimum must not be greater than maximum");
+        }
+
+        if value < min_value {
+            min
+        } else if value > max_value {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion clamping
+
+    // region: human-readable
+    /// Format the duration as a compact, human-readable string, such as `1h30m5s` or `2d3h`.
+    ///
+    /// Each nonzero unit is written from largest to smallest, using days (`d`), hours (`h`),
+    /// minutes (`m`), and seconds (`s`). Any part of the duration below one second is discarded.
+    /// Negative durations are prefixed with `-`, and a duration of zero is written as `0s`. For
+    /// the purposes of this method, a day is exactly 24 hours.
+    ///
+    /// Unlike the [`Display`](fmt::Display) implementation, this format is stable and can be
+    /// parsed with [`Duration::parse_human`]. To include the part of the duration below one second,
+    /// use [`Duration::format_human_subsec`].
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!((90.minutes() + 5.seconds()).format_human(), "1h30m5s");
+    /// assert_eq!((51.hours()).format_human(), "2d3h");
+    /// assert_eq!((-1.5).seconds().format_human(), "-1s");
+    /// assert_eq!(0.seconds().format_human(), "0s");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_human(self) -> String {
+        HumanReadable {
+            duration: Self::seconds(self.seconds),
+        }
+        .to_string()
+    }
+
+    /// Format the duration as a compact, human-readable string, including the part of the
+    /// duration below one second as a decimal fraction of the seconds.
+    ///
+    /// This is otherwise identical to [`Duration::format_human`]. Trailing zeros of the fraction
+    /// are omitted.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(
+    ///     (90.minutes() + 5.25.seconds()).format_human_subsec(),
+    ///     "1h30m5.25s"
+    /// );
+    /// assert_eq!(1.milliseconds().format_human_subsec(), "0.001s");
+    /// assert_eq!((-1.5).seconds().format_human_subsec(), "-1.5s");
+    /// assert_eq!(0.seconds().format_human_subsec(), "0s");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_human_subsec(self) -> String {
+        HumanReadable { duration: self }.to_string()
+    }
+
+    /// Parse a duration in the format produced by [`Duration::format_human`] and
+    /// [`Duration::format_human_subsec`].
+    ///
+    /// The input is an optional `-` followed by one or more values, each immediately followed by a
+    /// unit of `d`, `h`, `m`, or `s`. Units must be in that order and may each appear at most once,
+    /// but may be omitted. Values are not required to be less than the next larger unit, so `90m`
+    /// is accepted. Only the seconds may have a fraction, which is limited to nine digits.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(
+    ///     Duration::parse_human("1h30m5s")?,
+    ///     90.minutes() + 5.seconds()
+    /// );
+    /// assert_eq!(Duration::parse_human("2d3h")?, 51.hours());
+    /// assert_eq!(Duration::parse_human("-1.5s")?, (-1.5).seconds());
+    /// assert_eq!(Duration::parse_human("90m")?, 1.5.hours());
+    /// assert!(Duration::parse_human("5s1m").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "parsing")]
+    pub fn parse_human(input: &str) -> Result<Self, error::Parse> {
+        /// The error returned for any invalid input.
+        const INVALID: error::Parse = error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("duration"),
+        );
+
+        let (is_negative, mut input) = match input.as_bytes() {
+            [b'-', rest @ ..] => (true, rest),
+            input => (false, input),
+        };
+        if input.is_empty() {
+            return Err(INVALID);
+        }
+
+        let mut nanoseconds: i128 = 0;
+        // The number of seconds in the previous unit, which the next unit must be smaller than.
+        let mut previous_unit = u32::MAX;
+        while !input.is_empty() {
+            let num_digits = input.iter().take_while(|c| c.is_ascii_digit()).count();
+            if num_digits == 0 {
+                return Err(INVALID);
+            }
+            let (digits, remaining) = input.split_at(num_digits);
+            let value = digits.iter().try_fold(0_i128, |value, digit| {
+                value.checked_mul(10)?.checked_add(i128::from(digit - b'0'))
+            });
+
+            let (fraction, remaining) = match remaining {
+                [b'.', remaining @ ..] => {
+                    let num_digits = remaining.iter().take_while(|c| c.is_ascii_digit()).count();
+                    if !(1..=9).contains(&num_digits) {
+                        return Err(INVALID);
+                    }
+                    let (digits, remaining) = remaining.split_at(num_digits);
+                    let fraction = digits
+                        .iter()
+                        .chain(core::iter::repeat(&b'0'))
+                        .take(9)
+                        .fold(0, |value, digit| value * 10 + i128::from(digit - b'0'));
+                    (Some(fraction), remaining)
+                }
+                _ => (None, remaining),
+            };
+
+            let (unit, remaining) = match remaining {
+                [b'd', remaining @ ..] => (Second::per(Day), remaining),
+                [b'h', remaining @ ..] => (Second::per(Hour).extend(), remaining),
+                [b'm', remaining @ ..] => (Second::per(Minute).extend(), remaining),
+                [b's', remaining @ ..] => (1, remaining),
+                _ => return Err(INVALID),
+            };
+            if unit >= previous_unit || (fraction.is_some() && unit != 1) {
+                return Err(INVALID);
+            }
+            previous_unit = unit;
+
+            nanoseconds = value
+                .and_then(|value| value.checked_mul(i128::from(unit)))
+                .and_then(|seconds| seconds.checked_mul(Nanosecond::per(Second).into()))
+                .and_then(|value| value.checked_add(fraction.unwrap_or(0)))
+                .and_then(|value| nanoseconds.checked_add(value))
+                .ok_or(INVALID)?;
+            input = remaining;
+        }
+
+        if is_negative {
+            nanoseconds = -nanoseconds;
+        }
+        let seconds = nanoseconds / i128::from(Nanosecond::per(Second));
+        if seconds < i64::MIN.into() || seconds > i64::MAX.into() {
+            return Err(INVALID);
+        }
+        Ok(Self::nanoseconds_i128(nanoseconds))
+    }
+    // endregion human-readable
+
+    // region: ISO 8601
+    /// Parse an ISO 8601 duration, returning `None` if the input is invalid or out of range.
+    ///
+    /// Weeks, days, hours, minutes, and seconds are accepted, along with an optional leading sign.
+    /// Years and months are rejected, as their length is not fixed.
+    #[cfg(any(feature = "parsing", feature = "serde"))]
+    pub(crate) fn parse_iso8601(input: &str) -> Option<Self> {
+        let (is_negative, input) = match input.as_bytes().first() {
+            Some(b'-') => (true, &input[1..]),
+            Some(b'+') => (false, &input[1..]),
+            _ => (false, input),
+        };
+        let mut input = input.strip_prefix('P')?;
+
+        // The magnitude is accumulated so that `Duration::MIN` can be represented.
+        let mut seconds = 0_u64;
+        let mut nanoseconds = 0_u32;
+        let mut has_component = false;
+        let mut in_time = false;
+        // The index of the most recently parsed designator, used to enforce ordering.
+        let mut last_designator = 0;
+
+        while !input.is_empty() {
+            if !in_time && input.as_bytes()[0] == b'T' {
+                in_time = true;
+                input = &input[1..];
+                if input.is_empty() {
+                    return None;
+                }
+                continue;
+            }
+
+            let whole_len = input.bytes().take_while(u8::is_ascii_digit).count();
+            if whole_len == 0 {
+                return None;
+            }
+            let whole = input[..whole_len].parse::<u64>().ok()?;
+            input = &input[whole_len..];
+
+            let mut fraction = None;
+            if let Some(b'.' | b',') = input.as_bytes().first() {
+                let fraction_len = input[1..].bytes().take_while(u8::is_ascii_digit).count();
+                if fraction_len == 0 {
+                    return None;
+                }
+                fraction = Some(&input[1..=fraction_len]);
+                input = &input[fraction_len + 1..];
+            }
+
+            // The designator must be matched before slicing, as the input may not be ASCII.
+            let designator = *input.as_bytes().first()?;
+            let (index, unit_seconds) = match (in_time, designator) {
+                (false, b'W') => (1, Second::per(Week) as u64),
+                (false, b'D') => (2, Second::per(Day) as u64),
+                (true, b'H') => (3, Second::per(Hour) as u64),
+                (true, b'M') => (4, Second::per(Minute) as u64),
+                (true, b'S') => (5, 1),
+                _ => return None,
+            };
+            input = &input[1..];
+            if index <= last_designator {
+                return None;
+            }
+            last_designator = index;
+
+            if let Some(fraction) = fraction {
+                // Only the smallest unit may have a fractional part.
+                if designator != b'S' || !input.is_empty() {
+                    return None;
+                }
+                nanoseconds = fraction
+                    .bytes()
+                    .chain(core::iter::repeat(b'0'))
+                    .take(9)
+                    .fold(0, |acc, digit| acc * 10 + (digit - b'0') as u32);
+            }
+
+            seconds = seconds.checked_add(whole.checked_mul(unit_seconds)?)?;
+            has_component = true;
+        }
+
+        if !has_component {
+            return None;
+        }
+
+        Some(if is_negative {
+            Self::new(0_i64.checked_sub_unsigned(seconds)?, -(nanoseconds as i32))
+        } else {
+            Self::new(seconds.try_into().ok()?, nanoseconds as _)
+        })
+    }
+    // endregion ISO 8601
+
     /// Runs a closure, returning the duration of time it took to run. The return value of the
     /// closure is provided in the second part of the tuple.
     #[cfg(feature = "std")]
@@ -1182,6 +1942,60 @@ impl Duration {
     }
 }
 
+/// The compact, human-readable format of a [`Duration`].
+#[cfg(feature = "alloc")]
+struct HumanReadable {
+    /// The duration to format.
+    duration: Duration,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for HumanReadable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.duration.is_zero() {
+            return f.write_str("0s");
+        }
+        if self.duration.is_negative() {
+            f.write_str("-")?;
+        }
+
+        let seconds = self.duration.seconds.unsigned_abs();
+        let nanoseconds = self.duration.nanoseconds.get().unsigned_abs();
+
+        for (value, unit) in [
+            (seconds / Second::per(Day).extend::<u64>(), "d"),
+            (
+                seconds / Second::per(Hour).extend::<u64>() % Hour::per(Day).extend::<u64>(),
+                "h",
+            ),
+            (
+                seconds / Second::per(Minute).extend::<u64>() % Minute::per(Hour).extend::<u64>(),
+                "m",
+            ),
+        ] {
+            if value != 0 {
+                write!(f, "{value}{unit}")?;
+            }
+        }
+
+        let seconds = seconds % Second::per(Minute).extend::<u64>();
+        if nanoseconds != 0 {
+            // Omit the trailing zeros of the fraction.
+            let mut fraction = nanoseconds;
+            let mut num_digits = 9;
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                num_digits -= 1;
+            }
+            write!(f, "{seconds}.{fraction:0num_digits$}s")
+        } else if seconds != 0 {
+            write!(f, "{seconds}s")
+        } else {
+            Ok(())
+        }
+    }
+}
+
 // region: trait impls
 /// The format returned by this implementation is not stable and must not be relied upon.
 ///
@@ -1281,13 +2095,7 @@ impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
 
     fn try_from(original: StdDuration) -> Result<Self, error::ConversionRange> {
-        Ok(Self::new(
-            original
-                .as_secs()
-                .try_into()
-                .map_err(|_| error::ConversionRange)?,
-            original.subsec_nanos().cast_signed(),
-        ))
+        Self::try_from_std(original)
     }
 }
 
@@ -19,6 +19,11 @@ pub enum Format {
     InvalidComponent(&'static str),
     /// A value of `std::io::Error` was returned internally.
     StdIo(io::Error),
+    /// The output slice was too small to hold the formatted value.
+    ///
+    /// This variant is only returned when formatting into a slice.
+    #[non_exhaustive]
+    BufferTooSmall,
 }
 
 impl fmt::Display for Format {
@@ -33,6 +38,9 @@ impl fmt::Display for Format {
                 "The {component} component cannot be formatted into the requested format."
             ),
             Self::StdIo(err) => err.fmt(f),
+            Self::BufferTooSmall => {
+                f.write_str("The output is too small to hold the formatted value.")
+            }
         }
     }
 }
@@ -58,7 +66,9 @@ impl TryFrom<Format> for io::Error {
 impl std::error::Error for Format {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
-            Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
+            Self::InsufficientTypeInformation
+            | Self::InvalidComponent(_)
+            | Self::BufferTooSmall => None,
             Self::StdIo(ref err) => Some(err),
         }
     }
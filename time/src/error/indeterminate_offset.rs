@@ -33,3 +33,46 @@ impl TryFrom<crate::Error> for IndeterminateOffset {
         }
     }
 }
+
+/// The reason the system's UTC offset could not be determined.
+///
+/// This is returned by
+/// [`UtcOffset::local_offset_at_detailed`](crate::UtcOffset::local_offset_at_detailed)
+/// and [`OffsetDateTime::now_local_detailed`](crate::OffsetDateTime::now_local_detailed).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndeterminateOffsetReason {
+    /// The platform does not provide a supported way to obtain the UTC offset.
+    Unsupported,
+    /// The UTC offset was not requested from the system because doing so may be unsound, such as
+    /// when the process is multi-threaded on some Unix-like operating systems.
+    ///
+    /// See [`local_offset::set_soundness`](crate::util::local_offset::set_soundness) for details.
+    Unsound,
+    /// The system was queried, but it was unable to provide a valid UTC offset.
+    Indeterminate,
+}
+
+impl fmt::Display for IndeterminateOffsetReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str(
+                "The system's UTC offset could not be determined because the platform is not \
+                 supported",
+            ),
+            Self::Unsound => f.write_str(
+                "The system's UTC offset could not be determined because doing so may be unsound",
+            ),
+            Self::Indeterminate => f.write_str("The system's UTC offset could not be determined"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndeterminateOffsetReason {}
+
+impl From<IndeterminateOffsetReason> for IndeterminateOffset {
+    fn from(_: IndeterminateOffsetReason) -> Self {
+        Self
+    }
+}
@@ -14,6 +14,8 @@ pub enum InvalidFormatDescription {
     UnclosedOpeningBracket {
         /// The zero-based index of the opening bracket.
         index: usize,
+        /// The zero-based, half-open range of bytes containing the opening bracket.
+        index_range: (usize, usize),
     },
     /// A component name is not valid.
     #[non_exhaustive]
@@ -30,6 +32,8 @@ pub enum InvalidFormatDescription {
         value: String,
         /// The zero-based index the modifier starts at.
         index: usize,
+        /// The zero-based, half-open range of bytes containing the invalid modifier.
+        index_range: (usize, usize),
     },
     /// A component name is missing.
     #[non_exhaustive]
@@ -52,6 +56,8 @@ pub enum InvalidFormatDescription {
         what: &'static str,
         /// The zero-based index the item was expected to be found at.
         index: usize,
+        /// The zero-based, half-open range of bytes where the item was expected to be found.
+        index_range: (usize, usize),
     },
     /// Certain behavior is not supported in the given context.
     #[non_exhaustive]
@@ -63,6 +69,12 @@ pub enum InvalidFormatDescription {
         /// The zero-based index the error occurred at.
         index: usize,
     },
+    /// Nested format descriptions are nested beyond the maximum supported depth.
+    #[non_exhaustive]
+    NestingTooDeep {
+        /// The zero-based index of the opening bracket that exceeded the maximum depth.
+        index: usize,
+    },
 }
 
 impl From<InvalidFormatDescription> for crate::Error {
@@ -86,13 +98,13 @@ impl fmt::Display for InvalidFormatDescription {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use InvalidFormatDescription::*;
         match self {
-            UnclosedOpeningBracket { index } => {
+            UnclosedOpeningBracket { index, .. } => {
                 write!(f, "unclosed opening bracket at byte index {index}")
             }
             InvalidComponentName { name, index } => {
                 write!(f, "invalid component name `{name}` at byte index {index}")
             }
-            InvalidModifier { value, index } => {
+            InvalidModifier { value, index, .. } => {
                 write!(f, "invalid modifier `{value}` at byte index {index}")
             }
             MissingComponentName { index } => {
@@ -107,6 +119,7 @@ impl fmt::Display for InvalidFormatDescription {
             Expected {
                 what: expected,
                 index,
+                ..
             } => {
                 write!(f, "expected {expected} at byte index {index}")
             }
@@ -120,6 +133,12 @@ impl fmt::Display for InvalidFormatDescription {
                     "{what} is not supported in {context} at byte index {index}"
                 )
             }
+            NestingTooDeep { index } => {
+                write!(
+                    f,
+                    "format description nested too deeply at byte index {index}"
+                )
+            }
         }
     }
 }
@@ -25,7 +25,7 @@ pub use different_variant::DifferentVariant;
 #[cfg(feature = "formatting")]
 pub use format::Format;
 #[cfg(feature = "local-offset")]
-pub use indeterminate_offset::IndeterminateOffset;
+pub use indeterminate_offset::{IndeterminateOffset, IndeterminateOffsetReason};
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
 pub use invalid_variant::InvalidVariant;
@@ -5,9 +5,11 @@ mod digit_count;
 mod instant;
 mod numerical_duration;
 mod numerical_std_duration;
+mod try_numerical_duration;
 
 pub(crate) use self::digit_count::DigitCount;
 #[cfg(feature = "std")]
 pub use self::instant::InstantExt;
 pub use self::numerical_duration::NumericalDuration;
 pub use self::numerical_std_duration::NumericalStdDuration;
+pub use self::try_numerical_duration::TryNumericalDuration;
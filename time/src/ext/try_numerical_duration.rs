@@ -0,0 +1,89 @@
+use crate::{error, Duration};
+
+/// Sealed trait to prevent downstream implementations.
+mod sealed {
+    /// A trait that cannot be implemented by downstream users.
+    pub trait Sealed {}
+    impl Sealed for i64 {}
+}
+
+/// Fallibly create [`Duration`]s from numeric values.
+///
+/// This is the non-panicking counterpart to [`NumericalDuration`](super::NumericalDuration). If
+/// the resulting [`Duration`] cannot be represented, an error is returned instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # use time::{Duration, ext::TryNumericalDuration};
+/// assert_eq!(5.try_nanoseconds(), Ok(Duration::nanoseconds(5)));
+/// assert_eq!(5.try_microseconds(), Ok(Duration::microseconds(5)));
+/// assert_eq!(5.try_milliseconds(), Ok(Duration::milliseconds(5)));
+/// assert_eq!(5.try_seconds(), Ok(Duration::seconds(5)));
+/// assert_eq!(5.try_minutes(), Ok(Duration::minutes(5)));
+/// assert_eq!(5.try_hours(), Ok(Duration::hours(5)));
+/// assert_eq!(5.try_days(), Ok(Duration::days(5)));
+/// assert_eq!((-5).try_weeks(), Ok(Duration::weeks(-5)));
+/// ```
+///
+/// Values that would overflow the [`Duration`] return an error.
+///
+/// ```rust
+/// # use time::ext::TryNumericalDuration;
+/// assert!(i64::MAX.try_minutes().is_err());
+/// assert!(i64::MAX.try_hours().is_err());
+/// assert!(i64::MAX.try_days().is_err());
+/// assert!(i64::MIN.try_weeks().is_err());
+/// ```
+pub trait TryNumericalDuration: sealed::Sealed {
+    /// Create a [`Duration`] from the number of nanoseconds.
+    fn try_nanoseconds(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of microseconds.
+    fn try_microseconds(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of milliseconds.
+    fn try_milliseconds(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of seconds.
+    fn try_seconds(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of minutes.
+    fn try_minutes(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of hours.
+    fn try_hours(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of days.
+    fn try_days(self) -> Result<Duration, error::ConversionRange>;
+    /// Create a [`Duration`] from the number of weeks.
+    fn try_weeks(self) -> Result<Duration, error::ConversionRange>;
+}
+
+impl TryNumericalDuration for i64 {
+    fn try_nanoseconds(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_nanoseconds(self)
+    }
+
+    fn try_microseconds(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_microseconds(self)
+    }
+
+    fn try_milliseconds(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_milliseconds(self)
+    }
+
+    fn try_seconds(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_seconds(self)
+    }
+
+    fn try_minutes(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_minutes(self)
+    }
+
+    fn try_hours(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_hours(self)
+    }
+
+    fn try_days(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_days(self)
+    }
+
+    fn try_weeks(self) -> Result<Duration, error::ConversionRange> {
+        Duration::try_weeks(self)
+    }
+}
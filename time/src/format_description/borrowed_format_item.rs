@@ -19,7 +19,7 @@ pub type FormatItem<'a> = BorrowedFormatItem<'a>;
 #[cfg(not(doc))]
 pub use self::BorrowedFormatItem as FormatItem;
 use crate::error;
-use crate::format_description::Component;
+use crate::format_description::{modifier, Component};
 
 /// A complete description of how to format and parse a type.
 #[non_exhaustive]
@@ -45,6 +45,9 @@ pub enum BorrowedFormatItem<'a> {
     /// formatting, the first element of the slice is used.  An empty slice is a no-op when
     /// formatting or parsing.
     First(&'a [Self]),
+    /// A component whose formatted value is filled to a minimum width. When parsing, fill
+    /// characters on the padded side of the value are skipped.
+    Padded(Component, modifier::MinWidth),
 }
 
 #[cfg(feature = "alloc")]
@@ -56,6 +59,11 @@ impl fmt::Debug for BorrowedFormatItem<'_> {
             Self::Compound(compound) => compound.fmt(f),
             Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
             Self::First(items) => f.debug_tuple("First").field(items).finish(),
+            Self::Padded(component, min_width) => f
+                .debug_tuple("Padded")
+                .field(component)
+                .field(min_width)
+                .finish(),
         }
     }
 }
@@ -0,0 +1,188 @@
+//! A builder for constructing format descriptions programmatically.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::format_description::modifier::{
+    self, MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
+};
+use crate::format_description::{Component, OwnedFormatItem};
+
+/// A builder for constructing a format description without parsing a string.
+///
+/// Each method appends an item to the description. Modifiers that are not accepted by a method
+/// take their default value; use [`component`](Self::component) for full control.
+///
+/// ```rust
+/// # use time::format_description::FormatDescriptionBuilder;
+/// # use time::format_description::modifier::{MonthRepr, Padding, YearRepr};
+/// # use time_macros::date;
+/// let format = FormatDescriptionBuilder::new()
+///     .year(YearRepr::Full, Padding::Zero)
+///     .literal("-")
+///     .month(MonthRepr::Numerical, Padding::Zero)
+///     .literal("-")
+///     .day(Padding::Zero)
+///     .build();
+/// assert_eq!(date!(2020 - 01 - 02).format(&format)?, "2020-01-02");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatDescriptionBuilder {
+    /// The items that have been added to the description so far.
+    items: Vec<OwnedFormatItem>,
+}
+
+impl FormatDescriptionBuilder {
+    /// Create a new builder with no items.
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Add an arbitrary item to the description.
+    pub fn item(mut self, item: impl Into<OwnedFormatItem>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Add a literal to the description. It is formatted as-is and must be matched exactly when
+    /// parsing.
+    pub fn literal(self, literal: &str) -> Self {
+        self.item(OwnedFormatItem::Literal(literal.as_bytes().into()))
+    }
+
+    /// Add a component with any modifiers to the description.
+    pub fn component(self, component: Component) -> Self {
+        self.item(OwnedFormatItem::Component(component))
+    }
+
+    /// Add an item that is optional when parsing, containing the items added by `f`.
+    ///
+    /// ```rust
+    /// # use time::format_description::FormatDescriptionBuilder;
+    /// # use time::format_description::modifier::Padding;
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// let format = FormatDescriptionBuilder::new()
+    ///     .hour(false, Padding::Zero)
+    ///     .literal(":")
+    ///     .minute(Padding::Zero)
+    ///     .optional(|b| b.literal(":").second(Padding::Zero))
+    ///     .build();
+    /// assert_eq!(Time::parse("12:34", &format)?, time!(12:34));
+    /// assert_eq!(Time::parse("12:34:56", &format)?, time!(12:34:56));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn optional(self, f: impl FnOnce(Self) -> Self) -> Self {
+        let mut items = f(Self::new()).build();
+        let inner = if items.len() == 1 {
+            items.remove(0)
+        } else {
+            OwnedFormatItem::Compound(items.into_boxed_slice())
+        };
+        self.item(OwnedFormatItem::Optional(Box::new(inner)))
+    }
+
+    /// Add the day of the month.
+    pub fn day(self, padding: Padding) -> Self {
+        self.component(Component::Day(modifier::Day { padding }))
+    }
+
+    /// Add the month of the year.
+    pub fn month(self, repr: MonthRepr, padding: Padding) -> Self {
+        self.component(Component::Month(modifier::Month {
+            padding,
+            repr,
+            ..modifier::Month::default()
+        }))
+    }
+
+    /// Add the ordinal day of the year.
+    pub fn ordinal(self, padding: Padding) -> Self {
+        self.component(Component::Ordinal(modifier::Ordinal { padding }))
+    }
+
+    /// Add the day of the week.
+    pub fn weekday(self, repr: WeekdayRepr) -> Self {
+        self.component(Component::Weekday(modifier::Weekday {
+            repr,
+            ..modifier::Weekday::default()
+        }))
+    }
+
+    /// Add the week within the year.
+    pub fn week_number(self, repr: WeekNumberRepr, padding: Padding) -> Self {
+        self.component(Component::WeekNumber(modifier::WeekNumber {
+            padding,
+            repr,
+        }))
+    }
+
+    /// Add the year.
+    pub fn year(self, repr: YearRepr, padding: Padding) -> Self {
+        self.component(Component::Year(modifier::Year {
+            padding,
+            repr,
+            ..modifier::Year::default()
+        }))
+    }
+
+    /// Add the hour of the day, using either a 12 or 24-hour clock.
+    pub fn hour(self, is_12_hour_clock: bool, padding: Padding) -> Self {
+        self.component(Component::Hour(modifier::Hour {
+            padding,
+            is_12_hour_clock,
+            ..modifier::Hour::default()
+        }))
+    }
+
+    /// Add the minute within the hour.
+    pub fn minute(self, padding: Padding) -> Self {
+        self.component(Component::Minute(modifier::Minute {
+            padding,
+            ..modifier::Minute::default()
+        }))
+    }
+
+    /// Add the AM/PM part of the time.
+    pub fn period(self, is_uppercase: bool) -> Self {
+        self.component(Component::Period(modifier::Period {
+            is_uppercase,
+            ..modifier::Period::default()
+        }))
+    }
+
+    /// Add the second within the minute.
+    pub fn second(self, padding: Padding) -> Self {
+        self.component(Component::Second(modifier::Second { padding }))
+    }
+
+    /// Add the subsecond within the second.
+    pub fn subsecond(self, digits: SubsecondDigits) -> Self {
+        self.component(Component::Subsecond(modifier::Subsecond { digits }))
+    }
+
+    /// Add the hour of the UTC offset.
+    pub fn offset_hour(self, sign_is_mandatory: bool, padding: Padding) -> Self {
+        self.component(Component::OffsetHour(modifier::OffsetHour {
+            sign_is_mandatory,
+            padding,
+            ..modifier::OffsetHour::default()
+        }))
+    }
+
+    /// Add the minute within the hour of the UTC offset.
+    pub fn offset_minute(self, padding: Padding) -> Self {
+        self.component(Component::OffsetMinute(modifier::OffsetMinute { padding }))
+    }
+
+    /// Add the second within the minute of the UTC offset.
+    pub fn offset_second(self, padding: Padding) -> Self {
+        self.component(Component::OffsetSecond(modifier::OffsetSecond { padding }))
+    }
+
+    /// Consume the builder, returning the items that form the format description.
+    pub fn build(self) -> Vec<OwnedFormatItem> {
+        self.items
+    }
+}
@@ -18,6 +18,13 @@ pub enum Component {
     WeekNumber(modifier::WeekNumber),
     /// Year of the date.
     Year(modifier::Year),
+    /// Era of the year (BC or AD).
+    Era(modifier::Era),
+    /// Quarter of the year.
+    ///
+    /// When parsing a date without a month, the quarter may be combined with the year. The
+    /// resulting date is in the first month of the quarter, on the first day unless one is given.
+    Quarter(modifier::Quarter),
     /// Hour of the day.
     Hour(modifier::Hour),
     /// Minute within the hour.
@@ -28,6 +35,11 @@ pub enum Component {
     Second(modifier::Second),
     /// Subsecond within the second.
     Subsecond(modifier::Subsecond),
+    /// Number of seconds since midnight, from 0 to 86,399.
+    ///
+    /// When parsing, this sets the hour, minute, and second. A value of 86,400 is only accepted
+    /// when leap seconds are allowed, in which case it represents 23:59:60.
+    SecondsOfDay(modifier::SecondsOfDay),
     /// Hour of the UTC offset.
     OffsetHour(modifier::OffsetHour),
     /// Minute within the hour of the UTC offset.
@@ -8,31 +8,41 @@
 //! e.g. [`well_known::Rfc3339`].
 
 mod borrowed_format_item;
+#[cfg(feature = "alloc")]
+mod builder;
 mod component;
 pub mod modifier;
 #[cfg(feature = "alloc")]
 mod owned_format_item;
 #[cfg(feature = "alloc")]
 mod parse;
+mod requirements;
 
 pub use borrowed_format_item::BorrowedFormatItem;
 #[allow(deprecated)]
 pub use borrowed_format_item::FormatItem;
 #[cfg(feature = "alloc")]
+pub use builder::FormatDescriptionBuilder;
+#[cfg(feature = "alloc")]
 pub use owned_format_item::OwnedFormatItem;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::{parse, parse_borrowed, parse_owned};
+pub use self::parse::{
+    parse, parse_borrowed, parse_borrowed_with_options, parse_owned, parse_owned_with_options,
+    Brackets, ParseOptions,
+};
 
 /// Well-known formats, typically standards.
 pub mod well_known {
+    mod http_date;
     pub mod iso8601;
     mod rfc2822;
     mod rfc3339;
 
+    pub use http_date::HttpDate;
     #[doc(inline)]
     pub use iso8601::Iso8601;
     pub use rfc2822::Rfc2822;
-    pub use rfc3339::Rfc3339;
+    pub use rfc3339::{Rfc3339, Rfc3339Bounded, Rfc3339Fixed};
 }
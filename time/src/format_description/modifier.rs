@@ -23,6 +23,18 @@ pub enum MonthRepr {
     Short,
 }
 
+/// The casing used when formatting a month or weekday name.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameCase {
+    /// Only the first letter is uppercase (e.g. "Mon").
+    Title,
+    /// All letters are uppercase (e.g. "MON").
+    Upper,
+    /// All letters are lowercase (e.g. "mon").
+    Lower,
+}
+
 /// Month of the year.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -31,7 +43,11 @@ pub struct Month {
     pub padding: Padding,
     /// What form of representation should be used?
     pub repr: MonthRepr,
+    /// The casing of the month name. This has no effect on numerical representations.
+    pub case: NameCase,
     /// Is the value case sensitive when parsing?
+    ///
+    /// When `true`, the month name must use the casing indicated by the `case` field.
     pub case_sensitive: bool,
 }
 
@@ -69,7 +85,11 @@ pub struct Weekday {
     pub repr: WeekdayRepr,
     /// When using a numerical representation, should it be zero or one-indexed?
     pub one_indexed: bool,
+    /// The casing of the weekday name. This has no effect on numerical representations.
+    pub case: NameCase,
     /// Is the value case sensitive when parsing?
+    ///
+    /// When `true`, the weekday name must use the casing indicated by the `case` field.
     pub case_sensitive: bool,
 }
 
@@ -117,6 +137,43 @@ pub struct Year {
     pub iso_week_based: bool,
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
+    /// Whether the year is numbered relative to its era, such that 1 BC immediately precedes
+    /// AD 1. When `false`, astronomical year numbering is used, where year 0 is 1 BC.
+    ///
+    /// An era-based year is always positive, so it never has a sign. It is typically combined
+    /// with the [`Era`] component.
+    pub era_based: bool,
+    /// The first year of the 100-year range that the last two digits of a year are resolved into
+    /// when parsing. For example, with a value of 1950, `68` is parsed as 1968 and `49` as 2049.
+    ///
+    /// This only has an effect when parsing the [`LastTwo`](YearRepr::LastTwo) representation.
+    pub range_start: i16,
+}
+
+/// The era of the year (BC or AD).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era {
+    /// Is the era uppercase or lowercase?
+    ///
+    /// This has no effect on parsing, where the era is always case-insensitive.
+    pub is_uppercase: bool,
+}
+
+/// The representation used for the quarter of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuarterRepr {
+    /// The number of the quarter (1-4).
+    Numerical,
+}
+
+/// Quarter of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {
+    /// What form of representation should be used?
+    pub repr: QuarterRepr,
 }
 // endregion date modifiers
 
@@ -129,6 +186,11 @@ pub struct Hour {
     pub padding: Padding,
     /// Is the hour displayed using a 12 or 24-hour clock?
     pub is_12_hour_clock: bool,
+    /// The number of digits in the fractional part of the hour, if any. The fractional part is
+    /// preceded by a `.` and represents the minutes, seconds, and subseconds of the time.
+    ///
+    /// When parsing, the minute, second, and subsecond may not also be present.
+    pub fraction: Option<SubsecondDigits>,
 }
 
 /// Minute within the hour.
@@ -137,6 +199,11 @@ pub struct Hour {
 pub struct Minute {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The number of digits in the fractional part of the minute, if any. The fractional part is
+    /// preceded by a `.` and represents the seconds and subseconds of the time.
+    ///
+    /// When parsing, the second and subsecond may not also be present.
+    pub fraction: Option<SubsecondDigits>,
 }
 
 /// AM/PM part of the time.
@@ -149,6 +216,10 @@ pub struct Period {
     ///
     /// Note that when `false`, the `is_uppercase` field has no effect on parsing behavior.
     pub case_sensitive: bool,
+    /// May the period be omitted when parsing? If it is absent, AM is assumed.
+    ///
+    /// This has no effect on formatting, where the period is always present.
+    pub is_optional: bool,
 }
 
 /// Second within the minute.
@@ -193,6 +264,14 @@ pub struct Subsecond {
     /// How many digits are present in the component?
     pub digits: SubsecondDigits,
 }
+
+/// Number of seconds since midnight.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecondsOfDay {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+}
 // endregion time modifiers
 
 // region: offset modifiers
@@ -204,6 +283,9 @@ pub struct OffsetHour {
     pub sign_is_mandatory: bool,
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Whether `UTC` and `GMT` are accepted as a zero offset when parsing, ignoring case. This has
+    /// no effect when formatting.
+    pub utc_alias_allowed: bool,
 }
 
 /// Minute within the hour of the UTC offset.
@@ -286,6 +368,22 @@ pub struct UnixTimestamp {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct End;
 
+/// The minimum width of a component's formatted value.
+///
+/// Unlike [`Padding`], this applies to the rendered text of any component, including textual ones
+/// such as the name of a month.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinWidth {
+    /// The minimum number of characters in the formatted value. Values that are already at least
+    /// this wide are left unchanged.
+    pub width: u8,
+    /// The character used to fill the remaining width. This should be an ASCII character.
+    pub fill: u8,
+    /// Whether the value is placed at the start of the field, with the fill following it.
+    pub is_left_aligned: bool,
+}
+
 /// Generate the provided code if and only if `pub` is present.
 macro_rules! if_pub {
     (pub $(#[$attr:meta])*; $($x:tt)*) => {
@@ -331,12 +429,15 @@ impl_const_default! {
     /// Creates a modifier that indicates the value uses the
     /// [`Numerical`](Self::Numerical) representation.
     MonthRepr => Self::Numerical;
+    /// Creates a modifier that indicates the value uses the [`Title`](Self::Title) casing.
+    NameCase => Self::Title;
     /// Creates an instance of this type that indicates the value uses the
     /// [`Numerical`](MonthRepr::Numerical) representation, is [padded with zeroes](Padding::Zero),
-    /// and is case-sensitive when parsing.
+    /// uses [`Title`](NameCase::Title) casing, and is case-sensitive when parsing.
     @pub Month => Self {
         padding: Padding::Zero,
         repr: MonthRepr::Numerical,
+        case: NameCase::Title,
         case_sensitive: true,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
@@ -344,11 +445,13 @@ impl_const_default! {
     /// Creates a modifier that indicates the value uses the [`Long`](Self::Long) representation.
     WeekdayRepr => Self::Long;
     /// Creates a modifier that indicates the value uses the [`Long`](WeekdayRepr::Long)
-    /// representation and is case-sensitive when parsing. If the representation is changed to a
-    /// numerical one, the instance defaults to one-based indexing.
+    /// representation with [`Title`](NameCase::Title) casing and is case-sensitive when parsing.
+    /// If the representation is changed to a numerical one, the instance defaults to one-based
+    /// indexing.
     @pub Weekday => Self {
         repr: WeekdayRepr::Long,
         one_indexed: true,
+        case: NameCase::Title,
         case_sensitive: true,
     };
     /// Creates a modifier that indicates that the value uses the [`Iso`](Self::Iso) representation.
@@ -363,26 +466,45 @@ impl_const_default! {
     YearRepr => Self::Full;
     /// Creates a modifier that indicates the value uses the [`Full`](YearRepr::Full)
     /// representation, is [padded with zeroes](Padding::Zero), uses the Gregorian calendar as its
-    /// base, and only includes the year's sign if necessary.
+    /// base, only includes the year's sign if necessary, and uses astronomical year numbering.
+    /// When parsing the last two digits of a year, they are resolved into the range 1950–2049.
     @pub Year => Self {
         padding: Padding::Zero,
         repr: YearRepr::Full,
         iso_week_based: false,
         sign_is_mandatory: false,
+        era_based: false,
+        range_start: 1950,
+    };
+    /// Creates a modifier that indicates the value uses the upper-case representation.
+    @pub Era => Self { is_uppercase: true };
+    /// Creates a modifier that indicates the value uses the [`Numerical`](Self::Numerical)
+    /// representation.
+    QuarterRepr => Self::Numerical;
+    /// Creates a modifier that indicates the value uses the [`Numerical`](QuarterRepr::Numerical)
+    /// representation.
+    @pub Quarter => Self {
+        repr: QuarterRepr::Numerical,
     };
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and
-    /// has the 24-hour representation.
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero), has
+    /// the 24-hour representation, and has no fractional part.
     @pub Hour => Self {
         padding: Padding::Zero,
         is_12_hour_clock: false,
+        fraction: None,
+    };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and has
+    /// no fractional part.
+    @pub Minute => Self {
+        padding: Padding::Zero,
+        fraction: None,
     };
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Minute => Self { padding: Padding::Zero };
     /// Creates a modifier that indicates the value uses the upper-case representation and is
     /// case-sensitive when parsing.
     @pub Period => Self {
         is_uppercase: true,
         case_sensitive: true,
+        is_optional: false,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
     @pub Second => Self { padding: Padding::Zero };
@@ -392,11 +514,15 @@ impl_const_default! {
     /// Creates a modifier that indicates the stringified value contains [one or more
     /// digits](SubsecondDigits::OneOrMore).
     @pub Subsecond => Self { digits: SubsecondDigits::OneOrMore };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
+    @pub SecondsOfDay => Self { padding: Padding::Zero };
     /// Creates a modifier that indicates the value only uses a sign for negative values and is
-    /// [padded with zeroes](Padding::Zero).
+    /// [padded with zeroes](Padding::Zero). `UTC` and `GMT` are not accepted as a zero offset when
+    /// parsing.
     @pub OffsetHour => Self {
         sign_is_mandatory: false,
         padding: Padding::Zero,
+        utc_alias_allowed: false,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
     @pub OffsetMinute => Self { padding: Padding::Zero };
@@ -415,4 +541,11 @@ impl_const_default! {
     };
     /// Creates a modifier used to represent the end of input.
     @pub End => End;
+    /// Creates a modifier that indicates the value has no minimum width. If a width is set, the
+    /// value is right-aligned and filled with spaces.
+    @pub MinWidth => Self {
+        width: 0,
+        fill: b' ',
+        is_left_aligned: false,
+    };
 }
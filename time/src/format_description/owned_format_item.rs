@@ -6,7 +6,7 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use crate::error;
-use crate::format_description::{BorrowedFormatItem, Component};
+use crate::format_description::{modifier, BorrowedFormatItem, Component};
 
 /// A complete description of how to format and parse a type.
 #[non_exhaustive]
@@ -31,6 +31,9 @@ pub enum OwnedFormatItem {
     /// formatting, the first element of the [`Vec`] is used. An empty [`Vec`] is a no-op when
     /// formatting or parsing.
     First(Box<[Self]>),
+    /// A component whose formatted value is filled to a minimum width. When parsing, fill
+    /// characters on the padded side of the value are skipped.
+    Padded(Component, modifier::MinWidth),
 }
 
 impl fmt::Debug for OwnedFormatItem {
@@ -41,6 +44,11 @@ impl fmt::Debug for OwnedFormatItem {
             Self::Compound(compound) => compound.fmt(f),
             Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
             Self::First(items) => f.debug_tuple("First").field(items).finish(),
+            Self::Padded(component, min_width) => f
+                .debug_tuple("Padded")
+                .field(component)
+                .field(min_width)
+                .finish(),
         }
     }
 }
@@ -76,6 +84,9 @@ impl From<&BorrowedFormatItem<'_>> for OwnedFormatItem {
                     .collect::<Vec<_>>()
                     .into_boxed_slice(),
             ),
+            BorrowedFormatItem::Padded(component, min_width) => {
+                Self::Padded(*component, *min_width)
+            }
         }
     }
 }
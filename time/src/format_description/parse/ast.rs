@@ -1,5 +1,6 @@
 //! AST for parsing format descriptions.
 
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -11,9 +12,7 @@ use crate::internal_macros::bug;
 /// One part of a complete format description.
 pub(super) enum Item<'a> {
     /// A literal string, formatted and parsed as-is.
-    ///
-    /// This should never be present inside a nested format description.
-    Literal(Spanned<&'a [u8]>),
+    Literal(Spanned<Cow<'a, [u8]>>),
     /// A sequence of brackets. The first acts as the escape character.
     ///
     /// This should never be present if the lexer has `BACKSLASH_ESCAPE` set to `true`.
@@ -22,6 +21,8 @@ pub(super) enum Item<'a> {
         _first: Unused<Location>,
         /// The second bracket.
         _second: Unused<Location>,
+        /// The bracket being escaped.
+        value: &'a [u8],
     },
     /// Part of a type, along with its modifiers.
     Component {
@@ -68,6 +69,15 @@ pub(super) enum Item<'a> {
         /// Where the closing bracket was in the format string.
         closing_bracket: Location,
     },
+    /// A comment, which is discarded.
+    ///
+    /// This is never emitted by [`parse`], as comments are removed while parsing.
+    Comment {
+        /// Where the opening bracket was in the format string.
+        _opening_bracket: Unused<Location>,
+        /// Where the closing bracket was in the format string.
+        _closing_bracket: Unused<Location>,
+    },
 }
 
 /// A format description that is nested within another format description.
@@ -94,7 +104,9 @@ pub(super) struct Modifier<'a> {
     pub(super) value: Spanned<&'a [u8]>,
 }
 
-/// Parse the provided tokens into an AST.
+/// Parse the provided tokens into an AST. Nested format descriptions may be at most
+/// `max_nesting_depth` levels deep; nesting beyond this results in an error rather than unbounded
+/// recursion.
 pub(super) fn parse<
     'item: 'iter,
     'iter,
@@ -102,13 +114,15 @@ pub(super) fn parse<
     const VERSION: usize,
 >(
     tokens: &'iter mut lexer::Lexed<I>,
+    max_nesting_depth: u8,
 ) -> impl Iterator<Item = Result<Item<'item>, Error>> + 'iter {
     validate_version!(VERSION);
-    parse_inner::<_, false, VERSION>(tokens)
+    parse_inner::<_, false, VERSION>(tokens, max_nesting_depth)
 }
 
 /// Parse the provided tokens into an AST. The const generic indicates whether the resulting
-/// [`Item`] will be used directly or as part of a [`NestedFormatDescription`].
+/// [`Item`] will be used directly or as part of a [`NestedFormatDescription`]. Comments are
+/// discarded. `remaining_depth` is the number of further nested format descriptions permitted.
 fn parse_inner<
     'item,
     I: Iterator<Item = Result<lexer::Token<'item>, Error>>,
@@ -116,6 +130,7 @@ fn parse_inner<
     const VERSION: usize,
 >(
     tokens: &mut lexer::Lexed<I>,
+    remaining_depth: u8,
 ) -> impl Iterator<Item = Result<Item<'item>, Error>> + '_ {
     validate_version!(VERSION);
     iter::from_fn(move || {
@@ -129,48 +144,53 @@ fn parse_inner<
         };
 
         Some(match next {
-            lexer::Token::Literal(Spanned { value: _, span: _ }) if NESTED => {
-                bug!("literal should not be present in nested description")
-            }
             lexer::Token::Literal(value) => Ok(Item::Literal(value)),
             lexer::Token::Bracket {
                 kind: lexer::BracketKind::Opening,
                 location,
+                value,
             } => {
                 if version!(..=1) {
                     if let Some(second_location) = tokens.next_if_opening_bracket() {
                         Ok(Item::EscapedBracket {
                             _first: unused(location),
                             _second: unused(second_location),
+                            value,
                         })
                     } else {
-                        parse_component::<_, VERSION>(location, tokens)
+                        parse_component::<_, VERSION>(location, tokens, remaining_depth)
                     }
                 } else {
-                    parse_component::<_, VERSION>(location, tokens)
+                    parse_component::<_, VERSION>(location, tokens, remaining_depth)
                 }
             }
             lexer::Token::Bracket {
                 kind: lexer::BracketKind::Closing,
                 location: _,
+                value: _,
             } if NESTED => {
                 bug!("closing bracket should be caught by the `if` statement")
             }
             lexer::Token::Bracket {
                 kind: lexer::BracketKind::Closing,
                 location: _,
+                value: _,
             } => {
                 bug!("closing bracket should have been consumed by `parse_component`")
             }
             lexer::Token::ComponentPart {
                 kind: _, // whitespace is significant in nested components
                 value,
-            } if NESTED => Ok(Item::Literal(value)),
+            } if NESTED => Ok(Item::Literal(Spanned {
+                value: Cow::Borrowed(value.value),
+                span: value.span,
+            })),
             lexer::Token::ComponentPart { kind: _, value: _ } => {
                 bug!("component part should have been consumed by `parse_component`")
             }
         })
     })
+    .filter(|item| !matches!(item, Ok(Item::Comment { .. })))
 }
 
 /// Parse a component. This assumes that the opening bracket has already been consumed.
@@ -181,6 +201,7 @@ fn parse_component<
 >(
     opening_bracket: Location,
     tokens: &mut lexer::Lexed<I>,
+    remaining_depth: u8,
 ) -> Result<Item<'a>, Error> {
     validate_version!(VERSION);
     let leading_whitespace = tokens.next_if_whitespace();
@@ -198,6 +219,10 @@ fn parse_component<
         });
     };
 
+    if name.starts_with(b"#") {
+        return parse_comment(opening_bracket, tokens);
+    }
+
     if *name == b"optional" {
         let Some(whitespace) = tokens.next_if_whitespace() else {
             return Err(Error {
@@ -205,17 +230,19 @@ fn parse_component<
                 public: crate::error::InvalidFormatDescription::Expected {
                     what: "whitespace after `optional`",
                     index: name.span.end.byte as _,
+                    index_range: name.span.shrink_to_end().byte_range(),
                 },
             });
         };
 
-        let nested = parse_nested::<_, VERSION>(whitespace.span.end, tokens)?;
+        let nested = parse_nested::<_, VERSION>(whitespace.span.end, tokens, remaining_depth)?;
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
             return Err(Error {
                 _inner: unused(opening_bracket.error("unclosed bracket")),
                 public: crate::error::InvalidFormatDescription::UnclosedOpeningBracket {
                     index: opening_bracket.byte as _,
+                    index_range: opening_bracket.to(opening_bracket).byte_range(),
                 },
             });
         };
@@ -237,13 +264,18 @@ fn parse_component<
                 public: crate::error::InvalidFormatDescription::Expected {
                     what: "whitespace after `first`",
                     index: name.span.end.byte as _,
+                    index_range: name.span.shrink_to_end().byte_range(),
                 },
             });
         };
 
         let mut nested_format_descriptions = Vec::new();
-        while let Ok(description) = parse_nested::<_, VERSION>(whitespace.span.end, tokens) {
-            nested_format_descriptions.push(description);
+        while tokens.peek_opening_bracket().is_some() {
+            nested_format_descriptions.push(parse_nested::<_, VERSION>(
+                whitespace.span.end,
+                tokens,
+                remaining_depth,
+            )?);
         }
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
@@ -251,6 +283,7 @@ fn parse_component<
                 _inner: unused(opening_bracket.error("unclosed bracket")),
                 public: crate::error::InvalidFormatDescription::UnclosedOpeningBracket {
                     index: opening_bracket.byte as _,
+                    index_range: opening_bracket.to(opening_bracket).byte_range(),
                 },
             });
         };
@@ -283,6 +316,7 @@ fn parse_component<
                 public: crate::error::InvalidFormatDescription::InvalidModifier {
                     value: String::from("["),
                     index: location.byte as _,
+                    index_range: location.to(location).byte_range(),
                 },
             });
         }
@@ -297,6 +331,7 @@ fn parse_component<
                 public: crate::error::InvalidFormatDescription::InvalidModifier {
                     value: String::from_utf8_lossy(value).into_owned(),
                     index: span.start.byte as _,
+                    index_range: span.byte_range(),
                 },
             });
         };
@@ -309,6 +344,7 @@ fn parse_component<
                 public: crate::error::InvalidFormatDescription::InvalidModifier {
                     value: String::new(),
                     index: span.start.byte as _,
+                    index_range: span.shrink_to_before(colon_index as _).byte_range(),
                 },
             });
         }
@@ -318,6 +354,7 @@ fn parse_component<
                 public: crate::error::InvalidFormatDescription::InvalidModifier {
                     value: String::new(),
                     index: span.shrink_to_end().start.byte as _,
+                    index_range: span.shrink_to_after(colon_index as _).byte_range(),
                 },
             });
         }
@@ -335,6 +372,7 @@ fn parse_component<
             _inner: unused(opening_bracket.error("unclosed bracket")),
             public: crate::error::InvalidFormatDescription::UnclosedOpeningBracket {
                 index: opening_bracket.byte as _,
+                index_range: opening_bracket.to(opening_bracket).byte_range(),
             },
         });
     };
@@ -349,10 +387,55 @@ fn parse_component<
     })
 }
 
+/// Parse the remainder of a comment. This assumes that the opening bracket and the `#` that begins
+/// the comment have already been consumed. Any brackets within the comment must be balanced.
+fn parse_comment<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>>(
+    opening_bracket: Location,
+    tokens: &mut lexer::Lexed<I>,
+) -> Result<Item<'a>, Error> {
+    let mut depth: usize = 0;
+    let closing_bracket = loop {
+        match tokens.next() {
+            Some(Ok(lexer::Token::Bracket {
+                kind: lexer::BracketKind::Opening,
+                location: _,
+                value: _,
+            })) => depth += 1,
+            Some(Ok(lexer::Token::Bracket {
+                kind: lexer::BracketKind::Closing,
+                location,
+                value: _,
+            })) => match depth.checked_sub(1) {
+                Some(new_depth) => depth = new_depth,
+                None => break location,
+            },
+            Some(Ok(_)) => {}
+            Some(Err(err)) => return Err(err),
+            None => {
+                return Err(Error {
+                    _inner: unused(opening_bracket.error("unclosed bracket")),
+                    public: crate::error::InvalidFormatDescription::UnclosedOpeningBracket {
+                        index: opening_bracket.byte as _,
+                        index_range: opening_bracket.to(opening_bracket).byte_range(),
+                    },
+                });
+            }
+        }
+    };
+
+    Ok(Item::Comment {
+        _opening_bracket: unused(opening_bracket),
+        _closing_bracket: unused(closing_bracket),
+    })
+}
+
 /// Parse a nested format description. The location provided is the the most recent one consumed.
+/// `remaining_depth` is the number of further nested format descriptions permitted, including this
+/// one.
 fn parse_nested<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, const VERSION: usize>(
     last_location: Location,
     tokens: &mut lexer::Lexed<I>,
+    remaining_depth: u8,
 ) -> Result<NestedFormatDescription<'a>, Error> {
     validate_version!(VERSION);
     let Some(opening_bracket) = tokens.next_if_opening_bracket() else {
@@ -361,15 +444,26 @@ fn parse_nested<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, const V
             public: crate::error::InvalidFormatDescription::Expected {
                 what: "opening bracket",
                 index: last_location.byte as _,
+                index_range: last_location.to(last_location).byte_range(),
             },
         });
     };
-    let items = parse_inner::<_, true, VERSION>(tokens).collect::<Result<_, _>>()?;
+    if remaining_depth == 0 {
+        return Err(Error {
+            _inner: unused(opening_bracket.error("format description is nested too deeply")),
+            public: crate::error::InvalidFormatDescription::NestingTooDeep {
+                index: opening_bracket.byte as _,
+            },
+        });
+    }
+    let items =
+        parse_inner::<_, true, VERSION>(tokens, remaining_depth - 1).collect::<Result<_, _>>()?;
     let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
         return Err(Error {
             _inner: unused(opening_bracket.error("unclosed bracket")),
             public: crate::error::InvalidFormatDescription::UnclosedOpeningBracket {
                 index: opening_bracket.byte as _,
+                index_range: opening_bracket.to(opening_bracket).byte_range(),
             },
         });
     };
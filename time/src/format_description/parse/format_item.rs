@@ -1,5 +1,6 @@
 //! Typed, validated representation of a parsed format description.
 
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::String;
 use core::num::NonZeroU16;
@@ -19,8 +20,17 @@ pub(super) fn parse<'a>(
 pub(super) enum Item<'a> {
     /// A literal string.
     Literal(&'a [u8]),
+    /// A literal string decoded from an escape sequence that cannot be borrowed.
+    DecodedLiteral {
+        /// The decoded bytes.
+        value: Box<[u8]>,
+        /// The span of the escape sequence.
+        span: Span,
+    },
     /// Part of a type, along with its modifiers.
     Component(Component),
+    /// Part of a type, along with its modifiers, filled to a minimum width.
+    Padded(Component, MinWidth),
     /// A sequence of optional items.
     Optional {
         /// The items themselves.
@@ -48,12 +58,29 @@ impl Item<'_> {
                 modifiers,
                 _trailing_whitespace: _,
                 _closing_bracket: _,
-            } => Item::Component(component_from_ast(&name, &modifiers)?),
-            ast::Item::Literal(Spanned { value, span: _ }) => Item::Literal(value),
+            } => {
+                let component = component_from_ast(&name, &modifiers)?;
+                match MinWidth::with_modifiers(&modifiers)? {
+                    Some(min_width) => Item::Padded(component, min_width),
+                    None => Item::Component(component),
+                }
+            }
+            ast::Item::Literal(Spanned {
+                value: Cow::Borrowed(value),
+                span: _,
+            }) => Item::Literal(value),
+            ast::Item::Literal(Spanned {
+                value: Cow::Owned(value),
+                span,
+            }) => Item::DecodedLiteral {
+                value: value.into_boxed_slice(),
+                span,
+            },
             ast::Item::EscapedBracket {
                 _first: _,
                 _second: _,
-            } => Item::Literal(b"["),
+                value,
+            } => Item::Literal(value),
             ast::Item::Optional {
                 opening_bracket,
                 _leading_whitespace: _,
@@ -98,6 +125,10 @@ impl Item<'_> {
                     span: opening_bracket.to(closing_bracket),
                 }
             }
+            ast::Item::Comment {
+                _opening_bracket: _,
+                _closing_bracket: _,
+            } => bug!("comment should have been discarded by the AST parser"),
         })
     }
 }
@@ -108,7 +139,20 @@ impl<'a> TryFrom<Item<'a>> for crate::format_description::BorrowedFormatItem<'a>
     fn try_from(item: Item<'a>) -> Result<Self, Self::Error> {
         match item {
             Item::Literal(literal) => Ok(Self::Literal(literal)),
+            Item::DecodedLiteral { value: _, span } => Err(Error {
+                _inner: unused(span.error(
+                    "non-ASCII escape sequences are not supported in borrowed format descriptions",
+                )),
+                public: crate::error::InvalidFormatDescription::NotSupported {
+                    what: "non-ASCII escape sequence",
+                    context: "borrowed format descriptions",
+                    index: span.start.byte as _,
+                },
+            }),
             Item::Component(component) => Ok(Self::Component(component.into())),
+            Item::Padded(component, min_width) => {
+                Ok(Self::Padded(component.into(), min_width.into()))
+            }
             Item::Optional { value: _, span } => Err(Error {
                 _inner: unused(span.error(
                     "optional items are not supported in runtime-parsed format descriptions",
@@ -137,7 +181,9 @@ impl From<Item<'_>> for crate::format_description::OwnedFormatItem {
     fn from(item: Item<'_>) -> Self {
         match item {
             Item::Literal(literal) => Self::Literal(literal.to_vec().into_boxed_slice()),
+            Item::DecodedLiteral { value, span: _ } => Self::Literal(value),
             Item::Component(component) => Self::Component(component.into()),
+            Item::Padded(component, min_width) => Self::Padded(component.into(), min_width.into()),
             Item::Optional { value, span: _ } => Self::Optional(Box::new(value.into())),
             Item::First { value, span: _ } => {
                 Self::First(value.into_vec().into_iter().map(Into::into).collect())
@@ -193,6 +239,10 @@ macro_rules! component_definition {
                 };
 
                 for modifier in modifiers {
+                    // Modifiers that apply to all components are handled separately.
+                    if MinWidth::is_modifier_key(&modifier.key) {
+                        continue;
+                    }
                     $(#[allow(clippy::string_lit_as_bytes)]
                     if modifier.key.eq_ignore_ascii_case($parse_field.as_bytes()) {
                         this.$field = component_definition!(@if_from_str $($from_str)?
@@ -208,6 +258,7 @@ macro_rules! component_definition {
                         public: crate::error::InvalidFormatDescription::InvalidModifier {
                             value: String::from_utf8_lossy(*modifier.key).into_owned(),
                             index: modifier.key.span.start.byte as _,
+                            index_range: modifier.key.span.byte_range(),
                         }
                     });
                 }
@@ -279,9 +330,13 @@ component_definition! {
             padding = "padding": Option<Padding> => padding,
         },
         End = "end" {},
+        Era = "era" {
+            case = "case": Option<EraCase> => is_uppercase,
+        },
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
             base = "repr": Option<HourBase> => is_12_hour_clock,
+            fraction = "fraction": Option<Fraction> => fraction,
         },
         Ignore = "ignore" {
             #[required]
@@ -289,15 +344,18 @@ component_definition! {
         },
         Minute = "minute" {
             padding = "padding": Option<Padding> => padding,
+            fraction = "fraction": Option<Fraction> => fraction,
         },
         Month = "month" {
             padding = "padding": Option<Padding> => padding,
             repr = "repr": Option<MonthRepr> => repr,
+            case = "case": Option<NameCase> => case,
             case_sensitive = "case_sensitive": Option<MonthCaseSensitive> => case_sensitive,
         },
         OffsetHour = "offset_hour" {
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
             padding = "padding": Option<Padding> => padding,
+            utc_alias = "utc_alias": Option<OffsetHourUtcAlias> => utc_alias_allowed,
         },
         OffsetMinute = "offset_minute" {
             padding = "padding": Option<Padding> => padding,
@@ -311,10 +369,17 @@ component_definition! {
         Period = "period" {
             case = "case": Option<PeriodCase> => is_uppercase,
             case_sensitive = "case_sensitive": Option<PeriodCaseSensitive> => case_sensitive,
+            optional = "optional": Option<PeriodOptional> => is_optional,
+        },
+        Quarter = "quarter" {
+            repr = "repr": Option<QuarterRepr> => repr,
         },
         Second = "second" {
             padding = "padding": Option<Padding> => padding,
         },
+        SecondsOfDay = "seconds_of_day" {
+            padding = "padding": Option<Padding> => padding,
+        },
         Subsecond = "subsecond" {
             digits = "digits": Option<SubsecondDigits> => digits,
         },
@@ -325,6 +390,7 @@ component_definition! {
         Weekday = "weekday" {
             repr = "repr": Option<WeekdayRepr> => repr,
             one_indexed = "one_indexed": Option<WeekdayOneIndexed> => one_indexed,
+            case = "case": Option<NameCase> => case,
             case_sensitive = "case_sensitive": Option<WeekdayCaseSensitive> => case_sensitive,
         },
         WeekNumber = "week_number" {
@@ -336,6 +402,8 @@ component_definition! {
             repr = "repr": Option<YearRepr> => repr,
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            era = "era": Option<YearEra> => era_based,
+            range_start = "range_start": Option<#[from_str] YearRangeStart> => range_start,
         },
     }
 }
@@ -402,6 +470,7 @@ macro_rules! modifier {
                     public: crate::error::InvalidFormatDescription::InvalidModifier {
                         value: String::from_utf8_lossy(value).into_owned(),
                         index: value.span.start.byte as _,
+                        index_range: value.span.byte_range(),
                     },
                 })
             }
@@ -419,6 +488,33 @@ macro_rules! modifier {
 
 // Keep in alphabetical order.
 modifier! {
+    enum Align(bool) {
+        Left(true) = b"left",
+        #[default]
+        Right(false) = b"right",
+    }
+
+    enum EraCase(bool) {
+        Lower(false) = b"lower",
+        #[default]
+        Upper(true) = b"upper",
+    }
+
+    enum Fraction(Option<crate::format_description::modifier::SubsecondDigits>) {
+        #[default]
+        None(None) = b"none",
+        One(Some(SubsecondDigits::One.into())) = b"1",
+        Two(Some(SubsecondDigits::Two.into())) = b"2",
+        Three(Some(SubsecondDigits::Three.into())) = b"3",
+        Four(Some(SubsecondDigits::Four.into())) = b"4",
+        Five(Some(SubsecondDigits::Five.into())) = b"5",
+        Six(Some(SubsecondDigits::Six.into())) = b"6",
+        Seven(Some(SubsecondDigits::Seven.into())) = b"7",
+        Eight(Some(SubsecondDigits::Eight.into())) = b"8",
+        Nine(Some(SubsecondDigits::Nine.into())) = b"9",
+        OneOrMore(Some(SubsecondDigits::OneOrMore.into())) = b"1+",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
@@ -438,6 +534,19 @@ modifier! {
         Short = b"short",
     }
 
+    enum NameCase {
+        #[default]
+        Title = b"title",
+        Upper = b"upper",
+        Lower = b"lower",
+    }
+
+    enum OffsetHourUtcAlias(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum Padding {
         Space = b"space",
         #[default]
@@ -457,6 +566,17 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum PeriodOptional(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum QuarterRepr {
+        #[default]
+        Numerical = b"numerical",
+    }
+
     enum SignBehavior(bool) {
         #[default]
         Automatic(false) = b"automatic",
@@ -518,6 +638,12 @@ modifier! {
         IsoWeek(true) = b"iso_week",
     }
 
+    enum YearEra(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum YearRepr {
         #[default]
         Full = b"full",
@@ -525,6 +651,113 @@ modifier! {
     }
 }
 
+/// The first year of the range that the last two digits of a year are resolved into when parsing.
+struct YearRangeStart(i16);
+
+impl Default for YearRangeStart {
+    fn default() -> Self {
+        Self(1950)
+    }
+}
+
+impl FromStr for YearRangeStart {
+    type Err = <i16 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self)
+    }
+}
+
+impl From<YearRangeStart> for i16 {
+    fn from(range_start: YearRangeStart) -> Self {
+        range_start.0
+    }
+}
+
+/// The character used to fill a component to its minimum width. Only a single ASCII character is
+/// permitted.
+struct Fill(u8);
+
+impl Default for Fill {
+    fn default() -> Self {
+        Self(b' ')
+    }
+}
+
+impl FromStr for Fill {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A string containing a single byte is necessarily ASCII.
+        match s.as_bytes() {
+            &[fill] => Ok(Self(fill)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Fill> for u8 {
+    fn from(fill: Fill) -> Self {
+        fill.0
+    }
+}
+
+/// The modifiers controlling the minimum width of a component. These are accepted on every
+/// component.
+pub(super) struct MinWidth {
+    /// The minimum width of the formatted value.
+    width: Option<u8>,
+    /// The character used to fill the remaining width.
+    fill: Option<Fill>,
+    /// Which side of the field the value is placed on.
+    align: Option<Align>,
+}
+
+impl MinWidth {
+    /// Whether the modifier key is one that controls the minimum width.
+    fn is_modifier_key(key: &[u8]) -> bool {
+        [b"min_width".as_slice(), b"fill", b"align"]
+            .iter()
+            .any(|known| key.eq_ignore_ascii_case(known))
+    }
+
+    /// Parse the minimum width from the modifiers of a component. If none of the relevant
+    /// modifiers are present, `None` is returned.
+    fn with_modifiers(modifiers: &[ast::Modifier<'_>]) -> Result<Option<Self>, Error> {
+        let mut this = Self {
+            width: None,
+            fill: None,
+            align: None,
+        };
+        let mut is_present = false;
+
+        for modifier in modifiers {
+            if modifier.key.eq_ignore_ascii_case(b"min_width") {
+                this.width = parse_from_modifier_value::<u8>(&modifier.value)?;
+            } else if modifier.key.eq_ignore_ascii_case(b"fill") {
+                this.fill = parse_from_modifier_value::<Fill>(&modifier.value)?;
+            } else if modifier.key.eq_ignore_ascii_case(b"align") {
+                this.align = Align::from_modifier_value(&modifier.value)?;
+            } else {
+                continue;
+            }
+            is_present = true;
+        }
+
+        Ok(is_present.then_some(this))
+    }
+}
+
+impl From<MinWidth> for crate::format_description::modifier::MinWidth {
+    fn from(min_width: MinWidth) -> Self {
+        Self {
+            width: min_width.width.unwrap_or_default(),
+            fill: min_width.fill.unwrap_or_default().into(),
+            is_left_aligned: min_width.align.unwrap_or_default().into(),
+        }
+    }
+}
+
 /// Parse a modifier value using `FromStr`. Requires the modifier value to be valid UTF-8.
 fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Option<T>, Error> {
     str::from_utf8(value)
@@ -536,6 +769,7 @@ fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Optio
             public: crate::error::InvalidFormatDescription::InvalidModifier {
                 value: String::from_utf8_lossy(value).into_owned(),
                 index: value.span.start.byte as _,
+                index_range: value.span.byte_range(),
             },
         })
 }
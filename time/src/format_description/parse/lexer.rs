@@ -1,8 +1,22 @@
 //! Lexer for parsing format descriptions.
 
+use alloc::borrow::{Cow, ToOwned};
 use core::iter;
 
-use super::{unused, Error, Location, Spanned, SpannedValue};
+use super::{unused, Brackets, Error, Location, ParseOptions, Spanned, SpannedValue};
+use crate::internal_macros::bug;
+
+/// Every ASCII character, in order. Decoded escape sequences that are ASCII borrow from this table
+/// rather than allocating.
+static ASCII: [u8; 128] = {
+    let mut table = [0; 128];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = i as u8;
+        i += 1;
+    }
+    table
+};
 
 /// An iterator over the lexed tokens.
 pub(super) struct Lexed<I: Iterator> {
@@ -57,6 +71,7 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
         if let Some(&Ok(Token::Bracket {
             kind: BracketKind::Opening,
             location,
+            value: _,
         })) = self.peek()
         {
             self.next(); // consume
@@ -66,11 +81,26 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
         }
     }
 
+    /// Peek at the next token if it is an opening bracket.
+    pub(super) fn peek_opening_bracket(&'iter mut self) -> Option<&'iter Location> {
+        if let Some(Ok(Token::Bracket {
+            kind: BracketKind::Opening,
+            location,
+            value: _,
+        })) = self.peek()
+        {
+            Some(location)
+        } else {
+            None
+        }
+    }
+
     /// Peek at the next token if it is a closing bracket.
     pub(super) fn peek_closing_bracket(&'iter mut self) -> Option<&'iter Location> {
         if let Some(Ok(Token::Bracket {
             kind: BracketKind::Closing,
             location,
+            value: _,
         })) = self.peek()
         {
             Some(location)
@@ -84,6 +114,7 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
         if let Some(&Ok(Token::Bracket {
             kind: BracketKind::Closing,
             location,
+            value: _,
         })) = self.peek()
         {
             self.next(); // consume
@@ -97,13 +128,17 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
 /// A token emitted by the lexer. There is no semantic meaning at this stage.
 pub(super) enum Token<'a> {
     /// A literal string, formatted and parsed as-is.
-    Literal(Spanned<&'a [u8]>),
+    ///
+    /// This is only owned when it is a decoded escape sequence for a non-ASCII character.
+    Literal(Spanned<Cow<'a, [u8]>>),
     /// An opening or closing bracket. May or may not be the start or end of a component.
     Bracket {
         /// Whether the bracket is opening or closing.
         kind: BracketKind,
         /// Where the bracket was in the format string.
         location: Location,
+        /// The bracket itself.
+        value: &'a [u8],
     },
     /// One part of a component. This could be its name, a modifier, or whitespace.
     ComponentPart {
@@ -116,9 +151,9 @@ pub(super) enum Token<'a> {
 
 /// What type of bracket is present.
 pub(super) enum BracketKind {
-    /// An opening bracket: `[`
+    /// An opening bracket, `[` by default.
     Opening,
-    /// A closing bracket: `]`
+    /// A closing bracket, `]` by default.
     Closing,
 }
 
@@ -149,40 +184,62 @@ fn attach_location<'item>(
 /// must be 1 or 2.
 ///
 /// - When `VERSION` is 1, `[[` is the only escape sequence, resulting in a literal `[`.
-/// - When `VERSION` is 2, all escape sequences begin with `\`. The only characters that may
-///   currently follow are `\`, `[`, and `]`, all of which result in the literal character. All
-///   other characters result in a lex error.
+/// - When `VERSION` is 2, all escape sequences begin with `\`. `\`, `[`, and `]` may follow,
+///   resulting in the literal character. `n`, `r`, `t`, and `u{...}` may also follow, resulting in
+///   a literal with the decoded character. All other characters result in a lex error. If the `raw`
+///   option is set, a backslash is treated as any other character.
+///
+/// The `brackets` option determines the characters used in place of `[` and `]`.
 pub(super) fn lex<const VERSION: usize>(
     mut input: &[u8],
+    options: ParseOptions,
 ) -> Lexed<impl Iterator<Item = Result<Token<'_>, Error>>> {
     validate_version!(VERSION);
 
+    let ParseOptions {
+        brackets,
+        raw,
+        max_nesting_depth: _,
+    } = options;
+    let Brackets { opening, closing } = brackets;
     let mut depth: u8 = 0;
     let mut iter = attach_location(input.iter()).peekable();
-    let mut second_bracket_location = None;
+    let mut second_bracket = None;
 
     let iter = iter::from_fn(move || {
         // The flag is only set when version is zero.
         if version!(..=1) {
             // There is a flag set to emit the second half of an escaped bracket pair.
-            if let Some(location) = second_bracket_location.take() {
+            if let Some((location, value)) = second_bracket.take() {
                 return Some(Ok(Token::Bracket {
                     kind: BracketKind::Opening,
                     location,
+                    value,
                 }));
             }
         }
 
         Some(Ok(match iter.next()? {
             // possible escape sequence
-            (b'\\', backslash_loc) if version!(2..) => {
+            (b'\\', backslash_loc) if version!(2..) && !raw => {
+                let invalid_escape = |loc: Location| Error {
+                    _inner: unused(loc.error("invalid escape sequence")),
+                    public: crate::error::InvalidFormatDescription::Expected {
+                        what: "valid escape sequence",
+                        index: loc.byte as _,
+                        index_range: backslash_loc.to(loc).byte_range(),
+                    },
+                };
+
                 match iter.next() {
-                    Some((b'\\' | b'[' | b']', char_loc)) => {
+                    Some((&byte, char_loc))
+                        if byte == b'\\' || byte == opening || byte == closing =>
+                    {
                         // The escaped character is emitted as-is.
                         let char = &input[1..2];
                         input = &input[2..];
                         if depth == 0 {
-                            Token::Literal(char.spanned(backslash_loc.to(char_loc)))
+                            Token::Literal(Cow::Borrowed(char).spanned(backslash_loc.to(char_loc)))
                         } else {
                             Token::ComponentPart {
                                 kind: ComponentKind::NotWhitespace,
@@ -190,31 +247,73 @@ pub(super) fn lex<const VERSION: usize>(
                             }
                         }
                     }
-                    Some((_, loc)) => {
-                        return Some(Err(Error {
-                            _inner: unused(loc.error("invalid escape sequence")),
-                            public: crate::error::InvalidFormatDescription::Expected {
-                                what: "valid escape sequence",
-                                index: loc.byte as _,
-                            },
-                        }));
+                    Some((&byte @ (b'n' | b'r' | b't' | b'u'), char_loc)) => {
+                        let (char, end_loc, len) = match byte {
+                            b'n' => ('\n', char_loc, 2),
+                            b'r' => ('\r', char_loc, 2),
+                            b't' => ('\t', char_loc, 2),
+                            _ => {
+                                let Some((_, brace_loc)) = iter.next_if(|&(&b, _)| b == b'{')
+                                else {
+                                    return Some(Err(invalid_escape(char_loc)));
+                                };
+                                let mut code_point = 0;
+                                let mut digits = 0;
+                                let mut last_loc = brace_loc;
+                                while digits < 6 {
+                                    let Some((&digit, loc)) =
+                                        iter.next_if(|&(&b, _)| b.is_ascii_hexdigit())
+                                    else {
+                                        break;
+                                    };
+                                    let Some(digit) = (digit as char).to_digit(16) else {
+                                        bug!("byte should be an ASCII hex digit");
+                                    };
+                                    code_point = code_point * 16 + digit;
+                                    digits += 1;
+                                    last_loc = loc;
+                                }
+                                let Some((_, end_loc)) = iter.next_if(|&(&b, _)| b == b'}') else {
+                                    return Some(Err(invalid_escape(last_loc)));
+                                };
+                                let Some(char) = char::from_u32(code_point).filter(|_| digits > 0)
+                                else {
+                                    return Some(Err(invalid_escape(end_loc)));
+                                };
+                                (char, end_loc, digits + 4)
+                            }
+                        };
+                        input = &input[len..];
+
+                        // The decoded character is always a literal, even within a component. In a
+                        // nested format description, this is a literal like any other. Otherwise,
+                        // it will result in an error when parsing the component.
+                        let value = if char.is_ascii() {
+                            Cow::Borrowed(&ASCII[char as usize..][..1])
+                        } else {
+                            Cow::Owned(char.encode_utf8(&mut [0; 4]).as_bytes().to_owned())
+                        };
+                        Token::Literal(value.spanned(backslash_loc.to(end_loc)))
                     }
+                    Some((_, loc)) => return Some(Err(invalid_escape(loc))),
                     None => {
                         return Some(Err(Error {
                             _inner: unused(backslash_loc.error("unexpected end of input")),
                             public: crate::error::InvalidFormatDescription::Expected {
                                 what: "valid escape sequence",
                                 index: backslash_loc.byte as _,
+                                index_range: backslash_loc.to(backslash_loc).byte_range(),
                             },
                         }));
                     }
                 }
             }
             // potentially escaped opening bracket
-            (b'[', location) if version!(..=1) => {
-                if let Some((_, second_location)) = iter.next_if(|&(&byte, _)| byte == b'[') {
-                    // Escaped bracket. Store the location of the second so we can emit it later.
-                    second_bracket_location = Some(second_location);
+            (&byte, location) if byte == opening && version!(..=1) => {
+                let value = &input[..1];
+                if let Some((_, second_location)) = iter.next_if(|&(&byte, _)| byte == opening) {
+                    // Escaped bracket. Store the second so we can emit it later.
+                    second_bracket = Some((second_location, &input[1..2]));
                     input = &input[2..];
                 } else {
                     // opening bracket
@@ -225,26 +324,31 @@ pub(super) fn lex<const VERSION: usize>(
                 Token::Bracket {
                     kind: BracketKind::Opening,
                     location,
+                    value,
                 }
             }
             // opening bracket
-            (b'[', location) => {
+            (&byte, location) if byte == opening => {
                 depth += 1;
+                let value = &input[..1];
                 input = &input[1..];
 
                 Token::Bracket {
                     kind: BracketKind::Opening,
                     location,
+                    value,
                 }
             }
             // closing bracket
-            (b']', location) if depth > 0 => {
+            (&byte, location) if byte == closing && depth > 0 => {
                 depth -= 1;
+                let value = &input[..1];
                 input = &input[1..];
 
                 Token::Bracket {
                     kind: BracketKind::Closing,
                     location,
+                    value,
                 }
             }
             // literal
@@ -252,9 +356,9 @@ pub(super) fn lex<const VERSION: usize>(
                 let mut bytes = 1;
                 let mut end_location = start_location;
 
-                while let Some((_, location)) =
-                    iter.next_if(|&(&byte, _)| !((version!(2..) && byte == b'\\') || byte == b'['))
-                {
+                while let Some((_, location)) = iter.next_if(|&(&byte, _)| {
+                    !((version!(2..) && !raw && byte == b'\\') || byte == opening)
+                }) {
                     end_location = location;
                     bytes += 1;
                 }
@@ -262,7 +366,7 @@ pub(super) fn lex<const VERSION: usize>(
                 let value = &input[..bytes];
                 input = &input[bytes..];
 
-                Token::Literal(value.spanned(start_location.to(end_location)))
+                Token::Literal(Cow::Borrowed(value).spanned(start_location.to(end_location)))
             }
             // component part
             (byte, start_location) => {
@@ -270,8 +374,10 @@ pub(super) fn lex<const VERSION: usize>(
                 let mut end_location = start_location;
                 let is_whitespace = byte.is_ascii_whitespace();
 
-                while let Some((_, location)) = iter.next_if(|&(byte, _)| {
-                    !matches!(byte, b'\\' | b'[' | b']')
+                while let Some((_, location)) = iter.next_if(|&(&byte, _)| {
+                    byte != b'\\'
+                        && byte != opening
+                        && byte != closing
                         && is_whitespace == byte.is_ascii_whitespace()
                 }) {
                     end_location = location;
@@ -53,10 +53,54 @@ pub fn parse(
 /// description is provided as the const parameter. **It is recommended to use version 2.**
 pub fn parse_borrowed<const VERSION: usize>(
     s: &str,
+) -> Result<Vec<format_description::BorrowedFormatItem<'_>>, error::InvalidFormatDescription> {
+    parse_borrowed_with_options::<VERSION>(s, ParseOptions::default())
+}
+
+/// Parse a sequence of items from the format description, using the provided options.
+///
+/// Other than the options, this is identical to [`parse_borrowed`]. Escape sequences that decode to
+/// a non-ASCII character cannot be borrowed from the input, so they result in an error; use
+/// [`parse_owned_with_options`] for these.
+///
+/// ```rust
+/// # use time::format_description::{self, ParseOptions};
+/// # use time_macros::time;
+/// let format = format_description::parse_borrowed_with_options::<2>(
+///     "[hour]\\t[minute]",
+///     ParseOptions::default(),
+/// )?;
+/// assert_eq!(time!(12:30).format(&format)?, "12\t30");
+///
+/// let mut options = ParseOptions::default();
+/// options.raw = true;
+/// let format = format_description::parse_borrowed_with_options::<2>("[hour]\\t[minute]", options)?;
+/// assert_eq!(time!(12:30).format(&format)?, "12\\t30");
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// Escape sequences apply to the provided brackets rather than to `[` and `]`, which are treated as
+/// any other character.
+///
+/// ```rust
+/// # use time::format_description::{self, Brackets, ParseOptions};
+/// # use time_macros::date;
+/// let mut options = ParseOptions::default();
+/// options.brackets = Brackets::CURLY;
+/// let format = format_description::parse_borrowed_with_options::<2>(
+///     "{year}-{month}-{day} [\\{]",
+///     options,
+/// )?;
+/// assert_eq!(date!(2020 - 01 - 02).format(&format)?, "2020-01-02 [{]");
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn parse_borrowed_with_options<const VERSION: usize>(
+    s: &str,
+    options: ParseOptions,
 ) -> Result<Vec<format_description::BorrowedFormatItem<'_>>, error::InvalidFormatDescription> {
     validate_version!(VERSION);
-    let mut lexed = lexer::lex::<VERSION>(s.as_bytes());
-    let ast = ast::parse::<_, VERSION>(&mut lexed);
+    let mut lexed = lexer::lex::<VERSION>(s.as_bytes(), options);
+    let ast = ast::parse::<_, VERSION>(&mut lexed, options.max_nesting_depth);
     let format_items = format_item::parse(ast);
     Ok(format_items
         .map(|res| res.and_then(TryInto::try_into))
@@ -72,18 +116,154 @@ pub fn parse_borrowed<const VERSION: usize>(
 /// Unlike [`parse`], this function returns [`OwnedFormatItem`], which owns its contents. This means
 /// that there is no lifetime that needs to be handled. **It is recommended to use version 2.**
 ///
+/// Literals are copied out of the input, so the description can be stored after the source string
+/// has been dropped, such as when it is read from a configuration file.
+///
+/// ```rust
+/// # use time::format_description::{self, OwnedFormatItem};
+/// # use time_macros::date;
+/// struct Config {
+///     date_format: OwnedFormatItem,
+/// }
+///
+/// let config = {
+///     let source = String::from("[year]/[month]/[day]");
+///     Config {
+///         date_format: format_description::parse_owned::<2>(&source)?,
+///     }
+/// };
+/// assert_eq!(
+///     date!(2020 - 01 - 02).format(&config.date_format)?,
+///     "2020/01/02"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
 /// [`OwnedFormatItem`]: crate::format_description::OwnedFormatItem
 pub fn parse_owned<const VERSION: usize>(
     s: &str,
+) -> Result<format_description::OwnedFormatItem, error::InvalidFormatDescription> {
+    parse_owned_with_options::<VERSION>(s, ParseOptions::default())
+}
+
+/// Parse a sequence of items from the format description, using the provided options.
+///
+/// Other than the options, this is identical to [`parse_owned`].
+///
+/// ```rust
+/// # use time::format_description::{self, ParseOptions};
+/// # use time_macros::time;
+/// let format = format_description::parse_owned_with_options::<2>(
+///     "[hour]\\u{2236}[minute]",
+///     ParseOptions::default(),
+/// )?;
+/// assert_eq!(time!(12:30).format(&format)?, "12\u{2236}30");
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn parse_owned_with_options<const VERSION: usize>(
+    s: &str,
+    options: ParseOptions,
 ) -> Result<format_description::OwnedFormatItem, error::InvalidFormatDescription> {
     validate_version!(VERSION);
-    let mut lexed = lexer::lex::<VERSION>(s.as_bytes());
-    let ast = ast::parse::<_, VERSION>(&mut lexed);
+    let mut lexed = lexer::lex::<VERSION>(s.as_bytes(), options);
+    let ast = ast::parse::<_, VERSION>(&mut lexed, options.max_nesting_depth);
     let format_items = format_item::parse(ast);
     let items = format_items.collect::<Result<Box<_>, _>>()?;
     Ok(items.into())
 }
 
+/// The pair of characters that begin and end a component in a format description.
+///
+/// Square brackets are used by default. Other brackets are useful when embedding a format
+/// description in a system that reserves square brackets, such as a templating language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brackets {
+    /// The character that begins a component.
+    opening: u8,
+    /// The character that ends a component.
+    closing: u8,
+}
+
+impl Brackets {
+    /// Square brackets, `[` and `]`.
+    pub const SQUARE: Self = Self::new('[', ']');
+    /// Curly brackets, `{` and `}`.
+    pub const CURLY: Self = Self::new('{', '}');
+    /// Angle brackets, `<` and `>`.
+    pub const ANGLE: Self = Self::new('<', '>');
+
+    /// Use the provided characters to begin and end components.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if either character is not ASCII punctuation, if either character is a
+    /// backslash, or if the characters are the same.
+    ///
+    /// ```rust
+    /// # use time::format_description::Brackets;
+    /// assert_eq!(Brackets::new('{', '}'), Brackets::CURLY);
+    /// ```
+    pub const fn new(opening: char, closing: char) -> Self {
+        if !opening.is_ascii_punctuation() || !closing.is_ascii_punctuation() {
+            crate::expect_failed("brackets must be ASCII punctuation");
+        }
+        if opening == '\\' || closing == '\\' {
+            crate::expect_failed("brackets must not be a backslash");
+        }
+        if opening == closing {
+            crate::expect_failed("opening and closing brackets must differ");
+        }
+
+        Self {
+            opening: opening as u8,
+            closing: closing as u8,
+        }
+    }
+}
+
+impl Default for Brackets {
+    fn default() -> Self {
+        Self::SQUARE
+    }
+}
+
+/// Options that control how a format description is parsed.
+///
+/// In version 2 format descriptions, a backslash begins an escape sequence by default. The
+/// following escape sequences are recognized:
+///
+/// - `\\`, `\[`, and `\]` result in the literal character. If other [`Brackets`] are used, they are
+///   escaped instead of `[` and `]`.
+/// - `\n`, `\r`, and `\t` result in a newline, carriage return, and tab respectively.
+/// - `\u{...}` results in the Unicode character with the provided hexadecimal code point, which
+///   must have between one and six digits.
+///
+/// Version 1 format descriptions do not have backslash escapes, so `raw` has no effect on them.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The characters that begin and end a component.
+    pub brackets: Brackets,
+    /// Whether a backslash is an ordinary character rather than the start of an escape sequence.
+    /// When this is set, there is no way to include a literal bracket.
+    pub raw: bool,
+    /// The maximum number of levels that nested format descriptions, such as those in `[optional]`
+    /// and `[first]`, may be nested. Exceeding this results in
+    /// [`InvalidFormatDescription::NestingTooDeep`](error::InvalidFormatDescription::NestingTooDeep).
+    /// This is 32 by default.
+    pub max_nesting_depth: u8,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            brackets: Brackets::SQUARE,
+            raw: false,
+            max_nesting_depth: 32,
+        }
+    }
+}
+
 /// A location within a string.
 #[derive(Clone, Copy)]
 struct Location {
@@ -169,6 +349,11 @@ impl Span {
         }
     }
 
+    /// The half-open range of bytes covered by the span.
+    const fn byte_range(&self) -> (usize, usize) {
+        (self.start.byte as _, self.end.byte as usize + 1)
+    }
+
     /// Create an error with the provided message at this span.
     const fn error(self, message: &'static str) -> ErrorInner {
         ErrorInner {
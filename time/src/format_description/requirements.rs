@@ -0,0 +1,155 @@
+//! Which components a format description requires when parsing.
+
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{BorrowedFormatItem, Component};
+
+/// The components that a format description requires or permits when parsing.
+///
+/// A component is required if every successful parse must include it. A component is optional if
+/// it may be parsed, but only within an `[optional]` item or in some of the alternatives of a
+/// `[first]` item. Modifiers are not considered; only the kind of component is.
+///
+/// ```rust
+/// # use time::format_description::{self, modifier, Component};
+/// let format = format_description::parse_owned::<2>(
+///     "[year]-[month]-[day][optional [T[hour]:[minute]]][first [Z] [[offset_hour]]]",
+/// )?;
+/// let requirements = format.requirements();
+/// let year = Component::Year(modifier::Year::default());
+/// let hour = Component::Hour(modifier::Hour::default());
+/// let offset_hour = Component::OffsetHour(modifier::OffsetHour::default());
+/// let second = Component::Second(modifier::Second::default());
+///
+/// assert!(requirements.is_required(year));
+/// assert!(requirements.is_optional(hour));
+/// assert!(requirements.is_optional(offset_hour));
+/// assert!(!requirements.is_required(second) && !requirements.is_optional(second));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentRequirements {
+    /// The components that are always parsed, stored as a bitset.
+    required: u32,
+    /// The components that may be parsed, including those that are required.
+    present: u32,
+}
+
+impl ComponentRequirements {
+    /// No components are required or permitted.
+    const NONE: Self = Self {
+        required: 0,
+        present: 0,
+    };
+
+    /// Whether every successful parse includes a component of the same kind as the one provided.
+    pub const fn is_required(self, component: Component) -> bool {
+        self.required & bit(component) != 0
+    }
+
+    /// Whether a component of the same kind as the one provided may be parsed, but is not
+    /// required.
+    pub const fn is_optional(self, component: Component) -> bool {
+        self.present & !self.required & bit(component) != 0
+    }
+
+    /// The requirements of a sequence of items, all of which are parsed.
+    fn all(items: impl Iterator<Item = Self>) -> Self {
+        items.fold(Self::NONE, |acc, item| Self {
+            required: acc.required | item.required,
+            present: acc.present | item.present,
+        })
+    }
+
+    /// The requirements of a set of alternatives, of which exactly one is parsed.
+    fn any(mut items: impl Iterator<Item = Self>) -> Self {
+        let Some(first) = items.next() else {
+            return Self::NONE;
+        };
+        items.fold(first, |acc, item| Self {
+            required: acc.required & item.required,
+            present: acc.present | item.present,
+        })
+    }
+
+    /// Make all components optional.
+    const fn optional(self) -> Self {
+        Self {
+            required: 0,
+            present: self.present,
+        }
+    }
+
+    /// Only the provided component is required.
+    const fn component(component: Component) -> Self {
+        Self {
+            required: bit(component),
+            present: bit(component),
+        }
+    }
+}
+
+/// Obtain the bit representing the kind of the component.
+const fn bit(component: Component) -> u32 {
+    let index = match component {
+        Component::Day(_) => 0,
+        Component::Month(_) => 1,
+        Component::Ordinal(_) => 2,
+        Component::Weekday(_) => 3,
+        Component::WeekNumber(_) => 4,
+        Component::Year(_) => 5,
+        Component::Era(_) => 6,
+        Component::Quarter(_) => 7,
+        Component::Hour(_) => 8,
+        Component::Minute(_) => 9,
+        Component::Period(_) => 10,
+        Component::Second(_) => 11,
+        Component::Subsecond(_) => 12,
+        Component::SecondsOfDay(_) => 13,
+        Component::OffsetHour(_) => 14,
+        Component::OffsetMinute(_) => 15,
+        Component::OffsetSecond(_) => 16,
+        Component::Ignore(_) => 17,
+        Component::UnixTimestamp(_) => 18,
+        Component::End(_) => 19,
+    };
+    1 << index
+}
+
+impl BorrowedFormatItem<'_> {
+    /// Determine which components are required or optional when parsing with this item.
+    ///
+    /// A sequence of items can be inspected by first wrapping it in
+    /// [`BorrowedFormatItem::Compound`].
+    pub fn requirements(&self) -> ComponentRequirements {
+        match self {
+            Self::Literal(_) => ComponentRequirements::NONE,
+            Self::Component(component) | Self::Padded(component, _) => {
+                ComponentRequirements::component(*component)
+            }
+            Self::Compound(items) => {
+                ComponentRequirements::all(items.iter().map(Self::requirements))
+            }
+            Self::Optional(item) => item.requirements().optional(),
+            Self::First(items) => ComponentRequirements::any(items.iter().map(Self::requirements)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedFormatItem {
+    /// Determine which components are required or optional when parsing with this item.
+    pub fn requirements(&self) -> ComponentRequirements {
+        match self {
+            Self::Literal(_) => ComponentRequirements::NONE,
+            Self::Component(component) | Self::Padded(component, _) => {
+                ComponentRequirements::component(*component)
+            }
+            Self::Compound(items) => {
+                ComponentRequirements::all(items.iter().map(Self::requirements))
+            }
+            Self::Optional(item) => item.requirements().optional(),
+            Self::First(items) => ComponentRequirements::any(items.iter().map(Self::requirements)),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! The HTTP-date format described in RFC 7231.
+
+/// The HTTP-date format described in [RFC 7231](https://tools.ietf.org/html/rfc7231#section-7.1.1.1).
+///
+/// Example: Sun, 06 Nov 1994 08:49:37 GMT
+///
+/// When formatting, the preferred IMF-fixdate form is always used. The value is converted to UTC
+/// beforehand, as HTTP-date is always expressed in GMT.
+///
+/// When parsing, all three forms listed in the RFC are accepted: IMF-fixdate, the obsolete RFC 850
+/// form, and the obsolete asctime form. The two-digit year of the RFC 850 form is interpreted as
+/// 2000–2049 or 1950–1999, in the same manner as [`Rfc2822`](super::Rfc2822).
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::HttpDate, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::HttpDate;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1994-11-06 08:49:37 UTC).format(&HttpDate)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// assert_eq!(
+///     datetime!(1994-11-06 03:49:37 -05:00).format(&HttpDate)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpDate;
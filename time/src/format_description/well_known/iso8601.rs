@@ -62,6 +62,7 @@ define_assoc_consts! {
     /// - The date uses the calendar format.
     /// - The time has precision to the second and nine decimal digits.
     /// - The UTC offset has precision to the minute.
+    /// - `24:00:00` is rejected when parsing.
     ///
     /// If you need different behavior, use another associated constant. For full customization, use
     /// [`Config::DEFAULT`] and [`Config`]'s methods to create a custom configuration.
@@ -170,6 +171,8 @@ pub struct Config {
     pub(crate) time_precision: TimePrecision,
     /// The precision for the UTC offset.
     pub(crate) offset_precision: OffsetPrecision,
+    /// Whether `24:00:00` is accepted as the end of the day when parsing.
+    pub(crate) end_of_day_allowed: bool,
 }
 
 impl Config {
@@ -195,6 +198,7 @@ impl Config {
             decimal_digits: NonZeroU8::new(9),
         },
         offset_precision: OffsetPrecision::Minute,
+        end_of_day_allowed: false,
     };
 
     /// A configuration that can only be used for parsing. Using this to format a value is
@@ -208,6 +212,7 @@ impl Config {
             decimal_digits: None,
         },
         offset_precision: OffsetPrecision::Hour,
+        end_of_day_allowed: false,
     };
 
     /// Set whether the format the date, time, and/or UTC offset.
@@ -254,4 +259,18 @@ impl Config {
             ..self
         }
     }
+
+    /// Set whether `24:00:00` is accepted as the end of the day when parsing.
+    ///
+    /// When allowed, a time of `24:00:00` (with any precision, provided all other components are
+    /// zero) is parsed as midnight of the following day. Parsing only a time yields
+    /// [`Time::MIDNIGHT`](crate::Time::MIDNIGHT), with
+    /// [`Parsed::is_end_of_day`](crate::parsing::Parsed::is_end_of_day) indicating that the date
+    /// should be rolled forward. This has no effect on formatting.
+    pub const fn set_end_of_day_allowed(self, end_of_day_allowed: bool) -> Self {
+        Self {
+            end_of_day_allowed,
+            ..self
+        }
+    }
 }
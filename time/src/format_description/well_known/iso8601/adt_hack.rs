@@ -2,7 +2,7 @@
 
 use core::num::NonZeroU8;
 
-#[cfg(feature = "formatting")]
+#[cfg(any(feature = "formatting", feature = "parsing"))]
 use super::Iso8601;
 use super::{Config, DateKind, FormattedComponents as FC, OffsetPrecision, TimePrecision};
 
@@ -48,6 +48,12 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     pub(crate) const OFFSET_PRECISION: OffsetPrecision = Self::CONFIG.offset_precision;
 }
 
+#[cfg(feature = "parsing")]
+impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
+    /// Whether `24:00:00` is accepted as the end of the day.
+    pub(crate) const END_OF_DAY_ALLOWED: bool = Config::decode(CONFIG).end_of_day_allowed;
+}
+
 impl Config {
     /// Encode the configuration, permitting it to be used as a const parameter of [`Iso8601`].
     ///
@@ -89,6 +95,7 @@ impl Config {
             OffsetPrecision::Hour => 0,
             OffsetPrecision::Minute => 1,
         };
+        bytes[7] = self.end_of_day_allowed as _;
 
         EncodedConfig::from_be_bytes(bytes)
     }
@@ -141,9 +148,14 @@ impl Config {
             1 => OffsetPrecision::Minute,
             _ => panic!("invalid configuration"),
         };
+        let end_of_day_allowed = match bytes[7] {
+            0 => false,
+            1 => true,
+            _ => panic!("invalid configuration"),
+        };
 
         // No `for` loops in `const fn`.
-        let mut idx = 7; // first unused byte
+        let mut idx = 8; // first unused byte
         while idx < EncodedConfig::BITS as usize / 8 {
             assert!(bytes[idx] == 0, "invalid configuration");
             idx += 1;
@@ -156,6 +168,7 @@ impl Config {
             date_kind,
             time_precision,
             offset_precision,
+            end_of_day_allowed,
         }
     }
 }
@@ -174,6 +187,7 @@ mod tests {
                 && a.date_kind == b.date_kind
                 && a.time_precision == b.time_precision
                 && a.offset_precision == b.offset_precision
+                && a.end_of_day_allowed == b.end_of_day_allowed
         }};
     }
 
@@ -223,6 +237,8 @@ mod tests {
         }));
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Hour));
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Minute));
+        assert_roundtrip!(Config::DEFAULT.set_end_of_day_allowed(false));
+        assert_roundtrip!(Config::DEFAULT.set_end_of_day_allowed(true));
     }
 
     macro_rules! assert_decode_fail {
@@ -244,6 +260,7 @@ mod tests {
         assert_decode_fail!(0x00_00_00_03_00_00_00_00_00_00_00_00_00_00_00_00);
         assert_decode_fail!(0x00_00_00_00_03_00_00_00_00_00_00_00_00_00_00_00);
         assert_decode_fail!(0x00_00_00_00_00_00_02_00_00_00_00_00_00_00_00_00);
-        assert_decode_fail!(0x00_00_00_00_00_00_00_01_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_02_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_00_01_00_00_00_00_00_00_00);
     }
 }
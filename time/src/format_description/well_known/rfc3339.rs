@@ -28,3 +28,91 @@
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rfc3339;
+
+impl Rfc3339 {
+    /// Limit the number of subsecond digits accepted when parsing. Inputs containing more than
+    /// `max` subsecond digits are rejected rather than being truncated to nanosecond precision. The
+    /// resulting error is a [`ComponentRange`](crate::error::ComponentRange) named
+    /// `"subsecond digits"`.
+    ///
+    /// Formatting is not affected by this limit.
+    ///
+    /// # Examples
+    #[cfg_attr(feature = "parsing", doc = "```rust")]
+    #[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+    /// # use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+    /// # use time_macros::datetime;
+    /// let format = Rfc3339.max_subsecond_digits(3);
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("1985-04-12T23:20:50.521Z", &format)?,
+    ///     datetime!(1985-04-12 23:20:50.521 +00:00)
+    /// );
+    /// assert!(OffsetDateTime::parse("1985-04-12T23:20:50.5213Z", &format).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn max_subsecond_digits(self, max: u8) -> Rfc3339Bounded {
+        Rfc3339Bounded {
+            max_subsecond_digits: max,
+        }
+    }
+
+    /// Always format exactly `digits` subsecond digits, padding with zeroes as necessary. When
+    /// `digits` is zero, the subsecond and its leading `.` are omitted. Any precision beyond the
+    /// requested number of digits is truncated.
+    ///
+    /// Parsing is not affected, and any number of subsecond digits continues to be accepted.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `digits` is greater than nine.
+    ///
+    /// # Examples
+    #[cfg_attr(feature = "formatting", doc = "```rust")]
+    #[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(1985-04-12 23:20:50 +00:00).format(&Rfc3339.subsecond_digits(9))?,
+    ///     "1985-04-12T23:20:50.000000000Z"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1985-04-12 23:20:50.52 +00:00).format(&Rfc3339.subsecond_digits(3))?,
+    ///     "1985-04-12T23:20:50.520Z"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1985-04-12 23:20:50.52 +00:00).format(&Rfc3339.subsecond_digits(0))?,
+    ///     "1985-04-12T23:20:50Z"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn subsecond_digits(self, digits: u8) -> Rfc3339Fixed {
+        if digits > 9 {
+            crate::expect_failed("RFC 3339 subsecond digits must be at most nine");
+        }
+        Rfc3339Fixed {
+            subsecond_digits: digits,
+        }
+    }
+}
+
+/// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), with a
+/// limit on the number of subsecond digits accepted when parsing.
+///
+/// This is created by [`Rfc3339::max_subsecond_digits`]. Other than the limit, it behaves
+/// identically to [`Rfc3339`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3339Bounded {
+    /// The maximum number of subsecond digits accepted when parsing.
+    pub(crate) max_subsecond_digits: u8,
+}
+
+/// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), with a
+/// fixed number of subsecond digits when formatting.
+///
+/// This is created by [`Rfc3339::subsecond_digits`]. Other than the number of subsecond digits
+/// that are formatted, it behaves identically to [`Rfc3339`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3339Fixed {
+    /// The number of subsecond digits to format.
+    pub(crate) subsecond_digits: u8,
+}
@@ -2,18 +2,22 @@
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt;
 use core::ops::Deref;
 use std::io;
 
 use num_conv::prelude::*;
 
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use crate::format_description::well_known::{
+    HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc3339Bounded, Rfc3339Fixed,
+};
 use crate::format_description::{BorrowedFormatItem, OwnedFormatItem};
 use crate::formatting::{
-    format_component, format_number_pad_zero, iso8601, write, MONTH_NAMES, WEEKDAY_NAMES,
+    format_component, format_number_pad_zero, format_padded_component, iso8601, write, FmtWriter,
+    MONTH_NAMES, WEEKDAY_NAMES,
 };
-use crate::{error, Date, Time, UtcOffset};
+use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
 
 /// A type that describes a format.
 ///
@@ -28,7 +32,10 @@ impl Formattable for [BorrowedFormatItem<'_>] {}
 impl Formattable for OwnedFormatItem {}
 impl Formattable for [OwnedFormatItem] {}
 impl Formattable for Rfc3339 {}
+impl Formattable for Rfc3339Bounded {}
+impl Formattable for Rfc3339Fixed {}
 impl Formattable for Rfc2822 {}
+impl Formattable for HttpDate {}
 impl<const CONFIG: EncodedConfig> Formattable for Iso8601<CONFIG> {}
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
 
@@ -48,6 +55,35 @@ mod sealed {
             offset: Option<UtcOffset>,
         ) -> Result<usize, error::Format>;
 
+        /// Format the item into the provided [`fmt::Write`] output, returning the number of bytes
+        /// written.
+        fn format_into_fmt(
+            &self,
+            output: &mut (impl fmt::Write + ?Sized),
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+        ) -> Result<usize, error::Format> {
+            self.format_into(&mut FmtWriter(output), date, time, offset)
+        }
+
+        /// Format the item into the start of the provided slice, returning the number of bytes
+        /// written.
+        fn format_into_slice(
+            &self,
+            output: &mut [u8],
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+        ) -> Result<usize, error::Format> {
+            match self.format_into(&mut &mut *output, date, time, offset) {
+                Err(error::Format::StdIo(err)) if err.kind() == io::ErrorKind::WriteZero => {
+                    Err(error::Format::BufferTooSmall)
+                }
+                result => result,
+            }
+        }
+
         /// Format the item directly to a `String`.
         fn format(
             &self,
@@ -80,6 +116,9 @@ impl sealed::Sealed for BorrowedFormatItem<'_> {
                 [] => 0,
                 [item, ..] => item.format_into(output, date, time, offset)?,
             },
+            Self::Padded(component, min_width) => {
+                format_padded_component(output, component, min_width, date, time, offset)?
+            }
         })
     }
 }
@@ -117,6 +156,9 @@ impl sealed::Sealed for OwnedFormatItem {
                 [] => Ok(0),
                 [item, ..] => item.format_into(output, date, time, offset),
             },
+            Self::Padded(component, min_width) => {
+                format_padded_component(output, *component, *min_width, date, time, offset)
+            }
         }
     }
 }
@@ -205,7 +247,7 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
-impl sealed::Sealed for Rfc3339 {
+impl sealed::Sealed for HttpDate {
     fn format_into(
         &self,
         output: &mut impl io::Write,
@@ -217,69 +259,176 @@ impl sealed::Sealed for Rfc3339 {
         let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
         let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
 
+        let (date, time) = if offset.is_utc() {
+            (date, time)
+        } else {
+            let datetime = OffsetDateTime::new_in_offset(date, time, offset)
+                .checked_to_offset(UtcOffset::UTC)
+                .ok_or(error::Format::InvalidComponent("year"))?;
+            (datetime.date(), datetime.time())
+        };
+
         let mut bytes = 0;
 
-        let year = date.year();
+        let (year, month, day) = date.to_calendar_date();
 
         if !(0..10_000).contains(&year) {
             return Err(error::Format::InvalidComponent("year"));
         }
-        if offset.whole_hours().unsigned_abs() > 23 {
-            return Err(error::Format::InvalidComponent("offset_hour"));
-        }
-        if offset.seconds_past_minute() != 0 {
-            return Err(error::Format::InvalidComponent("offset_second"));
-        }
 
+        bytes += write(
+            output,
+            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..3],
+        )?;
+        bytes += write(output, b", ")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += write(
+            output,
+            &MONTH_NAMES[u8::from(month).extend::<usize>() - 1][..3],
+        )?;
+        bytes += write(output, b" ")?;
         bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
-        bytes += write(output, b"-")?;
-        bytes += format_number_pad_zero::<2>(output, u8::from(date.month()))?;
-        bytes += write(output, b"-")?;
-        bytes += format_number_pad_zero::<2>(output, date.day())?;
-        bytes += write(output, b"T")?;
+        bytes += write(output, b" ")?;
         bytes += format_number_pad_zero::<2>(output, time.hour())?;
         bytes += write(output, b":")?;
         bytes += format_number_pad_zero::<2>(output, time.minute())?;
         bytes += write(output, b":")?;
         bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b" GMT")?;
 
-        #[allow(clippy::if_not_else)]
-        if time.nanosecond() != 0 {
-            let nanos = time.nanosecond();
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc3339 {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        format_rfc3339(output, date, time, offset, None)
+    }
+}
+
+impl sealed::Sealed for Rfc3339Bounded {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        Rfc3339.format_into(output, date, time, offset)
+    }
+}
+
+impl sealed::Sealed for Rfc3339Fixed {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        format_rfc3339(output, date, time, offset, Some(self.subsecond_digits))
+    }
+}
+
+/// Format the value using RFC 3339. If `subsecond_digits` is `None`, the minimum number of
+/// subsecond digits necessary is used; otherwise exactly that many digits are present.
+fn format_rfc3339(
+    output: &mut impl io::Write,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+    subsecond_digits: Option<u8>,
+) -> Result<usize, error::Format> {
+    let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+    let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+    let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+    let mut bytes = 0;
+
+    let year = date.year();
+
+    if !(0..10_000).contains(&year) {
+        return Err(error::Format::InvalidComponent("year"));
+    }
+    if offset.whole_hours().unsigned_abs() > 23 {
+        return Err(error::Format::InvalidComponent("offset_hour"));
+    }
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::Format::InvalidComponent("offset_second"));
+    }
+
+    bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
+    bytes += write(output, b"-")?;
+    bytes += format_number_pad_zero::<2>(output, u8::from(date.month()))?;
+    bytes += write(output, b"-")?;
+    bytes += format_number_pad_zero::<2>(output, date.day())?;
+    bytes += write(output, b"T")?;
+    bytes += format_number_pad_zero::<2>(output, time.hour())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, time.minute())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, time.second())?;
+
+    #[allow(clippy::if_not_else)]
+    if let Some(digits) = subsecond_digits {
+        let nanos = time.nanosecond();
+        if digits != 0 {
             bytes += write(output, b".")?;
-            bytes += if nanos % 10 != 0 {
-                format_number_pad_zero::<9>(output, nanos)
-            } else if (nanos / 10) % 10 != 0 {
-                format_number_pad_zero::<8>(output, nanos / 10)
-            } else if (nanos / 100) % 10 != 0 {
-                format_number_pad_zero::<7>(output, nanos / 100)
-            } else if (nanos / 1_000) % 10 != 0 {
-                format_number_pad_zero::<6>(output, nanos / 1_000)
-            } else if (nanos / 10_000) % 10 != 0 {
-                format_number_pad_zero::<5>(output, nanos / 10_000)
-            } else if (nanos / 100_000) % 10 != 0 {
-                format_number_pad_zero::<4>(output, nanos / 100_000)
-            } else if (nanos / 1_000_000) % 10 != 0 {
-                format_number_pad_zero::<3>(output, nanos / 1_000_000)
-            } else if (nanos / 10_000_000) % 10 != 0 {
-                format_number_pad_zero::<2>(output, nanos / 10_000_000)
-            } else {
-                format_number_pad_zero::<1>(output, nanos / 100_000_000)
-            }?;
         }
+        bytes += match digits {
+            0 => 0,
+            1 => format_number_pad_zero::<1>(output, nanos / 100_000_000)?,
+            2 => format_number_pad_zero::<2>(output, nanos / 10_000_000)?,
+            3 => format_number_pad_zero::<3>(output, nanos / 1_000_000)?,
+            4 => format_number_pad_zero::<4>(output, nanos / 100_000)?,
+            5 => format_number_pad_zero::<5>(output, nanos / 10_000)?,
+            6 => format_number_pad_zero::<6>(output, nanos / 1_000)?,
+            7 => format_number_pad_zero::<7>(output, nanos / 100)?,
+            8 => format_number_pad_zero::<8>(output, nanos / 10)?,
+            _ => format_number_pad_zero::<9>(output, nanos)?,
+        };
+    } else if time.nanosecond() != 0 {
+        let nanos = time.nanosecond();
+        bytes += write(output, b".")?;
+        bytes += if nanos % 10 != 0 {
+            format_number_pad_zero::<9>(output, nanos)
+        } else if (nanos / 10) % 10 != 0 {
+            format_number_pad_zero::<8>(output, nanos / 10)
+        } else if (nanos / 100) % 10 != 0 {
+            format_number_pad_zero::<7>(output, nanos / 100)
+        } else if (nanos / 1_000) % 10 != 0 {
+            format_number_pad_zero::<6>(output, nanos / 1_000)
+        } else if (nanos / 10_000) % 10 != 0 {
+            format_number_pad_zero::<5>(output, nanos / 10_000)
+        } else if (nanos / 100_000) % 10 != 0 {
+            format_number_pad_zero::<4>(output, nanos / 100_000)
+        } else if (nanos / 1_000_000) % 10 != 0 {
+            format_number_pad_zero::<3>(output, nanos / 1_000_000)
+        } else if (nanos / 10_000_000) % 10 != 0 {
+            format_number_pad_zero::<2>(output, nanos / 10_000_000)
+        } else {
+            format_number_pad_zero::<1>(output, nanos / 100_000_000)
+        }?;
+    }
 
-        if offset == UtcOffset::UTC {
-            bytes += write(output, b"Z")?;
-            return Ok(bytes);
-        }
+    if offset == UtcOffset::UTC {
+        bytes += write(output, b"Z")?;
+        return Ok(bytes);
+    }
 
-        bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
-        bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
-        bytes += write(output, b":")?;
-        bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+    bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+    bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
 
-        Ok(bytes)
-    }
+    Ok(bytes)
 }
 
 impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
@@ -3,6 +3,7 @@
 pub(crate) mod formattable;
 mod iso8601;
 
+use core::fmt;
 use core::num::NonZeroU8;
 use std::io;
 
@@ -41,12 +42,66 @@ const WEEKDAY_NAMES: [&[u8]; 7] = [
     b"Sunday",
 ];
 
+/// An adapter that allows writing to a [`fmt::Write`] implementor as though it were an
+/// [`io::Write`] implementor.
+///
+/// Each buffer written must be valid UTF-8. If it is not, an error is returned and nothing is
+/// written.
+pub(crate) struct FmtWriter<'a, W: fmt::Write + ?Sized>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write + ?Sized> io::Write for FmtWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let s = core::str::from_utf8(buf)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.0
+            .write_str(s)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An [`io::Write`] implementor that discards its input, counting the number of characters written.
+/// UTF-8 continuation bytes are not counted.
+struct CharCounter(usize);
+
+impl io::Write for CharCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.iter().filter(|&&byte| byte & 0xC0 != 0x80).count();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Write all bytes to the output, returning the number of bytes written.
 pub(crate) fn write(output: &mut impl io::Write, bytes: &[u8]) -> io::Result<usize> {
     output.write_all(bytes)?;
     Ok(bytes.len())
 }
 
+/// Write the name to the output using the provided casing, returning the number of bytes written.
+fn write_name(
+    output: &mut impl io::Write,
+    name: &[u8],
+    case: modifier::NameCase,
+) -> io::Result<usize> {
+    let mut buf = [0; 9];
+    let buf = &mut buf[..name.len()];
+    buf.copy_from_slice(name);
+    match case {
+        modifier::NameCase::Title => {}
+        modifier::NameCase::Upper => buf.make_ascii_uppercase(),
+        modifier::NameCase::Lower => buf.make_ascii_lowercase(),
+    }
+    write(output, buf)
+}
+
 /// If `pred` is true, write all bytes to the output, returning the number of bytes written.
 pub(crate) fn write_if(output: &mut impl io::Write, pred: bool, bytes: &[u8]) -> io::Result<usize> {
     if pred { write(output, bytes) } else { Ok(0) }
@@ -165,11 +220,14 @@ pub(crate) fn format_component(
         (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
+        (Era(modifier), Some(date), ..) => fmt_era(output, date, modifier)?,
+        (Quarter(modifier), Some(date), ..) => fmt_quarter(output, date, modifier)?,
         (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
         (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
         (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
         (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
         (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
+        (SecondsOfDay(modifier), _, Some(time), _) => fmt_seconds_of_day(output, time, modifier)?,
         (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
         (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
         (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
@@ -185,14 +243,45 @@ pub(crate) fn format_component(
         // Allow unreachable patterns because some branches may be fully matched above.
         #[allow(unreachable_patterns)]
         (
-            Day(_) | Month(_) | Ordinal(_) | Weekday(_) | WeekNumber(_) | Year(_) | Hour(_)
-            | Minute(_) | Period(_) | Second(_) | Subsecond(_) | OffsetHour(_) | OffsetMinute(_)
-            | OffsetSecond(_) | Ignore(_) | UnixTimestamp(_) | End(_),
+            Day(_) | Month(_) | Ordinal(_) | Weekday(_) | WeekNumber(_) | Year(_) | Era(_)
+            | Quarter(_) | Hour(_) | Minute(_) | Period(_) | Second(_) | Subsecond(_)
+            | SecondsOfDay(_) | OffsetHour(_) | OffsetMinute(_) | OffsetSecond(_) | Ignore(_)
+            | UnixTimestamp(_) | End(_),
             ..,
         ) => return Err(error::Format::InsufficientTypeInformation),
     })
 }
 
+/// Format the provided component into the designated output, filling it to the minimum width.
+pub(crate) fn format_padded_component(
+    output: &mut impl io::Write,
+    component: Component,
+    min_width: modifier::MinWidth,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    // The component is formatted once to determine its width and again to write it, avoiding an
+    // intermediate allocation.
+    let mut counter = CharCounter(0);
+    format_component(&mut counter, component, date, time, offset)?;
+    let fill_width = usize::from(min_width.width).saturating_sub(counter.0);
+
+    let mut bytes = 0;
+    if !min_width.is_left_aligned {
+        for _ in 0..fill_width {
+            bytes += write(output, &[min_width.fill])?;
+        }
+    }
+    bytes += format_component(output, component, date, time, offset)?;
+    if min_width.is_left_aligned {
+        for _ in 0..fill_width {
+            bytes += write(output, &[min_width.fill])?;
+        }
+    }
+    Ok(bytes)
+}
+
 // region: date formatters
 /// Format the day into the designated output.
 fn fmt_day(
@@ -210,6 +299,7 @@ fn fmt_month(
     modifier::Month {
         padding,
         repr,
+        case,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
 ) -> Result<usize, io::Error> {
@@ -217,13 +307,15 @@ fn fmt_month(
         modifier::MonthRepr::Numerical => {
             format_number::<2>(output, u8::from(date.month()), padding)
         }
-        modifier::MonthRepr::Long => write(
+        modifier::MonthRepr::Long => write_name(
             output,
             MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1],
+            case,
         ),
-        modifier::MonthRepr::Short => write(
+        modifier::MonthRepr::Short => write_name(
             output,
             &MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1][..3],
+            case,
         ),
     }
 }
@@ -244,17 +336,20 @@ fn fmt_weekday(
     modifier::Weekday {
         repr,
         one_indexed,
+        case,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
 ) -> Result<usize, io::Error> {
     match repr {
-        modifier::WeekdayRepr::Short => write(
+        modifier::WeekdayRepr::Short => write_name(
             output,
             &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..3],
+            case,
         ),
-        modifier::WeekdayRepr::Long => write(
+        modifier::WeekdayRepr::Long => write_name(
             output,
             WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()],
+            case,
         ),
         modifier::WeekdayRepr::Sunday => format_number::<1>(
             output,
@@ -295,6 +390,8 @@ fn fmt_year(
         repr,
         iso_week_based,
         sign_is_mandatory,
+        era_based,
+        range_start: _, // no effect on formatting
     }: modifier::Year,
 ) -> Result<usize, io::Error> {
     let full_year = if iso_week_based {
@@ -302,6 +399,12 @@ fn fmt_year(
     } else {
         date.year()
     };
+    // Astronomical year 0 is 1 BC, year -1 is 2 BC, and so on.
+    let full_year = if era_based && full_year <= 0 {
+        1 - full_year
+    } else {
+        full_year
+    };
     let value = match repr {
         modifier::YearRepr::Full => full_year,
         modifier::YearRepr::LastTwo => (full_year % 100).abs(),
@@ -315,7 +418,7 @@ fn fmt_year(
         modifier::YearRepr::LastTwo => format_number::<2>,
     };
     let mut bytes = 0;
-    if repr != modifier::YearRepr::LastTwo {
+    if repr != modifier::YearRepr::LastTwo && !era_based {
         if full_year < 0 {
             bytes += write(output, b"-")?;
         } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
@@ -325,6 +428,33 @@ fn fmt_year(
     bytes += format_number(output, value.unsigned_abs(), padding)?;
     Ok(bytes)
 }
+
+/// Format the era into the designated output.
+fn fmt_era(
+    output: &mut impl io::Write,
+    date: Date,
+    modifier::Era { is_uppercase }: modifier::Era,
+) -> Result<usize, io::Error> {
+    match (date.year() <= 0, is_uppercase) {
+        (false, false) => write(output, b"ad"),
+        (false, true) => write(output, b"AD"),
+        (true, false) => write(output, b"bc"),
+        (true, true) => write(output, b"BC"),
+    }
+}
+
+/// Format the quarter into the designated output.
+fn fmt_quarter(
+    output: &mut impl io::Write,
+    date: Date,
+    modifier::Quarter { repr }: modifier::Quarter,
+) -> Result<usize, io::Error> {
+    match repr {
+        modifier::QuarterRepr::Numerical => {
+            format_number::<1>(output, date.quarter(), modifier::Padding::None)
+        }
+    }
+}
 // endregion date formatters
 
 // region: time formatters
@@ -335,6 +465,7 @@ fn fmt_hour(
     modifier::Hour {
         padding,
         is_12_hour_clock,
+        fraction,
     }: modifier::Hour,
 ) -> Result<usize, io::Error> {
     let value = match (time.hour(), is_12_hour_clock) {
@@ -343,16 +474,41 @@ fn fmt_hour(
         (hour, true) if hour < 12 => hour,
         (hour, true) => hour - 12,
     };
-    format_number::<2>(output, value, padding)
+    let mut bytes = format_number::<2>(output, value, padding)?;
+    if let Some(digits) = fraction {
+        let nanos = time.minute().extend::<u64>() * Nanosecond::per(Minute)
+            + time.second().extend::<u64>() * Nanosecond::per(Second).extend::<u64>()
+            + time.nanosecond().extend::<u64>();
+        bytes += write(output, b".")?;
+        // The fraction of the hour, in billionths.
+        bytes += fmt_fraction(
+            output,
+            (nanos / Second::per(Hour).extend::<u64>()).truncate(),
+            digits,
+        )?;
+    }
+    Ok(bytes)
 }
 
 /// Format the minute into the designated output.
 fn fmt_minute(
     output: &mut impl io::Write,
     time: Time,
-    modifier::Minute { padding }: modifier::Minute,
+    modifier::Minute { padding, fraction }: modifier::Minute,
 ) -> Result<usize, io::Error> {
-    format_number::<2>(output, time.minute(), padding)
+    let mut bytes = format_number::<2>(output, time.minute(), padding)?;
+    if let Some(digits) = fraction {
+        let nanos = time.second().extend::<u64>() * Nanosecond::per(Second).extend::<u64>()
+            + time.nanosecond().extend::<u64>();
+        bytes += write(output, b".")?;
+        // The fraction of the minute, in billionths.
+        bytes += fmt_fraction(
+            output,
+            (nanos / Second::per(Minute).extend::<u64>()).truncate(),
+            digits,
+        )?;
+    }
+    Ok(bytes)
 }
 
 /// Format the period into the designated output.
@@ -362,6 +518,7 @@ fn fmt_period(
     modifier::Period {
         is_uppercase,
         case_sensitive: _, // no effect on formatting
+        is_optional: _,    // no effect on formatting
     }: modifier::Period,
 ) -> Result<usize, io::Error> {
     match (time.hour() >= 12, is_uppercase) {
@@ -386,9 +543,18 @@ fn fmt_subsecond<W: io::Write>(
     output: &mut W,
     time: Time,
     modifier::Subsecond { digits }: modifier::Subsecond,
+) -> Result<usize, io::Error> {
+    fmt_fraction(output, time.nanosecond(), digits)
+}
+
+/// Format a fraction, given in billionths, into the designated output. This is shared by the
+/// subsecond and the fractional part of the hour and minute.
+fn fmt_fraction<W: io::Write>(
+    output: &mut W,
+    nanos: u32,
+    digits: modifier::SubsecondDigits,
 ) -> Result<usize, io::Error> {
     use modifier::SubsecondDigits::*;
-    let nanos = time.nanosecond();
 
     if digits == Nine || (digits == OneOrMore && nanos % 10 != 0) {
         format_number_pad_zero::<9>(output, nanos)
@@ -410,6 +576,22 @@ fn fmt_subsecond<W: io::Write>(
         format_number_pad_zero::<1>(output, nanos / 100_000_000)
     }
 }
+
+/// Format the number of seconds since midnight into the designated output.
+fn fmt_seconds_of_day(
+    output: &mut impl io::Write,
+    time: Time,
+    modifier::SecondsOfDay { padding }: modifier::SecondsOfDay,
+) -> Result<usize, io::Error> {
+    let (hour, minute, second) = time.as_hms();
+    format_number::<5>(
+        output,
+        hour.extend::<u32>() * Second::per(Hour).extend::<u32>()
+            + minute.extend::<u32>() * Second::per(Minute).extend::<u32>()
+            + second.extend::<u32>(),
+        padding,
+    )
+}
 // endregion time formatters
 
 // region: offset formatters
@@ -420,6 +602,7 @@ fn fmt_offset_hour(
     modifier::OffsetHour {
         padding,
         sign_is_mandatory,
+        utc_alias_allowed: _,
     }: modifier::OffsetHour,
 ) -> Result<usize, io::Error> {
     let mut bytes = 0;
@@ -476,15 +659,17 @@ fn fmt_unix_timestamp(
         }
         modifier::UnixTimestampPrecision::Millisecond => format_number_pad_none(
             output,
-            (date_time.unix_timestamp_nanos()
-                / Nanosecond::per(Millisecond).cast_signed().extend::<i128>())
-            .unsigned_abs(),
+            date_time
+                .unix_timestamp_nanos()
+                .div_euclid(Nanosecond::per(Millisecond).cast_signed().extend::<i128>())
+                .unsigned_abs(),
         ),
         modifier::UnixTimestampPrecision::Microsecond => format_number_pad_none(
             output,
-            (date_time.unix_timestamp_nanos()
-                / Nanosecond::per(Microsecond).cast_signed().extend::<i128>())
-            .unsigned_abs(),
+            date_time
+                .unix_timestamp_nanos()
+                .div_euclid(Nanosecond::per(Microsecond).cast_signed().extend::<i128>())
+                .unsigned_abs(),
         ),
         modifier::UnixTimestampPrecision::Nanosecond => {
             format_number_pad_none(output, date_time.unix_timestamp_nanos().unsigned_abs())
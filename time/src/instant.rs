@@ -63,6 +63,38 @@ impl Instant {
     pub fn elapsed(self) -> Duration {
         Self::now() - self
     }
+
+    /// Returns the amount of time elapsed from another instant to this one, or `None` if that
+    /// instant is later than this one.
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use time::{Instant, ext::NumericalDuration};
+    /// let now = Instant::now();
+    /// let later = now + 1.seconds();
+    /// assert_eq!(later.checked_duration_since(now), Some(1.seconds()));
+    /// assert_eq!(now.checked_duration_since(later), None);
+    /// ```
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        Duration::try_from_std(self.0.checked_duration_since(earlier.0)?).ok()
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one, or [`Duration::ZERO`]
+    /// if that instant is later than this one.
+    ///
+    /// Unlike subtraction, this never panics, making it suitable for defensive measurements.
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use time::{Duration, Instant, ext::NumericalDuration};
+    /// let now = Instant::now();
+    /// let later = now + 1.seconds();
+    /// assert_eq!(later.saturating_duration_since(now), 1.seconds());
+    /// assert_eq!(now.saturating_duration_since(later), Duration::ZERO);
+    /// ```
+    pub fn saturating_duration_since(self, earlier: Self) -> Duration {
+        Duration::saturating_from_std(self.0.saturating_duration_since(earlier.0))
+    }
     // endregion delegation
 
     // region: checked arithmetic
@@ -142,7 +174,8 @@ impl Sub for Instant {
 
     /// # Panics
     ///
-    /// This may panic if an overflow occurs.
+    /// This may panic if an overflow occurs. [`Instant::saturating_duration_since`] and
+    /// [`Instant::checked_duration_since`] can be used to avoid this.
     fn sub(self, other: Self) -> Self::Output {
         match self.0.cmp(&other.0) {
             Ordering::Equal => Duration::ZERO,
@@ -115,7 +115,7 @@ mod weekday;
 
 pub use time_core::convert;
 
-pub use crate::date::Date;
+pub use crate::date::{CalendarMonth, Date, DateRange};
 pub use crate::duration::Duration;
 pub use crate::error::Error;
 #[cfg(feature = "std")]
@@ -125,7 +125,7 @@ pub use crate::month::Month;
 pub use crate::offset_date_time::OffsetDateTime;
 pub use crate::primitive_date_time::PrimitiveDateTime;
 pub use crate::time::Time;
-pub use crate::utc_offset::UtcOffset;
+pub use crate::utc_offset::{LocalResult, UtcOffset};
 pub use crate::weekday::Weekday;
 
 /// An alias for [`std::result::Result`] with a generic error from the time crate.
@@ -1,13 +1,12 @@
 //! The `Month` enum and its associated `impl`s.
 
 use core::fmt;
-use core::num::NonZeroU8;
 use core::str::FromStr;
 
 use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
 
 use self::Month::*;
-use crate::error;
+use crate::{error, util};
 
 /// Months of the year.
 #[repr(u8)]
@@ -40,9 +39,17 @@ pub enum Month {
 }
 
 impl Month {
-    /// Create a `Month` from its numerical value.
-    pub(crate) const fn from_number(n: NonZeroU8) -> Result<Self, error::ComponentRange> {
-        match n.get() {
+    /// Create a `Month` from its number, where January is `1` and December is `12`.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::from_u8(1), Ok(Month::January));
+    /// assert_eq!(Month::from_u8(12), Ok(Month::December));
+    /// assert!(Month::from_u8(0).is_err()); // 0 isn't a valid month
+    /// assert!(Month::from_u8(13).is_err()); // 13 isn't a valid month
+    /// ```
+    pub const fn from_u8(n: u8) -> Result<Self, error::ComponentRange> {
+        match n {
             1 => Ok(January),
             2 => Ok(February),
             3 => Ok(March),
@@ -65,6 +72,17 @@ impl Month {
         }
     }
 
+    /// Get the number of the month, where January is `1` and December is `12`.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.to_u8(), 1);
+    /// assert_eq!(Month::December.to_u8(), 12);
+    /// ```
+    pub const fn to_u8(self) -> u8 {
+        self as _
+    }
+
     /// Get the previous month.
     ///
     /// ```rust
@@ -164,6 +182,49 @@ impl Month {
             }
         }
     }
+
+    /// Get the number of days in the month of a given year.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::February.length(2020), 29);
+    /// assert_eq!(Month::February.length(2021), 28);
+    /// ```
+    pub const fn length(self, year: i32) -> u8 {
+        util::days_in_year_month(year, self)
+    }
+
+    /// Get the number of days in the given year that precede the first day of the month.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.days_in_year_before(2020), 0);
+    /// assert_eq!(Month::February.days_in_year_before(2020), 31);
+    /// assert_eq!(Month::March.days_in_year_before(2020), 60);
+    /// assert_eq!(Month::March.days_in_year_before(2021), 59);
+    /// ```
+    pub const fn days_in_year_before(self, year: i32) -> u16 {
+        /// Cumulative days through the beginning of a month in both common and leap years.
+        const DAYS_CUMULATIVE_COMMON_LEAP: [[u16; 12]; 2] = [
+            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
+            [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
+        ];
+
+        DAYS_CUMULATIVE_COMMON_LEAP[util::is_leap_year(year) as usize][self as usize - 1]
+    }
+
+    /// Get an iterator over all twelve months in order, from January through December.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// let mut months = Month::all();
+    /// assert_eq!(months.len(), 12);
+    /// assert_eq!(months.next(), Some(Month::January));
+    /// assert_eq!(months.last(), Some(Month::December));
+    /// ```
+    pub fn all() -> impl ExactSizeIterator<Item = Self> + DoubleEndedIterator + Clone {
+        (0..12).map(|n| January.nth_next(n))
+    }
 }
 
 mod private {
@@ -217,31 +278,54 @@ impl fmt::Display for Month {
     }
 }
 
+/// Parse a month from its full English name or three-letter abbreviation, ignoring case, or from
+/// its number, where January is 1 and December is 12.
+///
+/// ```rust
+/// # use time::Month;
+/// assert_eq!("February".parse(), Ok(Month::February));
+/// assert_eq!("feb".parse(), Ok(Month::February));
+/// assert_eq!("3".parse(), Ok(Month::March));
+/// assert!("13".parse::<Month>().is_err());
+/// ```
 impl FromStr for Month {
     type Err = error::InvalidVariant;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "January" => Ok(January),
-            "February" => Ok(February),
-            "March" => Ok(March),
-            "April" => Ok(April),
-            "May" => Ok(May),
-            "June" => Ok(June),
-            "July" => Ok(July),
-            "August" => Ok(August),
-            "September" => Ok(September),
-            "October" => Ok(October),
-            "November" => Ok(November),
-            "December" => Ok(December),
-            _ => Err(error::InvalidVariant),
+        const NAMES: [(&str, Month); 12] = [
+            ("January", January),
+            ("February", February),
+            ("March", March),
+            ("April", April),
+            ("May", May),
+            ("June", June),
+            ("July", July),
+            ("August", August),
+            ("September", September),
+            ("October", October),
+            ("November", November),
+            ("December", December),
+        ];
+
+        if !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_digit()) {
+            return s
+                .parse::<u8>()
+                .ok()
+                .and_then(|number| Self::try_from(number).ok())
+                .ok_or(error::InvalidVariant);
         }
+
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]))
+            .map(|&(_, month)| month)
+            .ok_or(error::InvalidVariant)
     }
 }
 
 impl From<Month> for u8 {
     fn from(month: Month) -> Self {
-        month as _
+        month.to_u8()
     }
 }
 
@@ -249,15 +333,6 @@ impl TryFrom<u8> for Month {
     type Error = error::ComponentRange;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match NonZeroU8::new(value) {
-            Some(value) => Self::from_number(value),
-            None => Err(error::ComponentRange {
-                name: "month",
-                minimum: 1,
-                maximum: 12,
-                value: 0,
-                conditional_range: false,
-            }),
-        }
+        Self::from_u8(value)
     }
 }
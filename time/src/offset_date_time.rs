@@ -111,6 +111,39 @@ impl OffsetDateTime {
         SystemTime::now().into()
     }
 
+    /// Create a new `OffsetDateTime` with the current date and time in UTC, truncated to a
+    /// multiple of `granularity` since the Unix epoch.
+    ///
+    /// Granularities that do not evenly divide a second (such as 7 milliseconds) are still
+    /// measured from the Unix epoch, not from the start of the current second.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time::ext::NumericalDuration;
+    /// let now = OffsetDateTime::now_utc_truncated(1.seconds());
+    /// assert_eq!(now.nanosecond(), 0);
+    /// assert!(now <= OffsetDateTime::now_utc());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn now_utc_truncated(granularity: Duration) -> Self {
+        if !granularity.is_positive() {
+            crate::expect_failed("truncation granularity must be positive");
+        }
+
+        let timestamp = Self::now_utc().unix_timestamp_nanos();
+        let timestamp = timestamp - timestamp.rem_euclid(granularity.whole_nanoseconds());
+        match Self::from_unix_timestamp_nanos(timestamp) {
+            Ok(value) => value,
+            // A value earlier than the current time is out of range only if the granularity
+            // exceeds the time since `Date::MIN`.
+            Err(_) => crate::expect_failed("truncated time is out of range"),
+        }
+    }
+
     /// Attempt to create a new `OffsetDateTime` with the current date and time in the local offset.
     /// If the offset cannot be determined, an error is returned.
     ///
@@ -125,6 +158,23 @@ impl OffsetDateTime {
         let t = Self::now_utc();
         Ok(t.to_offset(UtcOffset::local_offset_at(t)?))
     }
+
+    /// Attempt to create a new `OffsetDateTime` with the current date and time in the local offset.
+    /// If the offset cannot be determined, the reason is returned.
+    ///
+    /// ```rust
+    /// # use time::{error::IndeterminateOffsetReason, OffsetDateTime};
+    /// match OffsetDateTime::now_local_detailed() {
+    ///     Ok(now) => println!("{now}"),
+    ///     Err(IndeterminateOffsetReason::Unsupported) => println!("{}", OffsetDateTime::now_utc()),
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// ```
+    #[cfg(feature = "local-offset")]
+    pub fn now_local_detailed() -> Result<Self, error::IndeterminateOffsetReason> {
+        let t = Self::now_utc();
+        Ok(t.to_offset(UtcOffset::local_offset_at_detailed(t)?))
+    }
     // endregion now
 
     /// Create a new `OffsetDateTime` with the given [`Date`], [`Time`], and [`UtcOffset`].
@@ -381,6 +431,119 @@ impl OffsetDateTime {
             UtcOffset::UTC,
         ))
     }
+
+    /// Construct an `OffsetDateTime` from the provided Unix timestamp (in milliseconds). Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(0),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(1_546_300_800_123),
+    ///     Ok(datetime!(2019-01-01 0:00:00.123 UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(-1),
+    ///     Ok(datetime!(1969-12-31 23:59:59.999 UTC)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_millis(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Self::from_unix_timestamp_nanos(timestamp as i128 * Nanosecond::per(Millisecond) as i128)
+    }
+
+    /// Construct an `OffsetDateTime` from the provided Unix timestamp (in microseconds). Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(0),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(1_546_300_800_123_456),
+    ///     Ok(datetime!(2019-01-01 0:00:00.123_456 UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(-1),
+    ///     Ok(datetime!(1969-12-31 23:59:59.999_999 UTC)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_micros(
+        timestamp: i128,
+    ) -> Result<Self, error::ComponentRange> {
+        // Saturating is fine here, as any saturated value is out of range regardless.
+        Self::from_unix_timestamp_nanos(
+            timestamp.saturating_mul(Nanosecond::per(Microsecond) as i128),
+        )
+    }
+
+    /// Create an `OffsetDateTime` from the provided [Julian
+    /// date](https://en.wikipedia.org/wiki/Julian_day), which may include a fraction of the day.
+    /// This is the inverse of [`OffsetDateTime::to_julian_date`]. Calling `.offset()` on the
+    /// resulting value is guaranteed to return UTC.
+    ///
+    /// An `f64` is only able to represent a contemporary Julian date to within approximately 40
+    /// microseconds, so the result should not be relied upon to be more precise than that. Any
+    /// part of the value below one nanosecond is truncated. An error is returned if the value is
+    /// not finite or the resulting value is out of range.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00 UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_date(2_440_587.75),
+    ///     Ok(datetime!(1970-01-01 6:00 UTC)),
+    /// );
+    /// assert!(OffsetDateTime::from_julian_date(f64::NAN).is_err());
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ConversionRange> {
+        // This also rejects NaN, as it is not contained in any range.
+        if !(i64::MIN as f64..i64::MAX as f64).contains(&julian_date) {
+            return Err(error::ConversionRange);
+        }
+
+        // Separating the whole days is exact, preserving the precision of the fraction.
+        let whole_days = julian_date as i64;
+        let fraction = julian_date - whole_days as f64;
+        // Julian days begin at noon, whereas Unix days begin at midnight.
+        let nanos = (whole_days as i128 - UNIX_EPOCH_JULIAN_DAY as i128)
+            * Nanosecond::per(Day) as i128
+            + ((fraction + 0.5) * Nanosecond::per(Day) as f64) as i128;
+
+        Self::from_unix_timestamp_nanos(nanos).map_err(|_| error::ConversionRange)
+    }
+
+    /// Create an `OffsetDateTime` in UTC from a [`SystemTime`], returning an error if the value is
+    /// out of range. Unlike the [`From`] implementation, this does not panic. Nanosecond precision
+    /// is preserved.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, SystemTime};
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH + Duration::from_nanos(1)),
+    ///     Ok(datetime!(1970-01-01 0:00:00.000_000_001 UTC)),
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_system_time(system_time: SystemTime) -> Result<Self, error::ConversionRange> {
+        match system_time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => Self::UNIX_EPOCH.checked_add(duration.try_into()?),
+            Err(err) => Self::UNIX_EPOCH.checked_sub(err.duration().try_into()?),
+        }
+        .ok_or(error::ConversionRange)
+    }
     // endregion constructors
 
     // region: getters
@@ -426,6 +589,79 @@ impl OffsetDateTime {
         self.unix_timestamp() as i128 * Nanosecond::per(Second) as i128 + self.nanosecond() as i128
     }
 
+    /// Get the Unix timestamp in milliseconds.
+    ///
+    /// Any sub-millisecond component is discarded by flooring, so values before the Unix epoch are
+    /// rounded towards negative infinity rather than towards zero.
+    ///
+    /// ```rust
+    /// use time_macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_millis(), 0);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 0:00:00.123_456 -1).unix_timestamp_millis(),
+    ///     3_600_123,
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1969-12-31 23:59:59.999_5 UTC).unix_timestamp_millis(),
+    ///     -1,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_millis(self) -> i64 {
+        div_floor!(
+            self.unix_timestamp_nanos(),
+            Nanosecond::per(Millisecond) as i128
+        ) as _
+    }
+
+    /// Get the Unix timestamp in microseconds.
+    ///
+    /// Any sub-microsecond component is discarded by flooring, so values before the Unix epoch are
+    /// rounded towards negative infinity rather than towards zero. An `i128` is returned, as the
+    /// value may not fit in an `i64` when the `large-dates` feature is enabled.
+    ///
+    /// ```rust
+    /// use time_macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_micros(), 0);
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 0:00:00.123_456_789 -1).unix_timestamp_micros(),
+    ///     3_600_123_456,
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1969-12-31 23:59:59.999_999_5 UTC).unix_timestamp_micros(),
+    ///     -1,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_micros(self) -> i128 {
+        div_floor!(
+            self.unix_timestamp_nanos(),
+            Nanosecond::per(Microsecond) as i128
+        )
+    }
+
+    /// Convert the `OffsetDateTime` to a [`SystemTime`], returning an error if the value cannot be
+    /// represented on the current platform. Unlike the [`From`] implementation, this does not
+    /// panic. Nanosecond precision is preserved if the platform supports it.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, SystemTime};
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 1:00:00.000_000_001 +1).try_to_system_time(),
+    ///     Ok(SystemTime::UNIX_EPOCH + Duration::from_nanos(1)),
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_to_system_time(self) -> Result<SystemTime, error::ConversionRange> {
+        let duration = self - Self::UNIX_EPOCH;
+
+        if duration.is_negative() {
+            SystemTime::UNIX_EPOCH.checked_sub(duration.unsigned_abs())
+        } else {
+            SystemTime::UNIX_EPOCH.checked_add(duration.unsigned_abs())
+        }
+        .ok_or(error::ConversionRange)
+    }
+
     /// Get the [`PrimitiveDateTime`] in the stored offset.
     const fn date_time(self) -> PrimitiveDateTime {
         self.local_date_time
@@ -664,6 +900,28 @@ impl OffsetDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.date().to_julian_day()
     }
+
+    /// Get the [Julian date](https://en.wikipedia.org/wiki/Julian_day) of the instant, including
+    /// the fraction of the day. Julian dates begin at noon UTC, so midnight UTC is at a fraction of
+    /// one half. Unlike [`OffsetDateTime::to_julian_day`], the offset is taken into account.
+    ///
+    /// The value is computed from UTC and does not account for leap seconds; it is _not_ based on
+    /// Terrestrial Time. As an `f64` is returned, the resolution is approximately 40 microseconds
+    /// for contemporary dates.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00 UTC).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 0:00 UTC).to_julian_date(), 2_451_544.5);
+    /// assert_eq!(datetime!(2000-01-01 18:00 +6).to_julian_date(), 2_451_545.0);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        let nanos = self.unix_timestamp_nanos();
+        let days = nanos.div_euclid(Nanosecond::per(Day) as _);
+        let nanos_within_day = nanos.rem_euclid(Nanosecond::per(Day) as _);
+        ((UNIX_EPOCH_JULIAN_DAY as i128 + days) as f64 - 0.5)
+            + nanos_within_day as f64 / Nanosecond::per(Day) as f64
+    }
     // endregion date getters
 
     // region: time getters
@@ -873,6 +1131,48 @@ impl OffsetDateTime {
     pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
         Some(const_try_opt!(self.date_time().checked_sub(duration)).assume_offset(self.offset()))
     }
+
+    /// Computes `self + months`, returning `None` if an overflow occurred. The time and offset are
+    /// unchanged.
+    ///
+    /// The year and month are advanced by the given number of calendar months. If the day does not
+    /// exist in the resulting month, it is clamped to the last day of that month. As such, adding
+    /// one month to January 31 results in the last day of February.
+    ///
+    /// ```
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-31 15:30 +10).checked_add_months(1),
+    ///     Some(datetime!(2020-02-29 15:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        Some(
+            const_try_opt!(self.date_time().checked_add_months(months))
+                .assume_offset(self.offset()),
+        )
+    }
+
+    /// Computes `self - months`, returning `None` if an overflow occurred. The time and offset are
+    /// unchanged.
+    ///
+    /// The year and month are moved back by the given number of calendar months. If the day does
+    /// not exist in the resulting month, it is clamped to the last day of that month. As such,
+    /// subtracting one month from March 31 results in the last day of February.
+    ///
+    /// ```
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2021-03-31 15:30 +10).checked_sub_months(1),
+    ///     Some(datetime!(2021-02-28 15:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_sub_months(self, months: i32) -> Option<Self> {
+        Some(
+            const_try_opt!(self.date_time().checked_sub_months(months))
+                .assume_offset(self.offset()),
+        )
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -986,6 +1286,113 @@ impl OffsetDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: differences
+    /// Returns the amount of time elapsed from another `OffsetDateTime` to this one. This is
+    /// negative if `earlier` is actually later than `self`, and is equivalent to `self - earlier`.
+    ///
+    /// The result is the difference between the two instants. Their offsets do not affect it, so
+    /// values that represent the same instant in different offsets are zero apart.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 12:00 UTC).duration_since(datetime!(2024-01-01 11:30 UTC)),
+    ///     30.minutes()
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 12:00 +1).duration_since(datetime!(2024-01-01 11:00 UTC)),
+    ///     0.seconds()
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 11:30 UTC).duration_since(datetime!(2024-01-01 12:00 UTC)),
+    ///     (-30).minutes()
+    /// );
+    /// ```
+    pub fn duration_since(self, earlier: Self) -> Duration {
+        self - earlier
+    }
+
+    /// Returns the amount of time elapsed from another `OffsetDateTime` to this one, or `None` if
+    /// that value is later than this one. As with [`OffsetDateTime::duration_since`], the offsets
+    /// do not affect the result.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 12:00 +1).checked_duration_since(datetime!(2024-01-01 10:30 UTC)),
+    ///     Some(30.minutes())
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 10:30 UTC).checked_duration_since(datetime!(2024-01-01 12:00 +1)),
+    ///     None
+    /// );
+    /// ```
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        if self < earlier {
+            None
+        } else {
+            Some(self - earlier)
+        }
+    }
+
+    /// Returns the absolute difference between two `OffsetDateTime`s. The result is never
+    /// negative, and the offsets do not affect it.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// let a = datetime!(2024-01-01 12:00 +1);
+    /// let b = datetime!(2024-01-01 10:30 UTC);
+    /// assert_eq!(a.abs_diff(b), 30.minutes());
+    /// assert_eq!(b.abs_diff(a), 30.minutes());
+    /// ```
+    pub fn abs_diff(self, other: Self) -> Duration {
+        if self < other {
+            other - self
+        } else {
+            self - other
+        }
+    }
+    // endregion: differences
+
+    // region: clamping
+    /// Restrict the `OffsetDateTime` to the inclusive range `min..=max`. Unlike [`Ord::clamp`],
+    /// this method can be used in `const` contexts.
+    ///
+    /// Values are compared by the instant they represent, regardless of their offsets. The value
+    /// returned is one of `self`, `min`, or `max` with its offset unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `min` is later than `max`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let (min, max) = (datetime!(2020-01-01 0:00 UTC), datetime!(2020-01-01 12:00 UTC));
+    /// assert_eq!(datetime!(2020-01-01 0:30 +1).clamp(min, max), min);
+    /// assert_eq!(datetime!(2020-01-01 12:30 +1).clamp(min, max), datetime!(2020-01-01 12:30 +1));
+    /// assert_eq!(datetime!(2020-01-01 12:30 -1).clamp(min, max), max);
+    /// ```
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        let value = self.unix_timestamp_nanos();
+        let min_value = min.unix_timestamp_nanos();
+        let max_value = max.unix_timestamp_nanos();
+        if min_value > max_value {
+            crate::expect_failed("minimum must not be greater than maximum");
+        }
+
+        if value < min_value {
+            min
+        } else if value > max_value {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion clamping
 }
 
 // region: replacement
@@ -1054,12 +1461,21 @@ impl OffsetDateTime {
 
     /// Replace the offset. The date and time components remain unchanged.
     ///
+    /// Unlike [`to_offset`](Self::to_offset), which preserves the instant and adjusts the local
+    /// date and time, this preserves the local date and time and therefore changes the instant
+    /// being represented. This is useful when a local date and time was tagged with the wrong
+    /// offset.
+    ///
     /// ```rust
     /// # use time_macros::{datetime, offset};
     /// assert_eq!(
     ///     datetime!(2020-01-01 0:00 UTC).replace_offset(offset!(-5)),
     ///     datetime!(2020-01-01 0:00 -5)
     /// );
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 0:00 UTC).replace_offset(offset!(-5)).unix_timestamp(),
+    ///     datetime!(2020-01-01 5:00 UTC).unix_timestamp()
+    /// );
     /// ```
     #[must_use = "This method does not mutate the original `OffsetDateTime`."]
     pub const fn replace_offset(self, offset: UtcOffset) -> Self {
@@ -1252,6 +1668,69 @@ impl OffsetDateTime {
         )
     }
 
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing the result to a
+    /// [`fmt::Write`] implementor.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::datetime;
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///          sign:mandatory]:[offset_minute]",
+    /// )?;
+    /// let mut buf = String::new();
+    /// datetime!(2020-01-02 03:04:05 +06:07).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "2020-01-02 03:04:05 +06:07");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_fmt(
+            output,
+            Some(self.date()),
+            Some(self.time()),
+            Some(self.offset()),
+        )
+    }
+
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing the result to the start of a byte
+    /// slice. The number of bytes written is returned.
+    ///
+    /// If the slice is too small to hold the formatted value, [`Format::BufferTooSmall`] is
+    /// returned. The contents of the slice are unspecified in this situation.
+    ///
+    /// [`Format::BufferTooSmall`]: error::Format::BufferTooSmall
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::datetime;
+    /// let format = format_description::parse("[year]-[month]-[day] [offset_hour sign:mandatory]")?;
+    /// let mut buf = [0; 32];
+    /// let len = datetime!(2020-01-02 03:04:05 +06:07).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02 +06");
+    /// assert!(datetime!(2020-01-02 03:04:05 +06:07)
+    ///     .format_into_slice(&mut buf[..13], &format)
+    ///     .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_slice(
+            output,
+            Some(self.date()),
+            Some(self.time()),
+            Some(self.offset()),
+        )
+    }
+
     /// Format the `OffsetDateTime` using the provided [format
     /// description](crate::format_description).
     ///
@@ -1298,6 +1777,105 @@ impl OffsetDateTime {
         description.parse_offset_date_time(input.as_bytes())
     }
 
+    /// Parse an `OffsetDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the value and the number of bytes
+    /// consumed.
+    ///
+    /// Unlike [`OffsetDateTime::parse`], input may remain after the value is parsed.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!(
+    ///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour \
+    ///          sign:mandatory]:[offset_minute]:[offset_second]"
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_partial("2020-01-02 03:04:05 +06:07:08 rest", &format)?,
+    ///     (datetime!(2020-01-02 03:04:05 +06:07:08), 29)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_partial(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, usize), error::Parse> {
+        let (parsed, consumed) = description.parse_partial(input.as_bytes())?;
+        Ok((parsed.try_into()?, consumed))
+    }
+
+    /// Parse an ISO 8601 time interval, returning its start and end.
+    ///
+    /// The input is two parts separated by `/`. Either part may be an `OffsetDateTime` in the
+    /// provided [format description](crate::format_description), while at most one may be an ISO
+    /// 8601 duration such as `P1DT2H`. This supports the `start/end`, `start/duration`, and
+    /// `duration/end` forms; the missing endpoint is computed by applying the duration to the
+    /// other. As with the `serde` support for ISO 8601 durations, years and months are not
+    /// accepted in the duration, as their length is not fixed. The duration may have a leading `+`
+    /// or `-` sign.
+    ///
+    /// The end is not required to be after the start. Validating this is left to the caller.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/2024-01-02T00:00:00Z", &Rfc3339)?,
+    ///     (datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-02 0:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_interval("2024-01-01T00:00:00Z/P1DT12H", &Rfc3339)?,
+    ///     (datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-02 12:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_interval("PT1H/2024-01-01T00:00:00Z", &Rfc3339)?,
+    ///     (datetime!(2023-12-31 23:00 UTC), datetime!(2024-01-01 0:00 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_interval(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, Self), error::Parse> {
+        /// The error returned when a duration is invalid or cannot be applied.
+        const INVALID_DURATION: error::Parse = error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("duration"),
+        );
+
+        let (start, end) = input
+            .split_once('/')
+            .ok_or(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidLiteral,
+            ))?;
+
+        let is_duration = |part: &str| {
+            part.strip_prefix(|c| c == '+' || c == '-')
+                .unwrap_or(part)
+                .starts_with('P')
+        };
+
+        match (is_duration(start), is_duration(end)) {
+            (false, false) => Ok((
+                Self::parse(start, description)?,
+                Self::parse(end, description)?,
+            )),
+            (false, true) => {
+                let start = Self::parse(start, description)?;
+                let duration = Duration::parse_iso8601(end).ok_or(INVALID_DURATION)?;
+                let end = start.checked_add(duration).ok_or(INVALID_DURATION)?;
+                Ok((start, end))
+            }
+            (true, false) => {
+                let end = Self::parse(end, description)?;
+                let duration = Duration::parse_iso8601(start).ok_or(INVALID_DURATION)?;
+                let start = end.checked_sub(duration).ok_or(INVALID_DURATION)?;
+                Ok((start, end))
+            }
+            (true, true) => Err(INVALID_DURATION),
+        }
+    }
+
     /// A helper method to check if the `OffsetDateTime` is a valid representation of a leap second.
     /// Leap seconds, when parsed, are represented as the preceding nanosecond. However, leap
     /// seconds can only occur as the last second of a month UTC.
@@ -1465,6 +2043,9 @@ impl SubAssign<StdDuration> for OffsetDateTime {
 impl Sub for OffsetDateTime {
     type Output = Duration;
 
+    /// The difference between the two instants. The offsets of the values do not affect the
+    /// result.
+    ///
     /// # Panics
     ///
     /// This may panic if an overflow occurs.
@@ -1560,6 +2141,10 @@ impl PartialOrd<OffsetDateTime> for SystemTime {
 
 #[cfg(feature = "std")]
 impl From<SystemTime> for OffsetDateTime {
+    /// # Panics
+    ///
+    /// This may panic if the value is out of range. Use
+    /// [`OffsetDateTime::try_from_system_time`] to handle this case.
     fn from(system_time: SystemTime) -> Self {
         match system_time.duration_since(SystemTime::UNIX_EPOCH) {
             Ok(duration) => Self::UNIX_EPOCH + duration,
@@ -1570,6 +2155,10 @@ impl From<SystemTime> for OffsetDateTime {
 
 #[cfg(feature = "std")]
 impl From<OffsetDateTime> for SystemTime {
+    /// # Panics
+    ///
+    /// This may panic if the value cannot be represented on the current platform. Use
+    /// [`OffsetDateTime::try_to_system_time`] to handle this case.
     fn from(datetime: OffsetDateTime) -> Self {
         let duration = datetime - OffsetDateTime::UNIX_EPOCH;
 
@@ -5,8 +5,8 @@ pub(crate) mod rfc;
 use num_conv::prelude::*;
 
 use crate::format_description::modifier::Padding;
-use crate::parsing::shim::{Integer, IntegerParseBytes};
 use crate::parsing::ParsedItem;
+use crate::parsing::shim::{Integer, IntegerParseBytes};
 
 /// Parse a "+" or "-" sign. Returns the ASCII byte representing the sign, if present.
 pub(crate) const fn sign(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
@@ -27,19 +27,27 @@ pub(crate) fn first_match<'a, T>(
             if case_sensitive {
                 Some(ParsedItem(input.strip_prefix(expected)?, t))
             } else {
-                let n = expected.len();
-                if n <= input.len() {
-                    let (head, tail) = input.split_at(n);
-                    if head.eq_ignore_ascii_case(expected) {
-                        return Some(ParsedItem(tail, t));
-                    }
-                }
-                None
+                Some(ParsedItem(string_ci(expected)(input)?.0, t))
             }
         })
     }
 }
 
+/// Consume the provided string, ignoring ASCII case. The consumed input is returned, preserving
+/// its original casing.
+pub(crate) fn string_ci<'a, 'b>(
+    expected: &'b [u8],
+) -> impl Fn(&'a [u8]) -> Option<ParsedItem<'a, &'a [u8]>> + 'b {
+    move |input| {
+        if expected.len() > input.len() {
+            return None;
+        }
+        let (head, tail) = input.split_at(expected.len());
+        head.eq_ignore_ascii_case(expected)
+            .then_some(ParsedItem(tail, head))
+    }
+}
+
 /// Consume zero or more instances of the provided parser. The parser must return the unit value.
 pub(crate) fn zero_or_more<'a, P: Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>>>(
     parser: P,
@@ -165,6 +173,47 @@ pub(crate) const fn any_digit(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
     }
 }
 
+/// Consume exactly one non-ASCII decimal digit, returning the equivalent ASCII digit.
+pub(crate) fn unicode_digit(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
+    /// The code point of zero in each supported set of digits. The following nine code points are
+    /// the remaining digits in order.
+    const ZEROS: [u32; 19] = [
+        0x0660, // Arabic-Indic
+        0x06F0, // Extended Arabic-Indic
+        0x07C0, // NKo
+        0x0966, // Devanagari
+        0x09E6, // Bengali
+        0x0A66, // Gurmukhi
+        0x0AE6, // Gujarati
+        0x0B66, // Oriya
+        0x0BE6, // Tamil
+        0x0C66, // Telugu
+        0x0CE6, // Kannada
+        0x0D66, // Malayalam
+        0x0E50, // Thai
+        0x0ED0, // Lao
+        0x0F20, // Tibetan
+        0x1040, // Myanmar
+        0x17E0, // Khmer
+        0x1810, // Mongolian
+        0xFF10, // Fullwidth
+    ];
+
+    let len = match input.first()? {
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => return None,
+    };
+    let c = core::str::from_utf8(input.get(..len)?)
+        .ok()?
+        .chars()
+        .next()?;
+    let value = ZEROS
+        .iter()
+        .find_map(|&zero| (c as u32).checked_sub(zero).filter(|&value| value < 10))?;
+    Some(ParsedItem(&input[len..], b'0' + value.truncate::<u8>()))
+}
+
 /// Consume exactly one of the provided ASCII characters.
 pub(crate) fn ascii_char<const CHAR: u8>(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
     debug_assert!(CHAR.is_ascii_graphic() || CHAR.is_ascii_whitespace());
@@ -9,7 +9,8 @@ use crate::format_description::modifier;
 #[cfg(feature = "large-dates")]
 use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits, opt, sign,
+    any_digit, ascii_char, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits,
+    opt, sign,
 };
 use crate::parsing::ParsedItem;
 use crate::{Month, Weekday};
@@ -27,8 +28,13 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
             let ParsedItem(input, year) =
                 n_to_m_digits_padded::<4, 6, u32>(modifiers.padding)(input)?;
             match sign {
+                // An era-based year is always positive, with the era indicated separately.
+                Some(_) if modifiers.era_based => None,
+                None if modifiers.era_based && year == 0 => None,
                 Some(b'-') => Some(ParsedItem(input, -year.cast_signed())),
-                None if modifiers.sign_is_mandatory || year >= 10_000 => None,
+                None if !modifiers.era_based && (modifiers.sign_is_mandatory || year >= 10_000) => {
+                    None
+                }
                 _ => Some(ParsedItem(input, year.cast_signed())),
             }
         }
@@ -38,17 +44,42 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
     }
 }
 
+/// Consume the first of the provided names that matches the input, ignoring ASCII case. If
+/// `case_sensitive` is set, the consumed input must additionally use the provided casing.
+fn name_with_case<'a, T>(
+    input: &'a [u8],
+    options: impl IntoIterator<Item = (&'a [u8], T)>,
+    case: modifier::NameCase,
+    case_sensitive: bool,
+) -> Option<ParsedItem<'a, T>> {
+    let ParsedItem(remaining, value) = first_match(options, false)(input)?;
+    if !case_sensitive {
+        return Some(ParsedItem(remaining, value));
+    }
+
+    let name = &input[..input.len() - remaining.len()];
+    let has_case = match case {
+        modifier::NameCase::Title => {
+            !name[0].is_ascii_lowercase() && !name[1..].iter().any(u8::is_ascii_uppercase)
+        }
+        modifier::NameCase::Upper => !name.iter().any(u8::is_ascii_lowercase),
+        modifier::NameCase::Lower => !name.iter().any(u8::is_ascii_uppercase),
+    };
+    has_case.then_some(ParsedItem(remaining, value))
+}
+
 /// Parse the "month" component of a `Date`.
 pub(crate) fn parse_month(
     input: &[u8],
     modifiers: modifier::Month,
 ) -> Option<ParsedItem<'_, Month>> {
     use Month::*;
-    let ParsedItem(remaining, value) = first_match(
+    name_with_case(
+        input,
         match modifiers.repr {
             modifier::MonthRepr::Numerical => {
-                return exactly_n_digits_padded::<2, _>(modifiers.padding)(input)?
-                    .flat_map(|n| Month::from_number(n).ok());
+                return exactly_n_digits_padded::<2, NonZeroU8>(modifiers.padding)(input)?
+                    .flat_map(|n| Month::from_u8(n.get()).ok());
             }
             modifier::MonthRepr::Long => [
                 (b"January".as_slice(), January),
@@ -79,9 +110,9 @@ pub(crate) fn parse_month(
                 (b"Dec".as_slice(), December),
             ],
         },
+        modifiers.case,
         modifiers.case_sensitive,
-    )(input)?;
-    Some(ParsedItem(remaining, value))
+    )
 }
 
 /// Parse the "week number" component of a `Date`.
@@ -97,7 +128,8 @@ pub(crate) fn parse_weekday(
     input: &[u8],
     modifiers: modifier::Weekday,
 ) -> Option<ParsedItem<'_, Weekday>> {
-    first_match(
+    name_with_case(
+        input,
         match (modifiers.repr, modifiers.one_indexed) {
             (modifier::WeekdayRepr::Short, _) => [
                 (b"Mon".as_slice(), Weekday::Monday),
@@ -154,8 +186,9 @@ pub(crate) fn parse_weekday(
                 (b"7".as_slice(), Weekday::Sunday),
             ],
         },
+        modifiers.case,
         modifiers.case_sensitive,
-    )(input)
+    )
 }
 
 /// Parse the "ordinal" component of a `Date`.
@@ -173,6 +206,34 @@ pub(crate) fn parse_day(
 ) -> Option<ParsedItem<'_, NonZeroU8>> {
     exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
 }
+
+/// Indicate whether the year is "BC" or "AD".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Era {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Bc,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Ad,
+}
+
+/// Parse the "era" component of a `Date`. This is always case-insensitive.
+pub(crate) fn parse_era(input: &[u8], _: modifier::Era) -> Option<ParsedItem<'_, Era>> {
+    first_match(
+        [(b"BC".as_slice(), Era::Bc), (b"AD".as_slice(), Era::Ad)],
+        false,
+    )(input)
+}
+
+/// Parse the "quarter" component of a `Date`.
+pub(crate) fn parse_quarter(
+    input: &[u8],
+    modifiers: modifier::Quarter,
+) -> Option<ParsedItem<'_, NonZeroU8>> {
+    match modifiers.repr {
+        modifier::QuarterRepr::Numerical => exactly_n_digits::<1, NonZeroU8>(input)
+            .filter(|ParsedItem(_, quarter)| quarter.get() <= 4),
+    }
+}
 // endregion date components
 
 // region: time components
@@ -258,6 +319,24 @@ pub(crate) fn parse_subsecond(
         }
     })
 }
+
+/// Parse the "seconds of day" component of a `Time`.
+pub(crate) fn parse_seconds_of_day(
+    input: &[u8],
+    modifiers: modifier::SecondsOfDay,
+) -> Option<ParsedItem<'_, u32>> {
+    exactly_n_digits_padded::<5, _>(modifiers.padding)(input)
+}
+
+/// Parse the fractional part of the "hour" or "minute" component of a `Time`, including the
+/// leading decimal point. The value is in billionths of the component's unit.
+pub(crate) fn parse_fraction(
+    input: &[u8],
+    digits: modifier::SubsecondDigits,
+) -> Option<ParsedItem<'_, u32>> {
+    let input = ascii_char::<b'.'>(input)?.into_inner();
+    parse_subsecond(input, modifier::Subsecond { digits })
+}
 // endregion time components
 
 // region: offset components
@@ -268,6 +347,14 @@ pub(crate) fn parse_offset_hour(
     input: &[u8],
     modifiers: modifier::OffsetHour,
 ) -> Option<ParsedItem<'_, (i8, bool)>> {
+    if modifiers.utc_alias_allowed {
+        if let Some(ParsedItem(input, ())) =
+            first_match([(b"UTC".as_slice(), ()), (b"GMT".as_slice(), ())], false)(input)
+        {
+            return Some(ParsedItem(input, (0, false)));
+        }
+    }
+
     let ParsedItem(input, sign) = opt(sign)(input);
     let ParsedItem(input, hour) = exactly_n_digits_padded::<2, u8>(modifiers.padding)(input)?;
     match sign {
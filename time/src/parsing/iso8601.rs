@@ -124,6 +124,11 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
 
             let ParsedItem(mut input, hour) = float(input).ok_or(InvalidComponent("hour"))?;
             match hour {
+                (24, None) if Self::END_OF_DAY_ALLOWED => {
+                    // The remaining components are checked to be zero after parsing.
+                    parsed.set_hour_24(0).ok_or(InvalidComponent("hour"))?;
+                    parsed.is_end_of_day = true;
+                }
                 (hour, None) => parsed.set_hour_24(hour).ok_or(InvalidComponent("hour"))?,
                 (hour, Some(fractional_part)) => {
                     *parsed = parsed
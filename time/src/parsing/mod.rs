@@ -7,8 +7,10 @@ pub(crate) mod parsable;
 mod parsed;
 pub(crate) mod shim;
 
-pub use self::parsable::Parsable;
-pub use self::parsed::Parsed;
+pub use self::parsable::{
+    AllowLeapSeconds, LenientWhitespace, Parsable, RejectConflicts, UnicodeDigits,
+};
+pub use self::parsed::{Parsed, ParsedDefaults};
 
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug)]
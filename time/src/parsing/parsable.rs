@@ -1,12 +1,15 @@
 //! A trait that can be used to parse an item from an input.
 
+use core::num::NonZeroU8;
 use core::ops::Deref;
 
 use num_conv::prelude::*;
 
 use crate::error::TryFromParsed;
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use crate::format_description::well_known::{
+    HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc3339Bounded, Rfc3339Fixed,
+};
 use crate::format_description::BorrowedFormatItem;
 #[cfg(feature = "alloc")]
 use crate::format_description::OwnedFormatItem;
@@ -17,7 +20,40 @@ use crate::{error, Date, Month, OffsetDateTime, Time, UtcOffset, Weekday};
 /// A type that can be parsed.
 #[cfg_attr(docsrs, doc(notable_trait))]
 #[doc(alias = "Parseable")]
-pub trait Parsable: sealed::Sealed {}
+pub trait Parsable: sealed::Sealed {
+    /// Parse from the start of the input, returning the information parsed along with the input
+    /// that remains afterwards.
+    ///
+    /// Unlike methods such as [`OffsetDateTime::parse`], the input does not need to be fully
+    /// consumed. This allows the remaining input to be passed onward, such as when each record of
+    /// a log begins with a timestamp. The [`Parsed`] value can then be converted into the desired
+    /// type with `TryFrom`.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::parsing::Parsable;
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// let log = "2021-01-02T03:04:05Z started\n2021-01-02T03:04:06Z finished\n";
+    /// let mut records = Vec::new();
+    /// for line in log.lines() {
+    ///     let (parsed, message) = Rfc3339.parse_prefix(line.as_bytes())?;
+    ///     records.push((OffsetDateTime::try_from(parsed)?, message));
+    /// }
+    /// assert_eq!(
+    ///     records,
+    ///     [
+    ///         (datetime!(2021-01-02 03:04:05 UTC), b" started".as_slice()),
+    ///         (datetime!(2021-01-02 03:04:06 UTC), b" finished".as_slice()),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    fn parse_prefix<'a>(&self, input: &'a [u8]) -> Result<(Parsed, &'a [u8]), error::Parse> {
+        let (parsed, consumed) = self.parse_partial(input)?;
+        Ok((parsed, &input[consumed..]))
+    }
+}
 impl Parsable for BorrowedFormatItem<'_> {}
 impl Parsable for [BorrowedFormatItem<'_>] {}
 #[cfg(feature = "alloc")]
@@ -25,9 +61,120 @@ impl Parsable for OwnedFormatItem {}
 #[cfg(feature = "alloc")]
 impl Parsable for [OwnedFormatItem] {}
 impl Parsable for Rfc2822 {}
+impl Parsable for HttpDate {}
 impl Parsable for Rfc3339 {}
+impl Parsable for Rfc3339Bounded {}
+impl Parsable for Rfc3339Fixed {}
 impl<const CONFIG: EncodedConfig> Parsable for Iso8601<CONFIG> {}
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
+impl<T: Parsable + ?Sized> Parsable for LenientWhitespace<T> {}
+impl<T: Parsable + ?Sized> Parsable for RejectConflicts<T> {}
+impl<T: Parsable + ?Sized> Parsable for AllowLeapSeconds<T> {}
+impl<T: Parsable + ?Sized> Parsable for UnicodeDigits<T> {}
+
+/// A format description that parses with lenient whitespace.
+///
+/// Any run of ASCII whitespace in a literal of the wrapped format description matches one or more
+/// ASCII whitespace characters in the input, rather than having to match exactly. This has no
+/// effect on well-known formats. Strict matching remains the default when this wrapper is not
+/// used.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::parsing::LenientWhitespace;
+/// # use time::Date;
+/// # use time_macros::date;
+/// let format = format_description::parse("[year] [month] [day]")?;
+/// assert!(Date::parse("2024  01\t02", &format).is_err());
+/// assert_eq!(
+///     Date::parse("2024  01\t02", &LenientWhitespace(&format))?,
+///     date!(2024 - 01 - 02)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenientWhitespace<T: ?Sized>(pub T);
+
+/// A format description that rejects conflicting values for the same component.
+///
+/// By default, when a component appears more than once in a format description, the value parsed
+/// last is used. When wrapped in this type, parsing instead fails with
+/// [`InvalidComponent`](crate::error::ParseFromDescription::InvalidComponent) if a component is
+/// parsed with a value differing from the one already present. Repeating the same value is
+/// permitted. Within an `[optional]` item, a conflict causes the item to be skipped, as with any
+/// other error.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::parsing::RejectConflicts;
+/// # use time::Date;
+/// # use time_macros::date;
+/// let format = format_description::parse("[year]-[month]-[day] ([year])")?;
+/// assert_eq!(
+///     Date::parse("2024-01-02 (2023)", &format)?,
+///     date!(2023 - 01 - 02)
+/// );
+/// assert!(Date::parse("2024-01-02 (2023)", &RejectConflicts(&format)).is_err());
+/// assert_eq!(
+///     Date::parse("2024-01-02 (2024)", &RejectConflicts(&format))?,
+///     date!(2024 - 01 - 02)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RejectConflicts<T: ?Sized>(pub T);
+
+/// A format description that permits a leap second to be parsed.
+///
+/// By default, a second of `60` is rejected when the parsed value is converted to an
+/// [`OffsetDateTime`]. When wrapped in this type, it is instead accepted and treated as the
+/// nearest preceding moment that can be represented, which is `59.999_999_999` seconds. This
+/// preserves the ordering of instants. As leap seconds only occur at the end of a month UTC, a
+/// second of `60` at any other moment is still rejected. This matches the handling of leap seconds
+/// in well-known formats such as [`Rfc3339`]. Other types cannot verify that a leap second is
+/// valid, so they continue to reject it.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::parsing::AllowLeapSeconds;
+/// # use time::OffsetDateTime;
+/// # use time_macros::datetime;
+/// let format = format_description::parse(
+///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]",
+/// )?;
+/// assert!(OffsetDateTime::parse("2016-12-31 23:59:60 +00", &format).is_err());
+/// assert_eq!(
+///     OffsetDateTime::parse("2016-12-31 23:59:60 +00", &AllowLeapSeconds(&format))?,
+///     datetime!(2016-12-31 23:59:59.999_999_999 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllowLeapSeconds<T: ?Sized>(pub T);
+
+/// A format description that accepts non-ASCII decimal digits.
+///
+/// Components of the wrapped format description accept the decimal digits of common non-Latin
+/// scripts, such as Arabic-Indic, Devanagari, and Thai, as well as full-width digits. Each is
+/// treated as the equivalent ASCII digit, and ASCII digits continue to be accepted. Digits in
+/// literals must still match exactly. This has no effect on well-known formats. Only ASCII digits
+/// are accepted when this wrapper is not used.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::parsing::UnicodeDigits;
+/// # use time::Date;
+/// # use time_macros::date;
+/// let format = format_description::parse("[year]-[month]-[day]")?;
+/// assert!(Date::parse("٢٠٢٤-٠١-٠٢", &format).is_err());
+/// assert_eq!(
+///     Date::parse("٢٠٢٤-٠١-٠٢", &UnicodeDigits(&format))?,
+///     date!(2024 - 01 - 02)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeDigits<T: ?Sized>(pub T);
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
@@ -62,6 +209,15 @@ mod sealed {
             }
         }
 
+        /// Parse the item into a new [`Parsed`] struct, returning the number of bytes consumed.
+        ///
+        /// Unlike [`parse`](Self::parse), any characters that remain after parsing are ignored.
+        fn parse_partial(&self, input: &[u8]) -> Result<(Parsed, usize), error::Parse> {
+            let mut parsed = Parsed::new();
+            let remaining = self.parse_into(input, &mut parsed)?;
+            Ok((parsed, input.len() - remaining.len()))
+        }
+
         /// Parse a [`Date`] from the format description.
         fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
@@ -147,6 +303,56 @@ where
         self.deref().parse_into(input, parsed)
     }
 }
+/// Parse `item` with the flag returned by `flag` set, restoring its previous value afterwards.
+fn parse_with_flag<'a, T: sealed::Sealed + ?Sized>(
+    item: &T,
+    flag: fn(&mut Parsed) -> &mut bool,
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Parse> {
+    let previous = core::mem::replace(flag(parsed), true);
+    let result = item.parse_into(input, parsed);
+    *flag(parsed) = previous;
+    result
+}
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for LenientWhitespace<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_with_flag(&self.0, |p| &mut p.lenient_whitespace, input, parsed)
+    }
+}
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for RejectConflicts<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_with_flag(&self.0, |p| &mut p.reject_conflicts, input, parsed)
+    }
+}
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for AllowLeapSeconds<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        // The flag is left set, as it is needed when converting to an `OffsetDateTime`.
+        parsed.leap_second_allowed = true;
+        self.0.parse_into(input, parsed)
+    }
+}
+impl<T: sealed::Sealed + ?Sized> sealed::Sealed for UnicodeDigits<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_with_flag(&self.0, |p| &mut p.unicode_digits, input, parsed)
+    }
+}
 // endregion custom formats
 
 // region: well-known formats
@@ -494,234 +700,461 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
-impl sealed::Sealed for Rfc3339 {
+impl sealed::Sealed for HttpDate {
     fn parse_into<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::format_description::modifier::Padding;
         use crate::parsing::combinator::{
-            any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
+            ascii_char, exactly_n_digits, exactly_n_digits_padded, first_match,
         };
 
-        let dash = ascii_char::<b'-'>;
-        let colon = ascii_char::<b':'>;
-
-        let input = exactly_n_digits::<4, u32>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_year(value.cast_signed())))
-            .ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
+        /// Parse the abbreviated name of a month.
+        fn month<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+            Ok(first_match(
+                [
+                    (b"Jan".as_slice(), Month::January),
+                    (b"Feb".as_slice(), Month::February),
+                    (b"Mar".as_slice(), Month::March),
+                    (b"Apr".as_slice(), Month::April),
+                    (b"May".as_slice(), Month::May),
+                    (b"Jun".as_slice(), Month::June),
+                    (b"Jul".as_slice(), Month::July),
+                    (b"Aug".as_slice(), Month::August),
+                    (b"Sep".as_slice(), Month::September),
+                    (b"Oct".as_slice(), Month::October),
+                    (b"Nov".as_slice(), Month::November),
+                    (b"Dec".as_slice(), Month::December),
+                ],
+                true,
+            )(input)
             .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-            .ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
-        let input = ascii_char_ignore_case::<b'T'>(input)
-            .ok_or(InvalidLiteral)?
-            .into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-            .ok_or(InvalidComponent("second"))?;
-        let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
-            let ParsedItem(mut input, mut value) = any_digit(input)
-                .ok_or(InvalidComponent("subsecond"))?
-                .map(|v| (v - b'0').extend::<u32>() * 100_000_000);
-
-            let mut multiplier = 10_000_000;
-            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
-                value += (digit - b'0').extend::<u32>() * multiplier;
-                input = new_input;
-                multiplier /= 10;
+            .ok_or(InvalidComponent("month"))?)
+        }
+
+        /// Parse the time of day, formatted as `HH:MM:SS`.
+        fn time_of_day<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+            let colon = ascii_char::<b':'>;
+
+            let input = exactly_n_digits::<2, _>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+                .ok_or(InvalidComponent("hour"))?;
+            let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = exactly_n_digits::<2, _>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+                .ok_or(InvalidComponent("minute"))?;
+            let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = exactly_n_digits::<2, _>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+                .ok_or(InvalidComponent("second"))?;
+            Ok(input)
+        }
+
+        let space = ascii_char::<b' '>;
+        let comma = ascii_char::<b','>;
+        let hyphen = ascii_char::<b'-'>;
+
+        let day = |input, parsed: &mut Parsed| {
+            exactly_n_digits::<2, _>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                .ok_or(InvalidComponent("day"))
+        };
+        let four_digit_year = |input, parsed: &mut Parsed| {
+            exactly_n_digits::<4, u32>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_year(value.cast_signed())))
+                .ok_or(InvalidComponent("year"))
+        };
+
+        let long_weekday = first_match(
+            [
+                (b"Monday".as_slice(), Weekday::Monday),
+                (b"Tuesday".as_slice(), Weekday::Tuesday),
+                (b"Wednesday".as_slice(), Weekday::Wednesday),
+                (b"Thursday".as_slice(), Weekday::Thursday),
+                (b"Friday".as_slice(), Weekday::Friday),
+                (b"Saturday".as_slice(), Weekday::Saturday),
+                (b"Sunday".as_slice(), Weekday::Sunday),
+            ],
+            true,
+        )(input);
+
+        let (input, has_zone) = if let Some(item) = long_weekday {
+            // RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+            let input = item
+                .consume_value(|value| parsed.set_weekday(value))
+                .ok_or(InvalidComponent("weekday"))?;
+            let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = day(input, parsed)?;
+            let input = hyphen(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = month(input, parsed)?;
+            let input = hyphen(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = exactly_n_digits::<2, u32>(input)
+                .and_then(|item| {
+                    item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
+                        .map(|year| year.cast_signed())
+                        .consume_value(|value| parsed.set_year(value))
+                })
+                .ok_or(InvalidComponent("year"))?;
+            let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+            (time_of_day(input, parsed)?, true)
+        } else {
+            let input = first_match(
+                [
+                    (b"Mon".as_slice(), Weekday::Monday),
+                    (b"Tue".as_slice(), Weekday::Tuesday),
+                    (b"Wed".as_slice(), Weekday::Wednesday),
+                    (b"Thu".as_slice(), Weekday::Thursday),
+                    (b"Fri".as_slice(), Weekday::Friday),
+                    (b"Sat".as_slice(), Weekday::Saturday),
+                    (b"Sun".as_slice(), Weekday::Sunday),
+                ],
+                true,
+            )(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_weekday(value)))
+            .ok_or(InvalidComponent("weekday"))?;
+
+            if let Some(input) = comma(input) {
+                // IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+                let input = space(input.into_inner())
+                    .ok_or(InvalidLiteral)?
+                    .into_inner();
+                let input = day(input, parsed)?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = month(input, parsed)?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = four_digit_year(input, parsed)?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                (time_of_day(input, parsed)?, true)
+            } else {
+                // asctime: `Sun Nov  6 08:49:37 1994`
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = month(input, parsed)?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = exactly_n_digits_padded::<2, _>(Padding::Space)(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or(InvalidComponent("day"))?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = time_of_day(input, parsed)?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                (four_digit_year(input, parsed)?, false)
             }
+        };
 
-            parsed
-                .set_subsecond(value)
-                .ok_or(InvalidComponent("subsecond"))?;
-            input
+        let input = if has_zone {
+            let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+            input.strip_prefix(b"GMT").ok_or(InvalidLiteral)?
         } else {
             input
         };
 
-        // The RFC explicitly allows leap seconds.
-        parsed.leap_second_allowed = true;
-
-        if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
-            parsed
-                .set_offset_hour(0)
-                .ok_or(InvalidComponent("offset hour"))?;
-            parsed
-                .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
-            parsed
-                .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
-            return Ok(input);
-        }
-
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
-        let input = exactly_n_digits::<2, u8>(input)
-            .and_then(|item| {
-                item.filter(|&offset_hour| offset_hour <= 23)?
-                    .map(|offset_hour| {
-                        if offset_sign == b'-' {
-                            -offset_hour.cast_signed()
-                        } else {
-                            offset_hour.cast_signed()
-                        }
-                    })
-                    .consume_value(|value| parsed.set_offset_hour(value))
-            })
+        // HTTP-date is always in UTC.
+        parsed
+            .set_offset_hour(0)
             .ok_or(InvalidComponent("offset hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = exactly_n_digits::<2, u8>(input)
-            .and_then(|item| {
-                item.map(|offset_minute| {
-                    if offset_sign == b'-' {
-                        -offset_minute.cast_signed()
-                    } else {
-                        offset_minute.cast_signed()
-                    }
-                })
-                .consume_value(|value| parsed.set_offset_minute_signed(value))
-            })
+        parsed
+            .set_offset_minute_signed(0)
             .ok_or(InvalidComponent("offset minute"))?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or(InvalidComponent("offset second"))?;
+
+        // The RFC explicitly allows leap seconds.
+        parsed.leap_second_allowed = true;
 
         Ok(input)
     }
+}
 
-    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::{
-            any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
-        };
+impl sealed::Sealed for Rfc3339 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_rfc3339_into(input, parsed, None)
+    }
 
-        let dash = ascii_char::<b'-'>;
-        let colon = ascii_char::<b':'>;
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        parse_rfc3339_offset_date_time(input, None)
+    }
+}
 
-        let ParsedItem(input, year) =
-            exactly_n_digits::<4, u32>(input).ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, month) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, day) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("day"))?;
-        let input = ascii_char_ignore_case::<b'T'>(input)
-            .ok_or(InvalidLiteral)?
-            .into_inner();
-        let ParsedItem(input, hour) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, minute) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, mut second) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
-        let ParsedItem(input, mut nanosecond) =
-            if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
-                let ParsedItem(mut input, mut value) = any_digit(input)
-                    .ok_or(InvalidComponent("subsecond"))?
-                    .map(|v| (v - b'0').extend::<u32>() * 100_000_000);
-
-                let mut multiplier = 10_000_000;
-                while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
-                    value += (digit - b'0').extend::<u32>() * multiplier;
-                    input = new_input;
-                    multiplier /= 10;
-                }
+/// Parse an RFC 3339 timestamp into the provided [`Parsed`] struct. If `max_subsecond_digits` is
+/// present, a subsecond with more digits than this is rejected.
+fn parse_rfc3339_into<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    max_subsecond_digits: Option<u8>,
+) -> Result<&'a [u8], error::Parse> {
+    use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    use crate::parsing::combinator::{ascii_char, ascii_char_ignore_case, exactly_n_digits, sign};
+
+    let dash = ascii_char::<b'-'>;
+    let colon = ascii_char::<b':'>;
+
+    let input = exactly_n_digits::<4, u32>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_year(value.cast_signed())))
+        .ok_or(InvalidComponent("year"))?;
+    let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = exactly_n_digits::<2, NonZeroU8>(input)
+        .and_then(|item| item.flat_map(|value| Month::from_u8(value.get()).ok()))
+        .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+        .ok_or(InvalidComponent("month"))?;
+    let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+        .ok_or(InvalidComponent("day"))?;
+    let input = ascii_char_ignore_case::<b'T'>(input)
+        .ok_or(InvalidLiteral)?
+        .into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+        .ok_or(InvalidComponent("hour"))?;
+    let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+        .ok_or(InvalidComponent("minute"))?;
+    let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+        .ok_or(InvalidComponent("second"))?;
+    let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
+        parse_rfc3339_subsecond(input, max_subsecond_digits)?
+            .consume_value(|value| parsed.set_subsecond(value))
+            .ok_or(InvalidComponent("subsecond"))?
+    } else {
+        input
+    };
+
+    // The RFC explicitly allows leap seconds.
+    parsed.leap_second_allowed = true;
+
+    if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
+        parsed
+            .set_offset_hour(0)
+            .ok_or(InvalidComponent("offset hour"))?;
+        parsed
+            .set_offset_minute_signed(0)
+            .ok_or(InvalidComponent("offset minute"))?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or(InvalidComponent("offset second"))?;
+        return Ok(input);
+    }
 
-                ParsedItem(input, value)
-            } else {
-                ParsedItem(input, 0)
-            };
-        let ParsedItem(input, offset) = {
-            if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
-                ParsedItem(input, UtcOffset::UTC)
-            } else {
-                let ParsedItem(input, offset_sign) =
-                    sign(input).ok_or(InvalidComponent("offset hour"))?;
-                let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
-                    .and_then(|parsed| parsed.filter(|&offset_hour| offset_hour <= 23))
-                    .ok_or(InvalidComponent("offset hour"))?;
-                let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-                let ParsedItem(input, offset_minute) =
-                    exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
-                UtcOffset::from_hms(
+    let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+    let input = exactly_n_digits::<2, u8>(input)
+        .and_then(|item| {
+            item.filter(|&offset_hour| offset_hour <= 23)?
+                .map(|offset_hour| {
                     if offset_sign == b'-' {
                         -offset_hour.cast_signed()
                     } else {
                         offset_hour.cast_signed()
-                    },
-                    if offset_sign == b'-' {
-                        -offset_minute.cast_signed()
-                    } else {
-                        offset_minute.cast_signed()
-                    },
-                    0,
-                )
-                .map(|offset| ParsedItem(input, offset))
-                .map_err(|mut err| {
-                    // Provide the user a more accurate error.
-                    if err.name == "hours" {
-                        err.name = "offset hour";
-                    } else if err.name == "minutes" {
-                        err.name = "offset minute";
                     }
-                    err
                 })
-                .map_err(TryFromParsed::ComponentRange)?
-            }
-        };
+                .consume_value(|value| parsed.set_offset_hour(value))
+        })
+        .ok_or(InvalidComponent("offset hour"))?;
+    let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = exactly_n_digits::<2, u8>(input)
+        .and_then(|item| {
+            item.map(|offset_minute| {
+                if offset_sign == b'-' {
+                    -offset_minute.cast_signed()
+                } else {
+                    offset_minute.cast_signed()
+                }
+            })
+            .consume_value(|value| parsed.set_offset_minute_signed(value))
+        })
+        .ok_or(InvalidComponent("offset minute"))?;
 
-        if !input.is_empty() {
-            return Err(error::Parse::ParseFromDescription(
-                error::ParseFromDescription::UnexpectedTrailingCharacters,
-            ));
-        }
+    Ok(input)
+}
 
-        // The RFC explicitly permits leap seconds. We don't currently support them, so treat it as
-        // the preceding nanosecond. However, leap seconds can only occur as the last second of the
-        // month UTC.
-        let leap_second_input = if second == 60 {
-            second = 59;
-            nanosecond = 999_999_999;
-            true
+/// Parse an RFC 3339 timestamp directly into an [`OffsetDateTime`]. If `max_subsecond_digits` is
+/// present, a subsecond with more digits than this is rejected.
+fn parse_rfc3339_offset_date_time(
+    input: &[u8],
+    max_subsecond_digits: Option<u8>,
+) -> Result<OffsetDateTime, error::Parse> {
+    use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    use crate::parsing::combinator::{ascii_char, ascii_char_ignore_case, exactly_n_digits, sign};
+
+    let dash = ascii_char::<b'-'>;
+    let colon = ascii_char::<b':'>;
+
+    let ParsedItem(input, year) =
+        exactly_n_digits::<4, u32>(input).ok_or(InvalidComponent("year"))?;
+    let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+    let ParsedItem(input, month) =
+        exactly_n_digits::<2, NonZeroU8>(input).ok_or(InvalidComponent("month"))?;
+    let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+    let ParsedItem(input, day) = exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("day"))?;
+    let input = ascii_char_ignore_case::<b'T'>(input)
+        .ok_or(InvalidLiteral)?
+        .into_inner();
+    let ParsedItem(input, hour) =
+        exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
+    let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+    let ParsedItem(input, minute) =
+        exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("minute"))?;
+    let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+    let ParsedItem(input, mut second) =
+        exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
+    let ParsedItem(input, mut nanosecond) =
+        if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
+            parse_rfc3339_subsecond(input, max_subsecond_digits)?
         } else {
-            false
+            ParsedItem(input, 0)
         };
+    let ParsedItem(input, offset) = {
+        if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
+            ParsedItem(input, UtcOffset::UTC)
+        } else {
+            let ParsedItem(input, offset_sign) =
+                sign(input).ok_or(InvalidComponent("offset hour"))?;
+            let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
+                .and_then(|parsed| parsed.filter(|&offset_hour| offset_hour <= 23))
+                .ok_or(InvalidComponent("offset hour"))?;
+            let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            let ParsedItem(input, offset_minute) =
+                exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
+            UtcOffset::from_hms(
+                if offset_sign == b'-' {
+                    -offset_hour.cast_signed()
+                } else {
+                    offset_hour.cast_signed()
+                },
+                if offset_sign == b'-' {
+                    -offset_minute.cast_signed()
+                } else {
+                    offset_minute.cast_signed()
+                },
+                0,
+            )
+            .map(|offset| ParsedItem(input, offset))
+            .map_err(|mut err| {
+                // Provide the user a more accurate error.
+                if err.name == "hours" {
+                    err.name = "offset hour";
+                } else if err.name == "minutes" {
+                    err.name = "offset minute";
+                }
+                err
+            })
+            .map_err(TryFromParsed::ComponentRange)?
+        }
+    };
 
-        let date = Month::from_number(month)
-            .and_then(|month| Date::from_calendar_date(year.cast_signed(), month, day))
-            .map_err(TryFromParsed::ComponentRange)?;
-        let time = Time::from_hms_nano(hour, minute, second, nanosecond)
-            .map_err(TryFromParsed::ComponentRange)?;
-        let dt = OffsetDateTime::new_in_offset(date, time, offset);
+    if !input.is_empty() {
+        return Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters,
+        ));
+    }
 
-        if leap_second_input && !dt.is_valid_leap_second_stand_in() {
-            return Err(error::Parse::TryFromParsed(TryFromParsed::ComponentRange(
-                error::ComponentRange {
-                    name: "second",
-                    minimum: 0,
-                    maximum: 59,
-                    value: 60,
-                    conditional_range: true,
-                },
-            )));
+    // The RFC explicitly permits leap seconds. We don't currently support them, so treat it as
+    // the preceding nanosecond. However, leap seconds can only occur as the last second of the
+    // month UTC.
+    let leap_second_input = if second == 60 {
+        second = 59;
+        nanosecond = 999_999_999;
+        true
+    } else {
+        false
+    };
+
+    let date = Month::from_u8(month.get())
+        .and_then(|month| Date::from_calendar_date(year.cast_signed(), month, day))
+        .map_err(TryFromParsed::ComponentRange)?;
+    let time = Time::from_hms_nano(hour, minute, second, nanosecond)
+        .map_err(TryFromParsed::ComponentRange)?;
+    let dt = OffsetDateTime::new_in_offset(date, time, offset);
+
+    if leap_second_input && !dt.is_valid_leap_second_stand_in() {
+        return Err(error::Parse::TryFromParsed(TryFromParsed::ComponentRange(
+            error::ComponentRange {
+                name: "second",
+                minimum: 0,
+                maximum: 59,
+                value: 60,
+                conditional_range: true,
+            },
+        )));
+    }
+
+    Ok(dt)
+}
+
+/// Parse the digits of an RFC 3339 subsecond, following the `.`, into a number of nanoseconds. Any
+/// digits beyond the ninth are ignored. If `max_digits` is present, more digits than this are
+/// rejected with a [`ComponentRange`](error::ComponentRange) error for the number of digits.
+fn parse_rfc3339_subsecond(
+    input: &[u8],
+    max_digits: Option<u8>,
+) -> Result<ParsedItem<'_, u32>, error::Parse> {
+    use crate::error::ParseFromDescription::InvalidComponent;
+    use crate::parsing::combinator::any_digit;
+
+    let num_digits = input.iter().take_while(|c| c.is_ascii_digit()).count();
+    if num_digits == 0 {
+        return Err(InvalidComponent("subsecond").into());
+    }
+    if let Some(max_digits) = max_digits {
+        if num_digits > max_digits.extend() {
+            return Err(TryFromParsed::ComponentRange(error::ComponentRange {
+                name: "subsecond digits",
+                minimum: 0,
+                maximum: max_digits.into(),
+                value: num_digits as i64,
+                conditional_range: false,
+            })
+            .into());
         }
+    }
 
-        Ok(dt)
+    let mut value = 0;
+    let mut multiplier = 100_000_000;
+    let mut input = input;
+    while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+        value += (digit - b'0').extend::<u32>() * multiplier;
+        input = new_input;
+        multiplier /= 10;
+    }
+
+    Ok(ParsedItem(input, value))
+}
+
+impl sealed::Sealed for Rfc3339Bounded {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_rfc3339_into(input, parsed, Some(self.max_subsecond_digits))
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        parse_rfc3339_offset_date_time(input, Some(self.max_subsecond_digits))
+    }
+}
+
+impl sealed::Sealed for Rfc3339Fixed {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Rfc3339.parse_into(input, parsed)
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        Rfc3339.parse_offset_date_time(input)
     }
 }
 
@@ -761,6 +1194,17 @@ impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
             }
         }
 
+        // `24:00:00` is the only valid time with an hour of 24.
+        if parsed.is_end_of_day
+            && (parsed.minute() != Some(0)
+                || parsed.second() != Some(0)
+                || parsed.subsecond() != Some(0))
+        {
+            return Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponent("hour"),
+            ));
+        }
+
         // If a date and offset are present, a time must be as well.
         if !date_is_present || time_is_present {
             match Self::parse_offset(parsed, &mut extended_kind)(input) {
@@ -15,10 +15,12 @@ use crate::error::TryFromParsed::InsufficientInformation;
 use crate::format_description::OwnedFormatItem;
 use crate::format_description::{modifier, BorrowedFormatItem, Component};
 use crate::internal_macros::{bug, const_try_opt};
+use crate::parsing::combinator::unicode_digit;
 use crate::parsing::component::{
-    parse_day, parse_end, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset_hour,
-    parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_second,
-    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Period,
+    parse_day, parse_end, parse_era, parse_fraction, parse_hour, parse_ignore, parse_minute,
+    parse_month, parse_offset_hour, parse_offset_minute, parse_offset_second, parse_ordinal,
+    parse_period, parse_quarter, parse_second, parse_seconds_of_day, parse_subsecond,
+    parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Era, Period,
 };
 use crate::parsing::ParsedItem;
 use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
@@ -45,7 +47,7 @@ impl sealed::AnyFormatItem for BorrowedFormatItem<'_> {
         input: &'a [u8],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
+            Self::Literal(literal) => parsed.parse_description_literal(input, literal),
             Self::Component(component) => parsed.parse_component(input, *component),
             Self::Compound(compound) => parsed.parse_items(input, compound),
             Self::Optional(item) => parsed.parse_item(input, *item).or(Ok(input)),
@@ -67,6 +69,9 @@ impl sealed::AnyFormatItem for BorrowedFormatItem<'_> {
                     None => Ok(input),
                 }
             }
+            Self::Padded(component, min_width) => {
+                parsed.parse_padded_component(input, *component, *min_width)
+            }
         }
     }
 }
@@ -79,7 +84,7 @@ impl sealed::AnyFormatItem for OwnedFormatItem {
         input: &'a [u8],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
+            Self::Literal(literal) => parsed.parse_description_literal(input, literal),
             Self::Component(component) => parsed.parse_component(input, *component),
             Self::Compound(compound) => parsed.parse_items(input, compound),
             Self::Optional(item) => parsed.parse_item(input, item.as_ref()).or(Ok(input)),
@@ -101,6 +106,9 @@ impl sealed::AnyFormatItem for OwnedFormatItem {
                     None => Ok(input),
                 }
             }
+            Self::Padded(component, min_width) => {
+                parsed.parse_padded_component(input, *component, *min_width)
+            }
         }
     }
 }
@@ -121,6 +129,11 @@ pub struct Parsed {
     iso_year: OptionRangedI32<{ MIN_YEAR }, { MAX_YEAR }>,
     /// The last two digits of the ISO week year.
     iso_year_last_two: OptionRangedU8<0, 99>,
+    /// Whether the year is BC. This only affects the interpretation of year fields that were
+    /// parsed as being numbered relative to the era.
+    era_is_bc: Option<bool>,
+    /// Quarter of the year. This is only used to construct a date when the month is not present.
+    quarter: OptionRangedU8<1, 4>,
     /// Month of the year.
     month: Option<Month>,
     /// Week of the year, where week one begins on the first Sunday of the calendar year.
@@ -173,8 +186,36 @@ pub struct Parsed {
     /// the offset hour, but may not otherwise be stored due to "-0" being equivalent to "0".
     offset_is_negative: Option<bool>,
     /// Indicates whether a leap second is permitted to be parsed. This is required by some
-    /// well-known formats.
+    /// well-known formats and may be enabled for others with
+    /// [`AllowLeapSeconds`](crate::parsing::AllowLeapSeconds).
     pub(super) leap_second_allowed: bool,
+    /// Indicates whether the time was parsed as `24:00:00`, the end of the day. When set, the
+    /// hour is stored as zero and the date must be rolled forward by one day.
+    pub(super) is_end_of_day: bool,
+    /// Indicates whether whitespace in literals should match any non-empty run of whitespace,
+    /// rather than being matched exactly.
+    pub(super) lenient_whitespace: bool,
+    /// Indicates whether setting a component that already has a different value should fail,
+    /// rather than overwriting the existing value.
+    pub(super) reject_conflicts: bool,
+    /// Indicates whether non-ASCII decimal digits should be accepted in components, as if they
+    /// were the equivalent ASCII digits.
+    pub(super) unicode_digits: bool,
+    /// Indicates whether the units below the hour or minute were obtained from the fractional part
+    /// of that component. When set, the minute, second, and subsecond may not be parsed again.
+    lower_units_from_fraction: bool,
+    /// Indicates whether the `year` field was parsed as being numbered relative to the era, rather
+    /// than astronomically.
+    year_is_era_based: bool,
+    /// Indicates whether the `iso_year` field was parsed as being numbered relative to the era,
+    /// rather than astronomically.
+    iso_year_is_era_based: bool,
+    /// The first year of the 100-year range that `year_last_two` is resolved into when no full
+    /// year is present.
+    year_range_start: i16,
+    /// The first year of the 100-year range that `iso_year_last_two` is resolved into when no
+    /// full ISO year is present.
+    iso_year_range_start: i16,
 }
 
 impl Default for Parsed {
@@ -191,6 +232,8 @@ impl Parsed {
             year_last_two: OptionRangedU8::None,
             iso_year: OptionRangedI32::None,
             iso_year_last_two: OptionRangedU8::None,
+            era_is_bc: None,
+            quarter: OptionRangedU8::None,
             month: None,
             sunday_week_number: OptionRangedU8::None,
             monday_week_number: OptionRangedU8::None,
@@ -210,6 +253,15 @@ impl Parsed {
             unix_timestamp_nanos: OptionRangedI128::None,
             offset_is_negative: None,
             leap_second_allowed: false,
+            is_end_of_day: false,
+            lenient_whitespace: false,
+            reject_conflicts: false,
+            unicode_digits: false,
+            lower_units_from_fraction: false,
+            year_is_era_based: false,
+            iso_year_is_era_based: false,
+            year_range_start: 1950,
+            iso_year_range_start: 1950,
         }
     }
 
@@ -256,6 +308,84 @@ impl Parsed {
             .ok_or(error::ParseFromDescription::InvalidLiteral)
     }
 
+    /// Parse a literal from a format description. If whitespace is lenient, each run of ASCII
+    /// whitespace in the literal matches one or more ASCII whitespace characters in the input.
+    /// Otherwise, this is identical to [`Parsed::parse_literal`].
+    fn parse_description_literal<'a>(
+        &self,
+        mut input: &'a [u8],
+        mut literal: &[u8],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        if !self.lenient_whitespace {
+            return Self::parse_literal(input, literal);
+        }
+
+        while let Some((&first, rest)) = literal.split_first() {
+            if first.is_ascii_whitespace() {
+                let whitespace_len = input
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_whitespace())
+                    .count();
+                if whitespace_len == 0 {
+                    return Err(error::ParseFromDescription::InvalidLiteral);
+                }
+                input = &input[whitespace_len..];
+                let whitespace_len = rest
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_whitespace())
+                    .count();
+                literal = &rest[whitespace_len..];
+            } else {
+                input = Self::parse_literal(input, &[first])?;
+                literal = rest;
+            }
+        }
+
+        Ok(input)
+    }
+
+    /// Parse a single component whose formatted value was filled to a minimum width, mutating the
+    /// struct. The remaining input is returned as the `Ok` value.
+    ///
+    /// Fill characters are only skipped on the padded side of the value and never beyond the
+    /// minimum width.
+    fn parse_padded_component<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        min_width: modifier::MinWidth,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        let width = usize::from(min_width.width);
+        let is_fill = |byte: &&u8| **byte == min_width.fill;
+
+        if min_width.is_left_aligned {
+            let remaining = self.parse_component(input, component)?;
+            let value_width = input.len() - remaining.len();
+            let fill_width = remaining
+                .iter()
+                .take(width.saturating_sub(value_width))
+                .take_while(is_fill)
+                .count();
+            return Ok(&remaining[fill_width..]);
+        }
+
+        // The fill character may also be valid at the start of the value, such as when filling a
+        // zero-padded number with zeros. Skip as much fill as possible, backing off on failure.
+        // Each attempt parses into a copy, as a value exceeding the width is rejected afterwards.
+        let mut fill_width = input.iter().take(width).take_while(is_fill).count();
+        loop {
+            let mut this = *self;
+            match this.parse_component(&input[fill_width..], component) {
+                Ok(remaining) if fill_width == 0 || input.len() - remaining.len() <= width => {
+                    *self = this;
+                    return Ok(remaining);
+                }
+                Err(err) if fill_width == 0 => return Err(err),
+                Ok(_) | Err(_) => fill_width -= 1,
+            }
+        }
+    }
+
     /// Parse a single component, mutating the struct. The remaining input is returned as the `Ok`
     /// value.
     pub fn parse_component<'a>(
@@ -265,6 +395,11 @@ impl Parsed {
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         use error::ParseFromDescription::InvalidComponent;
 
+        // The number of bytes to ignore is unaffected by the digits present.
+        if self.unicode_digits && !matches!(component, Component::Ignore(_)) {
+            return self.parse_component_unicode_digits(input, component);
+        }
+
         match component {
             Component::Day(modifiers) => parse_day(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_day(value)))
@@ -295,18 +430,32 @@ impl Parsed {
                 let ParsedItem(remaining, value) =
                     parse_year(input, modifiers).ok_or(InvalidComponent("year"))?;
                 match (modifiers.iso_week_based, modifiers.repr) {
-                    (false, modifier::YearRepr::Full) => self.set_year(value),
-                    (false, modifier::YearRepr::LastTwo) => {
-                        self.set_year_last_two(value.cast_unsigned().truncate())
-                    }
-                    (true, modifier::YearRepr::Full) => self.set_iso_year(value),
-                    (true, modifier::YearRepr::LastTwo) => {
-                        self.set_iso_year_last_two(value.cast_unsigned().truncate())
-                    }
+                    // The last two digits cannot be resolved to a full year relative to an era.
+                    (_, modifier::YearRepr::LastTwo) if modifiers.era_based => None,
+                    (false, modifier::YearRepr::Full) => self.set_year(value).map(|()| {
+                        self.year_is_era_based = modifiers.era_based;
+                    }),
+                    (false, modifier::YearRepr::LastTwo) => self
+                        .set_year_last_two(value.cast_unsigned().truncate())
+                        .map(|()| self.year_range_start = modifiers.range_start),
+                    (true, modifier::YearRepr::Full) => self.set_iso_year(value).map(|()| {
+                        self.iso_year_is_era_based = modifiers.era_based;
+                    }),
+                    (true, modifier::YearRepr::LastTwo) => self
+                        .set_iso_year_last_two(value.cast_unsigned().truncate())
+                        .map(|()| self.iso_year_range_start = modifiers.range_start),
                 }
                 .ok_or(InvalidComponent("year"))?;
                 Ok(remaining)
             }
+            Component::Era(modifiers) => parse_era(input, modifiers)
+                .and_then(|parsed| {
+                    parsed.consume_value(|value| self.set_era_is_bc(value == Era::Bc))
+                })
+                .ok_or(InvalidComponent("era")),
+            Component::Quarter(modifiers) => parse_quarter(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_quarter(value)))
+                .ok_or(InvalidComponent("quarter")),
             Component::Hour(modifiers) => {
                 let ParsedItem(remaining, value) =
                     parse_hour(input, modifiers).ok_or(InvalidComponent("hour"))?;
@@ -316,26 +465,69 @@ impl Parsed {
                     self.set_hour_24(value)
                 }
                 .ok_or(InvalidComponent("hour"))?;
-                Ok(remaining)
+                match modifiers.fraction {
+                    Some(digits) => self
+                        .parse_lower_units_from_fraction(remaining, digits, Second::per(Hour))
+                        .ok_or(InvalidComponent("hour")),
+                    None => Ok(remaining),
+                }
+            }
+            Component::Minute(_) if self.lower_units_from_fraction => {
+                Err(InvalidComponent("minute"))
+            }
+            Component::Minute(modifiers) => {
+                let ParsedItem(remaining, value) =
+                    parse_minute(input, modifiers).ok_or(InvalidComponent("minute"))?;
+                self.set_minute(value).ok_or(InvalidComponent("minute"))?;
+                match modifiers.fraction {
+                    Some(digits) => self
+                        .parse_lower_units_from_fraction(
+                            remaining,
+                            digits,
+                            Second::per(Minute).extend(),
+                        )
+                        .ok_or(InvalidComponent("minute")),
+                    None => Ok(remaining),
+                }
             }
-            Component::Minute(modifiers) => parse_minute(input, modifiers)
-                .and_then(|parsed| parsed.consume_value(|value| self.set_minute(value)))
-                .ok_or(InvalidComponent("minute")),
             Component::Period(modifiers) => parse_period(input, modifiers)
+                .or_else(|| {
+                    // An absent optional period is treated as AM.
+                    modifiers
+                        .is_optional
+                        .then_some(ParsedItem(input, Period::Am))
+                })
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_hour_12_is_pm(value == Period::Pm))
                 })
                 .ok_or(InvalidComponent("period")),
+            Component::Second(_) if self.lower_units_from_fraction => {
+                Err(InvalidComponent("second"))
+            }
             Component::Second(modifiers) => parse_second(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_second(value)))
                 .ok_or(InvalidComponent("second")),
+            Component::Subsecond(_) if self.lower_units_from_fraction => {
+                Err(InvalidComponent("subsecond"))
+            }
             Component::Subsecond(modifiers) => parse_subsecond(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_subsecond(value)))
                 .ok_or(InvalidComponent("subsecond")),
+            Component::SecondsOfDay(_) if self.lower_units_from_fraction => {
+                Err(InvalidComponent("seconds_of_day"))
+            }
+            Component::SecondsOfDay(modifiers) => parse_seconds_of_day(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_seconds_of_day(value)))
+                .ok_or(InvalidComponent("seconds_of_day")),
             Component::OffsetHour(modifiers) => parse_offset_hour(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|(value, is_negative)| {
                         self.set_offset_hour(value)?;
+                        if self.reject_conflicts
+                            && matches!(self.offset_is_negative, Some(prev) if prev != is_negative)
+                        {
+                            return None;
+                        }
                         self.offset_is_negative = Some(is_negative);
                         Some(())
                     })
@@ -364,6 +556,98 @@ impl Parsed {
                 .ok_or(error::ParseFromDescription::UnexpectedTrailingCharacters),
         }
     }
+
+    /// Parse a single [`Component`] after replacing non-ASCII decimal digits at the start of the
+    /// input with the equivalent ASCII digits.
+    fn parse_component_unicode_digits<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        /// The maximum number of bytes to replace. This is longer than any component other than
+        /// `[ignore]`, which is not replaced.
+        const MAX_LEN: usize = 64;
+
+        let mut normalized = [0; MAX_LEN];
+        // The index in `input` corresponding to each index in `normalized`.
+        let mut indices = [0; MAX_LEN + 1];
+        let mut len = 0;
+        let mut remaining = input;
+        while len < MAX_LEN {
+            let ParsedItem(next, byte) = match unicode_digit(remaining) {
+                Some(digit) => digit,
+                None => match remaining {
+                    [byte, next @ ..] => ParsedItem(next, *byte),
+                    [] => break,
+                },
+            };
+            normalized[len] = byte;
+            indices[len] = input.len() - remaining.len();
+            len += 1;
+            remaining = next;
+        }
+        indices[len] = input.len() - remaining.len();
+
+        self.unicode_digits = false;
+        let result = self.parse_component(&normalized[..len], component);
+        self.unicode_digits = true;
+        let consumed = len - result?.len();
+        Ok(&input[indices[consumed]..])
+    }
+
+    /// Parse the fractional part of the hour or minute, setting the units below it. `unit` is the
+    /// number of seconds in the component the fraction belongs to.
+    fn parse_lower_units_from_fraction<'a>(
+        &mut self,
+        input: &'a [u8],
+        digits: modifier::SubsecondDigits,
+        unit: u16,
+    ) -> Option<&'a [u8]> {
+        // The lower units must come from exactly one place.
+        if self.second.is_some()
+            || self.subsecond.is_some()
+            || (unit > Second::per(Minute).extend() && self.minute.is_some())
+        {
+            return None;
+        }
+
+        let ParsedItem(remaining, fraction) = parse_fraction(input, digits)?;
+        // The fraction is in billionths of the unit, so this is exact.
+        let nanos = fraction.extend::<u64>() * unit.extend::<u64>();
+        let seconds = nanos / Nanosecond::per(Second).extend::<u64>();
+        if unit > Second::per(Minute).extend() {
+            self.set_minute((seconds / Second::per(Minute).extend::<u64>()).truncate())?;
+        }
+        self.set_second((seconds % Second::per(Minute).extend::<u64>()).truncate())?;
+        self.set_subsecond((nanos % Nanosecond::per(Second).extend::<u64>()).truncate())?;
+        self.lower_units_from_fraction = true;
+        Some(remaining)
+    }
+
+    /// Set the hour, minute, and second from the number of seconds since midnight. One day's worth
+    /// of seconds is only accepted if leap seconds are allowed, in which case it is 23:59:60.
+    fn set_seconds_of_day(&mut self, value: u32) -> Option<()> {
+        let (hour, minute, second) = if value < Second::per(Day) {
+            (
+                value / Second::per(Hour).extend::<u32>(),
+                value / Second::per(Minute).extend::<u32>() % Minute::per(Hour).extend::<u32>(),
+                value % Second::per(Minute).extend::<u32>(),
+            )
+        } else if value == Second::per(Day) && self.leap_second_allowed {
+            (23, 59, 60)
+        } else {
+            return None;
+        };
+        self.set_hour_24(hour.truncate())?;
+        self.set_minute(minute.truncate())?;
+        self.set_second(second.truncate())
+    }
+}
+
+/// Resolve the last two digits of a year into the 100-year range beginning at `range_start`.
+const fn resolve_last_two(last_two: u8, range_start: i16) -> i32 {
+    let range_start = range_start as i32;
+    range_start + (last_two as i32 - range_start % 100).rem_euclid(100)
 }
 
 /// Getter methods
@@ -388,6 +672,16 @@ impl Parsed {
         self.iso_year_last_two.get_primitive()
     }
 
+    /// Obtain the `era_is_bc` component.
+    pub const fn era_is_bc(&self) -> Option<bool> {
+        self.era_is_bc
+    }
+
+    /// Obtain the `quarter` component.
+    pub const fn quarter(&self) -> Option<NonZeroU8> {
+        NonZeroU8::new(const_try_opt!(self.quarter.get_primitive()))
+    }
+
     /// Obtain the `month` component.
     pub const fn month(&self) -> Option<Month> {
         self.month
@@ -494,6 +788,16 @@ impl Parsed {
     pub const fn unix_timestamp_nanos(&self) -> Option<i128> {
         self.unix_timestamp_nanos.get_primitive()
     }
+
+    /// Obtain whether the time was parsed as `24:00:00`, the end of the day.
+    ///
+    /// When this is `true`, the time components are all zero and the parsed date is the day
+    /// _before_ the one the value refers to. This is only set by formats that explicitly opt in,
+    /// such as [`Iso8601`](crate::format_description::well_known::Iso8601) with
+    /// [`set_end_of_day_allowed`](crate::format_description::well_known::iso8601::Config::set_end_of_day_allowed).
+    pub const fn is_end_of_day(&self) -> bool {
+        self.is_end_of_day
+    }
 }
 
 /// Generate setters based on the builders.
@@ -501,7 +805,11 @@ macro_rules! setters {
     ($($name:ident $setter:ident $builder:ident $type:ty;)*) => {$(
         #[doc = concat!("Set the `", stringify!($setter), "` component.")]
         pub fn $setter(&mut self, value: $type) -> Option<()> {
-            *self = self.$builder(value)?;
+            let new = self.$builder(value)?;
+            if self.reject_conflicts && self.$name.is_some() && self.$name != new.$name {
+                return None;
+            }
+            *self = new;
             Some(())
         }
     )*};
@@ -510,13 +818,17 @@ macro_rules! setters {
 /// Setter methods
 ///
 /// All setters return `Option<()>`, which is `Some` if the value was set, and `None` if not. The
-/// setters _may_ fail if the value is invalid, though behavior is not guaranteed.
+/// setters _may_ fail if the value is invalid, though behavior is not guaranteed. When parsing with
+/// [`RejectConflicts`](crate::parsing::RejectConflicts), the setters also fail if the component
+/// already has a different value. Otherwise, the new value replaces the existing one.
 impl Parsed {
     setters! {
         year set_year with_year i32;
         year_last_two set_year_last_two with_year_last_two u8;
         iso_year set_iso_year with_iso_year i32;
         iso_year_last_two set_iso_year_last_two with_iso_year_last_two u8;
+        era_is_bc set_era_is_bc with_era_is_bc bool;
+        quarter set_quarter with_quarter NonZeroU8;
         month set_month with_month Month;
         sunday_week_number set_sunday_week_number with_sunday_week_number u8;
         monday_week_number set_monday_week_number with_monday_week_number u8;
@@ -594,6 +906,26 @@ impl Parsed {
         Some(self)
     }
 
+    /// Set the `era_is_bc` component and return `self`.
+    ///
+    /// When an era is present, a `year` or `iso_year` component that was parsed with the
+    /// [`era_based`](modifier::Year::era_based) modifier is interpreted as being numbered relative
+    /// to the era, such that 1 BC immediately precedes AD 1. Other years must agree with the era.
+    pub const fn with_era_is_bc(mut self, value: bool) -> Option<Self> {
+        self.era_is_bc = Some(value);
+        Some(self)
+    }
+
+    /// Set the `quarter` component and return `self`.
+    ///
+    /// When the `month` component is not present, the quarter is used in its place when
+    /// constructing a date, resolving to the first day of the first month of the quarter unless a
+    /// day is also present. When the `month` component is present, the quarter must agree with it.
+    pub const fn with_quarter(mut self, value: NonZeroU8) -> Option<Self> {
+        self.quarter = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value.get())));
+        Some(self)
+    }
+
     /// Set the `month` component and return `self`.
     pub const fn with_month(mut self, value: Month) -> Option<Self> {
         self.month = Some(value);
@@ -725,20 +1057,175 @@ impl Parsed {
     }
 }
 
+/// Values used in place of missing components by
+/// [`Parsed::into_offset_date_time_or`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedDefaults {
+    /// The date used when no date component was parsed.
+    pub date: Date,
+    /// The time used when no time component was parsed. This is midnight unless otherwise set.
+    pub time: Time,
+    /// The offset used when no offset component was parsed. This is UTC unless otherwise set.
+    pub offset: UtcOffset,
+}
+
+impl ParsedDefaults {
+    /// Create defaults using the provided date, midnight, and UTC.
+    ///
+    /// ```rust
+    /// # use time::parsing::ParsedDefaults;
+    /// # use time::{Time, UtcOffset};
+    /// # use time_macros::date;
+    /// let defaults = ParsedDefaults::new(date!(2000 - 01 - 01));
+    /// assert_eq!(defaults.date, date!(2000 - 01 - 01));
+    /// assert_eq!(defaults.time, Time::MIDNIGHT);
+    /// assert_eq!(defaults.offset, UtcOffset::UTC);
+    /// ```
+    pub const fn new(date: Date) -> Self {
+        Self {
+            date,
+            time: Time::MIDNIGHT,
+            offset: UtcOffset::UTC,
+        }
+    }
+}
+
+/// Lenient conversion
+impl Parsed {
+    /// Whether any component that contributes to the date is present.
+    const fn has_date_component(&self) -> bool {
+        self.year.is_some()
+            || self.year_last_two.is_some()
+            || self.iso_year.is_some()
+            || self.iso_year_last_two.is_some()
+            || self.era_is_bc.is_some()
+            || self.quarter.is_some()
+            || self.month.is_some()
+            || self.sunday_week_number.is_some()
+            || self.monday_week_number.is_some()
+            || self.iso_week_number.is_some()
+            || self.weekday.is_some()
+            || self.ordinal.is_some()
+            || self.day.is_some()
+    }
+
+    /// Whether any component that contributes to the time is present.
+    const fn has_time_component(&self) -> bool {
+        self.hour_24.is_some()
+            || self.hour_12.is_some()
+            || self.hour_12_is_pm.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.subsecond.is_some()
+    }
+
+    /// Whether any component that contributes to the offset is present.
+    const fn has_offset_component(&self) -> bool {
+        self.offset_hour.is_some() || self.offset_minute.is_some() || self.offset_second.is_some()
+    }
+
+    /// Convert the parsed components into an [`OffsetDateTime`], using `defaults` for any that are
+    /// missing entirely.
+    ///
+    /// The date, time, and offset are each considered separately:
+    ///
+    /// - If none of the year, last two digits of the year, ISO year, last two digits of the ISO
+    ///   year, era, quarter, month, week number (of any kind), weekday, ordinal, or day are
+    ///   present, [`ParsedDefaults::date`] is used.
+    /// - If none of the hour (24-hour or 12-hour), AM/PM, minute, second, or subsecond are present,
+    ///   [`ParsedDefaults::time`] is used.
+    /// - If none of the offset hour, minute, or second are present, [`ParsedDefaults::offset`] is
+    ///   used.
+    ///
+    /// When only some of the components in a group are present, they are converted exactly as
+    /// with the `TryFrom` implementation; no individual component is filled in. If a Unix timestamp
+    /// is present, the defaults are not used at all.
+    ///
+    /// ```rust
+    /// # use time::parsing::{Parsed, ParsedDefaults};
+    /// # use time_macros::{date, datetime, format_description, offset};
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(b"2024-02-29", format_description!("[year]-[month]-[day]"))?;
+    /// assert_eq!(
+    ///     parsed.into_offset_date_time_or(ParsedDefaults::new(date!(2000-01-01)))?,
+    ///     datetime!(2024-02-29 0:00 UTC),
+    /// );
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(b"12:30", format_description!("[hour]:[minute]"))?;
+    /// let defaults = ParsedDefaults {
+    ///     offset: offset!(+2),
+    ///     ..ParsedDefaults::new(date!(2000-01-01))
+    /// };
+    /// assert_eq!(
+    ///     parsed.into_offset_date_time_or(defaults)?,
+    ///     datetime!(2000-01-01 12:30 +2),
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn into_offset_date_time_or(
+        self,
+        defaults: ParsedDefaults,
+    ) -> Result<OffsetDateTime, error::TryFromParsed> {
+        let mut parsed = self;
+
+        if parsed.unix_timestamp_nanos().is_none() {
+            if !parsed.has_date_component() {
+                let (year, ordinal) = defaults.date.to_ordinal_date();
+                let new = NonZeroU16::new(ordinal).and_then(|ordinal| {
+                    parsed
+                        .with_year(year)
+                        .and_then(|parsed| parsed.with_ordinal(ordinal))
+                });
+                let Some(new) = new else {
+                    bug!("the default date is valid");
+                };
+                parsed = new;
+            }
+
+            if !parsed.has_time_component() {
+                let (hour, minute, second, nanosecond) = defaults.time.as_hms_nano();
+                let new = parsed
+                    .with_hour_24(hour)
+                    .and_then(|parsed| parsed.with_minute(minute))
+                    .and_then(|parsed| parsed.with_second(second))
+                    .and_then(|parsed| parsed.with_subsecond(nanosecond));
+                let Some(new) = new else {
+                    bug!("the default time is valid");
+                };
+                parsed = new;
+            }
+
+            if !parsed.has_offset_component() {
+                let (hours, minutes, seconds) = defaults.offset.as_hms();
+                let new = parsed
+                    .with_offset_hour(hours)
+                    .and_then(|parsed| parsed.with_offset_minute_signed(minutes))
+                    .and_then(|parsed| parsed.with_offset_second_signed(seconds));
+                let Some(new) = new else {
+                    bug!("the default offset is valid");
+                };
+                parsed = new;
+            }
+        }
+
+        parsed.try_into()
+    }
+}
+
 impl TryFrom<Parsed> for Date {
     type Error = error::TryFromParsed;
 
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
         /// Match on the components that need to be present.
         macro_rules! match_ {
             (_ => $catch_all:expr $(,)?) => {
                 $catch_all
             };
-            (($($name:ident),* $(,)?) => $arm:expr, $($rest:tt)*) => {
-                if let ($(Some($name)),*) = ($(parsed.$name()),*) {
-                    $arm
-                } else {
-                    match_!($($rest)*)
+            (($($name:ident),* $(,)?) $(if $guard:expr)? => $arm:expr, $($rest:tt)*) => {
+                match ($(parsed.$name()),*) {
+                    ($(Some($name)),*) $(if $guard)? => $arm,
+                    _ => match_!($($rest)*),
                 }
             };
         }
@@ -758,6 +1245,96 @@ impl TryFrom<Parsed> for Date {
             }
         }
 
+        /// The error for a year, resolved from its last two digits, that is out of range.
+        const fn last_two_out_of_range(year: i32) -> error::TryFromParsed {
+            error::TryFromParsed::ComponentRange(error::ComponentRange {
+                name: "year",
+                minimum: MIN_YEAR as _,
+                maximum: MAX_YEAR as _,
+                value: year as _,
+                conditional_range: false,
+            })
+        }
+
+        /// Convert a year to astronomical numbering, given the era. Era-based years have no year
+        /// zero, with 1 BC being astronomical year 0, 2 BC being year -1, and so on. Astronomical
+        /// years are left as-is, but must agree with the era.
+        fn astronomical_year(
+            year: i32,
+            is_era_based: bool,
+            is_bc: bool,
+        ) -> Result<i32, error::TryFromParsed> {
+            let (minimum, maximum) = match (is_era_based, is_bc) {
+                (true, true) => (1, 1 - MIN_YEAR),
+                (_, false) => (1, MAX_YEAR),
+                (false, true) => (MIN_YEAR, 0),
+            };
+            if year < minimum || year > maximum {
+                return Err(error::TryFromParsed::ComponentRange(
+                    error::ComponentRange {
+                        name: "year",
+                        minimum: minimum.extend(),
+                        maximum: maximum.extend(),
+                        value: year.extend(),
+                        conditional_range: true,
+                    },
+                ));
+            }
+            Ok(if is_era_based && is_bc {
+                1 - year
+            } else {
+                year
+            })
+        }
+
+        // A full year takes precedence over the last two digits. Only when it is absent are the
+        // last two digits resolved into a full year.
+        if let (None, Some(last_two)) = (parsed.year(), parsed.year_last_two()) {
+            let year = resolve_last_two(last_two, parsed.year_range_start);
+            parsed = parsed
+                .with_year(year)
+                .ok_or_else(|| last_two_out_of_range(year))?;
+        }
+        if let (None, Some(last_two)) = (parsed.iso_year(), parsed.iso_year_last_two()) {
+            let iso_year = resolve_last_two(last_two, parsed.iso_year_range_start);
+            parsed = parsed
+                .with_iso_year(iso_year)
+                .ok_or_else(|| last_two_out_of_range(iso_year))?;
+        }
+
+        if let Some(is_bc) = parsed.era_is_bc() {
+            if let Some(year) = parsed.year() {
+                let year = astronomical_year(year, parsed.year_is_era_based, is_bc)?;
+                let Some(new) = parsed.with_year(year) else {
+                    bug!("the astronomical year is in range");
+                };
+                parsed = new;
+            }
+            if let Some(iso_year) = parsed.iso_year() {
+                let iso_year = astronomical_year(iso_year, parsed.iso_year_is_era_based, is_bc)?;
+                let Some(new) = parsed.with_iso_year(iso_year) else {
+                    bug!("the astronomical year is in range");
+                };
+                parsed = new;
+            }
+        }
+
+        // A quarter that is present alongside the month must agree with it.
+        if let (Some(quarter), Some(month)) = (parsed.quarter(), parsed.month()) {
+            let month_quarter = (month as u8 - 1) / 3 + 1;
+            if quarter.get() != month_quarter {
+                return Err(error::TryFromParsed::ComponentRange(
+                    error::ComponentRange {
+                        name: "quarter",
+                        minimum: month_quarter.into(),
+                        maximum: month_quarter.into(),
+                        value: quarter.get().into(),
+                        conditional_range: true,
+                    },
+                ));
+            }
+        }
+
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
@@ -783,6 +1360,18 @@ impl TryFrom<Parsed> for Date {
                     - adjustment(year)
                     + 1).cast_unsigned(),
             )?),
+            // When the month is not known, the quarter can be used in its place. The date is then in
+            // the first month of the quarter, defaulting to the first day of that month.
+            (year, quarter) if parsed.month().is_none() => Ok(Self::from_calendar_date(
+                year,
+                match quarter.get() {
+                    1 => Month::January,
+                    2 => Month::April,
+                    3 => Month::July,
+                    _ => Month::October,
+                },
+                parsed.day().map_or(1, NonZeroU8::get),
+            )?),
             _ => Err(InsufficientInformation),
         }
     }
@@ -846,11 +1435,30 @@ impl TryFrom<Parsed> for UtcOffset {
     }
 }
 
+/// Obtain the day after `date`, as is needed when the time was parsed as the end of the day.
+fn next_day(date: Date) -> Result<Date, error::TryFromParsed> {
+    date.next_day().ok_or_else(|| {
+        error::TryFromParsed::ComponentRange(error::ComponentRange {
+            name: "year",
+            minimum: MIN_YEAR.extend(),
+            maximum: MAX_YEAR.extend(),
+            value: date.year().extend::<i64>() + 1,
+            conditional_range: false,
+        })
+    })
+}
+
 impl TryFrom<Parsed> for PrimitiveDateTime {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        Ok(Self::new(parsed.try_into()?, parsed.try_into()?))
+        let date = Date::try_from(parsed)?;
+        let date = if parsed.is_end_of_day {
+            next_day(date)?
+        } else {
+            date
+        };
+        Ok(Self::new(date, parsed.try_into()?))
     }
 }
 
@@ -881,11 +1489,14 @@ impl TryFrom<Parsed> for OffsetDateTime {
             false
         };
 
-        let dt = Self::new_in_offset(
-            Date::try_from(parsed)?,
-            Time::try_from(parsed)?,
-            UtcOffset::try_from(parsed)?,
-        );
+        let date = Date::try_from(parsed)?;
+        let date = if parsed.is_end_of_day {
+            next_day(date)?
+        } else {
+            date
+        };
+
+        let dt = Self::new_in_offset(date, Time::try_from(parsed)?, UtcOffset::try_from(parsed)?);
 
         if leap_second_input && !dt.is_valid_leap_second_stand_in() {
             return Err(error::TryFromParsed::ComponentRange(
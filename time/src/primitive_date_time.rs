@@ -11,6 +11,7 @@ use std::io;
 use powerfmt::ext::FormatterExt as _;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
+use crate::convert::{Day, Nanosecond};
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 use crate::internal_macros::{const_try, const_try_opt};
@@ -314,6 +315,27 @@ impl PrimitiveDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.date().to_julian_day()
     }
+
+    /// Get the [Julian date](https://en.wikipedia.org/wiki/Julian_day), including the fraction of
+    /// the day, treating the value as UTC. Julian dates begin at noon, so midnight is at a fraction
+    /// of one half.
+    ///
+    /// The value is computed from civil time and does not account for leap seconds; it is _not_
+    /// based on Terrestrial Time. As an `f64` is returned, the resolution is approximately 40
+    /// microseconds for contemporary dates.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+    /// assert_eq!(datetime!(2000-01-01 18:00).to_julian_date(), 2_451_545.25);
+    /// assert_eq!(datetime!(-4713-11-24 12:00).to_julian_date(), 0.0);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        (self.to_julian_day() as f64 - 0.5)
+            + self.time().as_duration_since_midnight().whole_nanoseconds() as f64
+                / Nanosecond::per(Day) as f64
+    }
     // endregion date getters
 
     // region: time getters
@@ -491,6 +513,34 @@ impl PrimitiveDateTime {
     pub const fn assume_utc(self) -> OffsetDateTime {
         self.assume_offset(UtcOffset::UTC)
     }
+
+    /// Assuming that the existing `PrimitiveDateTime` represents a moment in each of the provided
+    /// [`UtcOffset`]s, return an [`OffsetDateTime`] for each, in the order the offsets are
+    /// provided.
+    ///
+    /// This is useful when a datetime may correspond to more than one instant, such as around a
+    /// transition between standard and daylight saving time. The offsets are applied mechanically,
+    /// as with [`assume_offset`](Self::assume_offset). This crate has no knowledge of time zones,
+    /// so which offsets are candidates and how to choose between the results is the
+    /// responsibility of the caller.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// // 01:30 occurs twice in New York on 2024-11-03, once in EDT and once in EST.
+    /// let mut datetimes =
+    ///     datetime!(2024-11-03 1:30).possible_offsets([offset!(-4), offset!(-5)]);
+    /// assert_eq!(datetimes.next(), Some(datetime!(2024-11-03 1:30 -4)));
+    /// assert_eq!(datetimes.next(), Some(datetime!(2024-11-03 1:30 -5)));
+    /// assert_eq!(datetimes.next(), None);
+    /// ```
+    pub fn possible_offsets(
+        self,
+        offsets: impl IntoIterator<Item = UtcOffset>,
+    ) -> impl Iterator<Item = OffsetDateTime> {
+        offsets
+            .into_iter()
+            .map(move |offset| self.assume_offset(offset))
+    }
     // endregion attach offset
 
     // region: checked arithmetic
@@ -553,6 +603,50 @@ impl PrimitiveDateTime {
             time,
         })
     }
+
+    /// Computes `self + months`, returning `None` if an overflow occurred. The time is unchanged.
+    ///
+    /// The year and month are advanced by the given number of calendar months. If the day does not
+    /// exist in the resulting month, it is clamped to the last day of that month. As such, adding
+    /// one month to January 31 results in the last day of February.
+    ///
+    /// ```
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-31 15:30).checked_add_months(1),
+    ///     Some(datetime!(2020-02-29 15:30))
+    /// );
+    /// assert_eq!(PrimitiveDateTime::MAX.checked_add_months(1), None);
+    /// ```
+    pub const fn checked_add_months(self, months: i32) -> Option<Self> {
+        Some(Self {
+            date: const_try_opt!(self.date.checked_add_months(months)),
+            time: self.time,
+        })
+    }
+
+    /// Computes `self - months`, returning `None` if an overflow occurred. The time is unchanged.
+    ///
+    /// The year and month are moved back by the given number of calendar months. If the day does
+    /// not exist in the resulting month, it is clamped to the last day of that month. As such,
+    /// subtracting one month from March 31 results in the last day of February.
+    ///
+    /// ```
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2021-03-31 15:30).checked_sub_months(1),
+    ///     Some(datetime!(2021-02-28 15:30))
+    /// );
+    /// assert_eq!(PrimitiveDateTime::MIN.checked_sub_months(1), None);
+    /// ```
+    pub const fn checked_sub_months(self, months: i32) -> Option<Self> {
+        Some(Self {
+            date: const_try_opt!(self.date.checked_sub_months(months)),
+            time: self.time,
+        })
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -616,6 +710,42 @@ impl PrimitiveDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: clamping
+    /// Restrict the `PrimitiveDateTime` to the inclusive range `min..=max`. Unlike [`Ord::clamp`],
+    /// this method can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `min` is later than `max`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let (min, max) = (datetime!(2020-01-01 0:00), datetime!(2020-01-01 12:00));
+    /// assert_eq!(datetime!(2019-12-31 23:59).clamp(min, max), min);
+    /// assert_eq!(datetime!(2020-01-01 12:01).clamp(min, max), max);
+    /// assert_eq!(
+    ///     datetime!(2020-01-01 6:00).clamp(min, max),
+    ///     datetime!(2020-01-01 6:00)
+    /// );
+    /// ```
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        let value = self.assume_utc().unix_timestamp_nanos();
+        let min_value = min.assume_utc().unix_timestamp_nanos();
+        let max_value = max.assume_utc().unix_timestamp_nanos();
+        if min_value > max_value {
+            crate::expect_failed("minimum must not be greater than maximum");
+        }
+
+        if value < min_value {
+            min
+        } else if value > max_value {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion clamping
 }
 
 // region: replacement
@@ -857,6 +987,56 @@ impl PrimitiveDateTime {
         format.format_into(output, Some(self.date), Some(self.time), None)
     }
 
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing the result to a
+    /// [`fmt::Write`] implementor.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::datetime;
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// let mut buf = String::new();
+    /// datetime!(2020-01-02 03:04:05).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "2020-01-02 03:04:05");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_fmt(output, Some(self.date), Some(self.time), None)
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing the result to the start of a byte
+    /// slice. The number of bytes written is returned.
+    ///
+    /// If the slice is too small to hold the formatted value, [`Format::BufferTooSmall`] is
+    /// returned. The contents of the slice are unspecified in this situation.
+    ///
+    /// [`Format::BufferTooSmall`]: error::Format::BufferTooSmall
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::datetime;
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// let mut buf = [0; 32];
+    /// let len = datetime!(2020-01-02 03:04:05).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02 03:04:05");
+    /// assert!(datetime!(2020-01-02 03:04:05)
+    ///     .format_into_slice(&mut buf[..18], &format)
+    ///     .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_slice(output, Some(self.date), Some(self.time), None)
+    }
+
     /// Format the `PrimitiveDateTime` using the provided [format
     /// description](crate::format_description).
     ///
@@ -896,6 +1076,30 @@ impl PrimitiveDateTime {
     ) -> Result<Self, error::Parse> {
         description.parse_primitive_date_time(input.as_bytes())
     }
+
+    /// Parse a `PrimitiveDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the value and the number of bytes
+    /// consumed.
+    ///
+    /// Unlike [`PrimitiveDateTime::parse`], input may remain after the value is parsed.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_partial("2020-01-02 03:04:05 rest", &format)?,
+    ///     (datetime!(2020-01-02 03:04:05), 19)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_partial(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, usize), error::Parse> {
+        let (parsed, consumed) = description.parse_partial(input.as_bytes())?;
+        Ok((parsed.try_into()?, consumed))
+    }
 }
 
 impl SmartDisplay for PrimitiveDateTime {
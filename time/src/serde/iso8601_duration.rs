@@ -0,0 +1,131 @@
+//! Use the [ISO 8601 duration format] when serializing and deserializing a [`Duration`].
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! When serializing, the value is written as `PnDTnHnMnS`, omitting any component that is zero.
+//! Seconds are written with as many fractional digits as necessary, and negative durations are
+//! prefixed with `-`. A zero duration is written as `PT0S`. Years and months are never emitted, as
+//! their length is not fixed.
+//!
+//! When deserializing, weeks, days, hours, minutes, and seconds are accepted, along with an
+//! optional leading sign. Only the seconds component may have a fractional part, which may use
+//! either `.` or `,` as the decimal separator. Digits beyond nanosecond precision are truncated.
+//! Years and months are rejected for the same reason they are not emitted.
+//!
+//! [ISO 8601 duration format]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Visitor;
+use crate::convert::*;
+use crate::Duration;
+
+/// A [`Duration`] that is serialized and deserialized in the ISO 8601 duration format.
+pub(super) struct Iso8601Duration(pub(super) Duration);
+
+impl fmt::Display for Iso8601Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_negative() {
+            f.write_str("-")?;
+        }
+        f.write_str("P")?;
+
+        // Use unsigned values to avoid overflow when negating `Duration::MIN`.
+        let seconds = self.0.whole_seconds().unsigned_abs();
+        let nanoseconds = self.0.subsec_nanoseconds().unsigned_abs();
+
+        let days = seconds / Second::per(Day) as u64;
+        let hours = seconds / Second::per(Hour) as u64 % Hour::per(Day) as u64;
+        let minutes = seconds / Second::per(Minute) as u64 % Minute::per(Hour) as u64;
+        let seconds = seconds % Second::per(Minute) as u64;
+
+        if days != 0 {
+            write!(f, "{days}D")?;
+        }
+        if hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+            return if days == 0 {
+                f.write_str("T0S")
+            } else {
+                Ok(())
+            };
+        }
+
+        f.write_str("T")?;
+        if hours != 0 {
+            write!(f, "{hours}H")?;
+        }
+        if minutes != 0 {
+            write!(f, "{minutes}M")?;
+        }
+        if seconds != 0 || nanoseconds != 0 {
+            write!(f, "{seconds}")?;
+            if nanoseconds != 0 {
+                let mut nanoseconds = nanoseconds;
+                let mut width = 9;
+                while nanoseconds % 10 == 0 {
+                    nanoseconds /= 10;
+                    width -= 1;
+                }
+                write!(f, ".{nanoseconds:0width$}")?;
+            }
+            f.write_str("S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for Iso8601Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'a> Deserialize<'a> for Iso8601Duration {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_str(Visitor::<Self>(PhantomData))
+            .map(Self)
+    }
+}
+
+/// Serialize a [`Duration`] using the ISO 8601 duration format.
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    Iso8601Duration(*duration).serialize(serializer)
+}
+
+/// Deserialize a [`Duration`] from its ISO 8601 duration representation.
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Duration, D::Error> {
+    Iso8601Duration::deserialize(deserializer).map(|duration| duration.0)
+}
+
+/// Use the [ISO 8601 duration format] when serializing and deserializing an
+/// [`Option<Duration>`].
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [ISO 8601 duration format]: https://en.wikipedia.org/wiki/ISO_8601#Durations
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an [`Option<Duration>`] using the ISO 8601 duration format.
+    pub fn serialize<S: Serializer>(
+        option: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Iso8601Duration).serialize(serializer)
+    }
+
+    /// Deserialize an [`Option<Duration>`] from its ISO 8601 duration representation.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<Iso8601Duration>::deserialize(deserializer)?.map(|duration| duration.0))
+    }
+}
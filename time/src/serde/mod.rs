@@ -15,6 +15,7 @@ macro_rules! item {
 
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub mod iso8601;
+pub mod iso8601_duration;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub mod rfc2822;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
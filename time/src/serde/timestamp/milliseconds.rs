@@ -24,8 +24,8 @@ pub fn serialize<S: Serializer>(
 /// Deserialize an `OffsetDateTime` from its Unix timestamp with milliseconds
 pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
     let value: i128 = <_>::deserialize(deserializer)?;
-    OffsetDateTime::from_unix_timestamp_nanos(value * 1_000_000)
-        .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
+    OffsetDateTime::from_unix_timestamp_nanos(value.saturating_mul(1_000_000))
+        .map_err(|err| de::Error::invalid_value(super::unexpected(value), &err))
 }
 
 /// Treat an `Option<OffsetDateTime>` as a [Unix timestamp] with milliseconds
@@ -56,8 +56,10 @@ pub mod option {
         deserializer: D,
     ) -> Result<Option<OffsetDateTime>, D::Error> {
         Option::deserialize(deserializer)?
-            .map(|value: i128| OffsetDateTime::from_unix_timestamp_nanos(value * 1_000_000))
+            .map(|value: i128| {
+                OffsetDateTime::from_unix_timestamp_nanos(value.saturating_mul(1_000_000))
+                    .map_err(|err| de::Error::invalid_value(super::super::unexpected(value), &err))
+            })
             .transpose()
-            .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
     }
 }
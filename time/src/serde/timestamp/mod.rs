@@ -62,3 +62,12 @@ pub mod option {
             .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
     }
 }
+
+/// The value to report when a scaled timestamp is out of range. This is the value that was
+/// deserialized, not the value after scaling.
+fn unexpected(value: i128) -> de::Unexpected<'static> {
+    match i64::try_from(value) {
+        Ok(value) => de::Unexpected::Signed(value),
+        Err(_) => de::Unexpected::Other("i128"),
+    }
+}
@@ -7,6 +7,7 @@ use serde::de;
 #[cfg(feature = "parsing")]
 use serde::Deserializer;
 
+use super::iso8601_duration::Iso8601Duration;
 #[cfg(feature = "parsing")]
 use super::{
     DATE_FORMAT, OFFSET_DATE_TIME_FORMAT, PRIMITIVE_DATE_TIME_FORMAT, TIME_FORMAT,
@@ -72,6 +73,19 @@ impl<'a> de::Visitor<'a> for Visitor<Duration> {
     }
 }
 
+impl de::Visitor<'_> for Visitor<Iso8601Duration> {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an ISO 8601-formatted `Duration`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Duration, E> {
+        Duration::parse_iso8601(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
 impl<'a> de::Visitor<'a> for Visitor<OffsetDateTime> {
     type Value = OffsetDateTime;
 
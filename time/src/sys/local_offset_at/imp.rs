@@ -1,8 +1,10 @@
 //! A fallback for any OS not covered.
 
-use crate::{OffsetDateTime, UtcOffset};
+use crate::{error, OffsetDateTime, UtcOffset};
 
 #[allow(clippy::missing_docs_in_private_items)]
-pub(super) fn local_offset_at(_datetime: OffsetDateTime) -> Option<UtcOffset> {
-    None
+pub(super) fn local_offset_at(
+    _datetime: OffsetDateTime,
+) -> Result<UtcOffset, error::IndeterminateOffsetReason> {
+    Err(error::IndeterminateOffsetReason::Unsupported)
 }
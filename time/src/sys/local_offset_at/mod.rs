@@ -14,14 +14,16 @@
 )]
 mod imp;
 
-use crate::{OffsetDateTime, UtcOffset};
+use crate::{error, OffsetDateTime, UtcOffset};
 
-/// Attempt to obtain the system's UTC offset. If the offset cannot be determined, `None` is
+/// Attempt to obtain the system's UTC offset. If the offset cannot be determined, the reason is
 /// returned.
-pub(crate) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
+pub(crate) fn local_offset_at(
+    datetime: OffsetDateTime,
+) -> Result<UtcOffset, error::IndeterminateOffsetReason> {
     // miri does not support tzset()
     if cfg!(miri) {
-        None
+        Err(error::IndeterminateOffsetReason::Unsupported)
     } else {
         imp::local_offset_at(datetime)
     }
@@ -3,7 +3,7 @@
 use core::mem::MaybeUninit;
 
 use crate::util::local_offset::{self, Soundness};
-use crate::{OffsetDateTime, UtcOffset};
+use crate::{error, OffsetDateTime, UtcOffset};
 
 /// Whether the operating system has a thread-safe environment. This allows bypassing the check for
 /// if the process is multi-threaded.
@@ -127,7 +127,9 @@ fn tm_to_offset(unix_timestamp: i64, tm: libc::tm) -> Option<UtcOffset> {
 }
 
 /// Obtain the system's UTC offset.
-pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
+pub(super) fn local_offset_at(
+    datetime: OffsetDateTime,
+) -> Result<UtcOffset, error::IndeterminateOffsetReason> {
     // Continue to obtaining the UTC offset if and only if the call is sound or the user has
     // explicitly opted out of soundness.
     //
@@ -137,7 +139,7 @@ pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
     // place, which can cause a segmentation fault by dereferencing a dangling pointer.
     //
     // If the `num_threads` crate is incapable of determining the number of running threads, then
-    // we conservatively return `None` to avoid a soundness bug.
+    // we conservatively return an error to avoid a soundness bug.
 
     if OS_HAS_THREAD_SAFE_ENVIRONMENT
         || local_offset::get_soundness() == Soundness::Unsound
@@ -146,9 +148,10 @@ pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
         let unix_timestamp = datetime.unix_timestamp();
         // Safety: We have just confirmed that the process is single-threaded or the user has
         // explicitly opted out of soundness.
-        let tm = unsafe { timestamp_to_tm(unix_timestamp) }?;
-        tm_to_offset(unix_timestamp, tm)
+        unsafe { timestamp_to_tm(unix_timestamp) }
+            .and_then(|tm| tm_to_offset(unix_timestamp, tm))
+            .ok_or(error::IndeterminateOffsetReason::Indeterminate)
     } else {
-        None
+        Err(error::IndeterminateOffsetReason::Unsound)
     }
 }
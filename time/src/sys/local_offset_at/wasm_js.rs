@@ -1,10 +1,12 @@
 use num_conv::prelude::*;
 
 use crate::convert::*;
-use crate::{OffsetDateTime, UtcOffset};
+use crate::{error, OffsetDateTime, UtcOffset};
 
 /// Obtain the system's UTC offset.
-pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
+pub(super) fn local_offset_at(
+    datetime: OffsetDateTime,
+) -> Result<UtcOffset, error::IndeterminateOffsetReason> {
     let js_date: js_sys::Date = datetime.into();
     // The number of minutes returned by getTimezoneOffset() is positive if the local time zone
     // is behind UTC, and negative if the local time zone is ahead of UTC. For example,
@@ -12,5 +14,6 @@ pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
     let timezone_offset =
         (js_date.get_timezone_offset() as i32) * -Minute::per(Hour).cast_signed().extend::<i32>();
 
-    UtcOffset::from_whole_seconds(timezone_offset).ok()
+    UtcOffset::from_whole_seconds(timezone_offset)
+        .map_err(|_| error::IndeterminateOffsetReason::Indeterminate)
 }
@@ -5,7 +5,7 @@ use core::mem::MaybeUninit;
 use num_conv::prelude::*;
 
 use crate::convert::*;
-use crate::{OffsetDateTime, UtcOffset};
+use crate::{error, OffsetDateTime, UtcOffset};
 
 // ffi: WINAPI FILETIME struct
 #[repr(C)]
@@ -80,7 +80,9 @@ fn offset_to_systemtime(datetime: OffsetDateTime) -> SystemTime {
 }
 
 /// Obtain the system's UTC offset.
-pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
+pub(super) fn local_offset_at(
+    datetime: OffsetDateTime,
+) -> Result<UtcOffset, error::IndeterminateOffsetReason> {
     // This function falls back to UTC if any system call fails.
     let systime_utc = offset_to_systemtime(datetime.to_offset(UtcOffset::UTC));
 
@@ -95,19 +97,23 @@ pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
             local_time.as_mut_ptr(),
         ) {
             // call failed
-            return None;
+            return Err(error::IndeterminateOffsetReason::Indeterminate);
         } else {
             local_time.assume_init()
         }
     };
 
     // Convert SYSTEMTIMEs to FILETIMEs so we can perform arithmetic on them.
-    let ft_system = systemtime_to_filetime(&systime_utc)?;
-    let ft_local = systemtime_to_filetime(&systime_local)?;
+    let (Some(ft_system), Some(ft_local)) = (
+        systemtime_to_filetime(&systime_utc),
+        systemtime_to_filetime(&systime_local),
+    ) else {
+        return Err(error::IndeterminateOffsetReason::Indeterminate);
+    };
 
-    let diff_secs = (filetime_to_secs(&ft_local) - filetime_to_secs(&ft_system))
+    (filetime_to_secs(&ft_local) - filetime_to_secs(&ft_system))
         .try_into()
-        .ok()?;
-
-    UtcOffset::from_whole_seconds(diff_secs).ok()
+        .ok()
+        .and_then(|diff_secs| UtcOffset::from_whole_seconds(diff_secs).ok())
+        .ok_or(error::IndeterminateOffsetReason::Indeterminate)
 }
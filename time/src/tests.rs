@@ -30,6 +30,7 @@ use std::num::NonZeroU8;
 
 use crate::ext::DigitCount;
 use crate::parsing::combinator::rfc::iso8601;
+use crate::parsing::combinator::string_ci;
 use crate::parsing::shim::Integer;
 use crate::{duration, parsing};
 
@@ -104,3 +105,18 @@ fn parsing_internals() {
     );
     assert!(<NonZeroU8 as Integer>::parse_bytes(b"256").is_none());
 }
+
+#[test]
+fn case_insensitive_strings() {
+    for input in [b"MONDAY".as_slice(), b"monday", b"Monday"] {
+        let item = string_ci(b"Monday")(input).expect("string should match");
+        assert_eq!(item.0, b"");
+        assert_eq!(item.1, input);
+    }
+    assert_eq!(
+        string_ci(b"mon")(b"MONDAY").map(|v| (v.0, v.1)),
+        Some((b"DAY".as_slice(), b"MON".as_slice()))
+    );
+    assert!(string_ci(b"Monday")(b"Mon").is_none());
+    assert!(string_ci(b"Monday")(b"Tuesday").is_none());
+}
@@ -20,7 +20,7 @@ use crate::internal_macros::{cascade, ensure_ranged, impl_add_assign, impl_sub_a
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::util::DateAdjustment;
-use crate::{error, Duration};
+use crate::{error, Date, Duration, PrimitiveDateTime};
 
 /// By explicitly inserting this enum where padding is expected, the compiler is able to better
 /// perform niche value optimization.
@@ -152,6 +152,20 @@ impl Time {
     /// ```
     pub const MIDNIGHT: Self = Self::MIN;
 
+    /// Create a `Time` that is exactly noon.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::NOON, time!(12:00));
+    /// ```
+    pub const NOON: Self = Self::from_hms_nanos_ranged(
+        Hours::new_static::<12>(),
+        Minutes::MIN,
+        Seconds::MIN,
+        Nanoseconds::MIN,
+    );
+
     /// The smallest value that can be represented by `Time`.
     ///
     /// `00:00:00.0`
@@ -312,6 +326,96 @@ impl Time {
             ensure_ranged!(Nanoseconds: nanosecond),
         ))
     }
+
+    /// Create a `Time` from the hour, minute, second, and nanosecond, clamping each component to
+    /// its valid range rather than returning an error.
+    ///
+    /// Each component is clamped independently: the hour to at most 23, the minute to at most 59,
+    /// the second to at most 59, and the nanosecond to at most 999,999,999. Values that are out of
+    /// range do not carry into the next larger component, so a second of 60 becomes 59 rather
+    /// than the start of the next minute.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_hms_nano_clamped(1, 2, 3, 4), time!(1:02:03.000_000_004));
+    /// assert_eq!(Time::from_hms_nano_clamped(24, 0, 0, 0), time!(23:00));
+    /// assert_eq!(Time::from_hms_nano_clamped(12, 30, 60, 0), time!(12:30:59));
+    /// assert_eq!(
+    ///     Time::from_hms_nano_clamped(255, 255, 255, u32::MAX),
+    ///     time!(23:59:59.999_999_999)
+    /// );
+    /// ```
+    pub const fn from_hms_nano_clamped(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Self {
+        Self::from_hms_nanos_ranged(
+            Hours::new_saturating(hour),
+            Minutes::new_saturating(minute),
+            Seconds::new_saturating(second),
+            Nanoseconds::new_saturating(nanosecond),
+        )
+    }
+
+    /// Attempt to create a `Time` from the [`Duration`] since midnight.
+    ///
+    /// This is the inverse of [`Time::as_duration_since_midnight`]. An error is returned if the
+    /// duration is negative or at least 24 hours. To wrap around midnight instead, use
+    /// [`Time::from_duration_since_midnight_wrapping`].
+    ///
+    /// ```rust
+    /// # use time::{Time, ext::NumericalDuration};
+    /// # use time_macros::time;
+    /// assert_eq!(
+    ///     Time::from_duration_since_midnight(12.hours() + 30.minutes()),
+    ///     Ok(time!(12:30))
+    /// );
+    /// assert!(Time::from_duration_since_midnight(24.hours()).is_err());
+    /// assert!(Time::from_duration_since_midnight((-1).nanoseconds()).is_err());
+    /// ```
+    pub const fn from_duration_since_midnight(
+        duration: Duration,
+    ) -> Result<Self, error::ComponentRange> {
+        if duration.is_negative() || duration.whole_seconds() >= Second::per(Day) as i64 {
+            return Err(error::ComponentRange {
+                name: "duration",
+                minimum: 0,
+                maximum: Second::per(Day) as i64 - 1,
+                value: duration.whole_seconds(),
+                conditional_range: false,
+            });
+        }
+        Ok(Self::from_nanoseconds_since_midnight_wrapping(
+            duration.whole_nanoseconds(),
+        ))
+    }
+
+    /// Create a `Time` from the [`Duration`] since midnight, wrapping around midnight as necessary.
+    ///
+    /// Durations of 24 hours or more wrap to the following day, and negative durations are
+    /// measured backwards from the following midnight. This never fails.
+    ///
+    /// ```rust
+    /// # use time::{Time, ext::NumericalDuration};
+    /// # use time_macros::time;
+    /// assert_eq!(
+    ///     Time::from_duration_since_midnight_wrapping(12.hours()),
+    ///     time!(12:00)
+    /// );
+    /// assert_eq!(
+    ///     Time::from_duration_since_midnight_wrapping(25.hours()),
+    ///     time!(1:00)
+    /// );
+    /// assert_eq!(
+    ///     Time::from_duration_since_midnight_wrapping((-1).hours()),
+    ///     time!(23:00)
+    /// );
+    /// ```
+    pub const fn from_duration_since_midnight_wrapping(duration: Duration) -> Self {
+        Self::from_nanoseconds_since_midnight_wrapping(
+            duration
+                .whole_nanoseconds()
+                .rem_euclid(Nanosecond::per(Day) as i128),
+        )
+    }
     // endregion constructors
 
     // region: getters
@@ -380,6 +484,29 @@ impl Time {
         )
     }
 
+    /// Get the [`Duration`] since midnight.
+    ///
+    /// The returned value is always non-negative and less than 24 hours. This is the inverse of
+    /// [`Time::from_duration_since_midnight`].
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).as_duration_since_midnight(), 0.seconds());
+    /// assert_eq!(
+    ///     time!(12:30:15.5).as_duration_since_midnight(),
+    ///     12.hours() + 30.minutes() + 15.5.seconds()
+    /// );
+    /// ```
+    pub const fn as_duration_since_midnight(self) -> Duration {
+        Duration::new(
+            self.hour.get() as i64 * Second::per(Hour) as i64
+                + self.minute.get() as i64 * Second::per(Minute) as i64
+                + self.second.get() as i64,
+            self.nanosecond.get() as _,
+        )
+    }
+
     /// Get the clock hour, minute, second, and nanosecond.
     #[cfg(feature = "quickcheck")]
     pub(crate) const fn as_hms_nano_ranged(self) -> (Hours, Minutes, Seconds, Nanoseconds) {
@@ -463,6 +590,36 @@ impl Time {
     pub const fn nanosecond(self) -> u32 {
         self.nanosecond.get()
     }
+
+    /// Whether the time is exactly midnight.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert!(time!(0:00).is_midnight());
+    /// assert!(!time!(0:00:00.000_000_001).is_midnight());
+    /// assert!(!time!(12:00).is_midnight());
+    /// ```
+    pub const fn is_midnight(self) -> bool {
+        self.hour.get() == 0
+            && self.minute.get() == 0
+            && self.second.get() == 0
+            && self.nanosecond.get() == 0
+    }
+
+    /// Whether the time is exactly noon.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert!(time!(12:00).is_noon());
+    /// assert!(!time!(12:00:00.000_000_001).is_noon());
+    /// assert!(!time!(0:00).is_noon());
+    /// ```
+    pub const fn is_noon(self) -> bool {
+        self.hour.get() == 12
+            && self.minute.get() == 0
+            && self.second.get() == 0
+            && self.nanosecond.get() == 0
+    }
     // endregion getters
 
     // region: arithmetic helpers
@@ -710,7 +867,213 @@ impl Time {
         Ok(self)
     }
     // endregion replacement
+
+    // region: rounding
+    /// Round the `Time` to the nearest multiple of `granularity` since midnight. Values exactly
+    /// halfway between two multiples are rounded up. Wraps around midnight if the result would be
+    /// at or after `24:00`.
+    ///
+    /// Granularities that do not evenly divide a day are still measured from midnight.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:07).round_to(15.minutes()), time!(12:00));
+    /// assert_eq!(time!(12:07:30).round_to(15.minutes()), time!(12:15));
+    /// assert_eq!(time!(23:55).round_to(15.minutes()), time!(0:00));
+    /// ```
+    pub const fn round_to(self, granularity: Duration) -> Self {
+        let (nanoseconds, remainder, granularity) = self.rounding_parts(granularity);
+        if remainder >= granularity - remainder {
+            Self::from_nanoseconds_since_midnight_wrapping(nanoseconds - remainder + granularity)
+        } else {
+            Self::from_nanoseconds_since_midnight_wrapping(nanoseconds - remainder)
+        }
+    }
+
+    /// Round the `Time` down to a multiple of `granularity` since midnight.
+    ///
+    /// Granularities that do not evenly divide a day are still measured from midnight.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:14:59).round_down_to(15.minutes()), time!(12:00));
+    /// assert_eq!(time!(0:00:00.25).round_down_to(1.seconds()), time!(0:00));
+    /// ```
+    pub const fn round_down_to(self, granularity: Duration) -> Self {
+        let (nanoseconds, remainder, _) = self.rounding_parts(granularity);
+        Self::from_nanoseconds_since_midnight_wrapping(nanoseconds - remainder)
+    }
+
+    /// Round the `Time` up to a multiple of `granularity` since midnight. Wraps around midnight if
+    /// the result would be at or after `24:00`.
+    ///
+    /// Granularities that do not evenly divide a day are still measured from midnight.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `granularity` is not positive.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:00:01).round_up_to(15.minutes()), time!(12:15));
+    /// assert_eq!(time!(12:15).round_up_to(15.minutes()), time!(12:15));
+    /// assert_eq!(time!(23:50).round_up_to(15.minutes()), time!(0:00));
+    /// ```
+    pub const fn round_up_to(self, granularity: Duration) -> Self {
+        let (nanoseconds, remainder, granularity) = self.rounding_parts(granularity);
+        if remainder == 0 {
+            self
+        } else {
+            Self::from_nanoseconds_since_midnight_wrapping(nanoseconds - remainder + granularity)
+        }
+    }
+
+    /// Obtain the number of nanoseconds since midnight, the remainder when dividing it by
+    /// `granularity`, and `granularity` in nanoseconds.
+    const fn rounding_parts(self, granularity: Duration) -> (i128, i128, i128) {
+        if !granularity.is_positive() {
+            crate::expect_failed("rounding granularity must be positive");
+        }
+
+        let granularity = granularity.whole_nanoseconds();
+        let nanoseconds = self.hour.get() as i128 * Nanosecond::per(Hour) as i128
+            + self.minute.get() as i128 * Nanosecond::per(Minute) as i128
+            + self.second.get() as i128 * Nanosecond::per(Second) as i128
+            + self.nanosecond.get() as i128;
+        (nanoseconds, nanoseconds % granularity, granularity)
+    }
+
+    /// Create a `Time` from the number of nanoseconds since midnight, wrapping around midnight.
+    /// `nanoseconds` must not be negative.
+    const fn from_nanoseconds_since_midnight_wrapping(nanoseconds: i128) -> Self {
+        let nanoseconds = nanoseconds % Nanosecond::per(Day) as i128;
+
+        // Safety: The values are in range due to the modulus above.
+        unsafe {
+            Self::__from_hms_nanos_unchecked(
+                (nanoseconds / Nanosecond::per(Hour) as i128) as _,
+                (nanoseconds % Nanosecond::per(Hour) as i128 / Nanosecond::per(Minute) as i128)
+                    as _,
+                (nanoseconds % Nanosecond::per(Minute) as i128 / Nanosecond::per(Second) as i128)
+                    as _,
+                (nanoseconds % Nanosecond::per(Second) as i128) as _,
+            )
+        }
+    }
+    // endregion rounding
+
+    // region: overflowing arithmetic
+    /// Computes `self + duration`, wrapping around midnight. The number of days crossed is
+    /// returned alongside the resulting `Time`, and is negative when moving backward in time.
+    ///
+    /// This is equivalent to the behavior of `Time + Duration`, with the exception that the
+    /// number of days is not discarded.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).overflowing_add(25.hours()), (time!(1:00), 1));
+    /// assert_eq!(time!(23:00).overflowing_add(2.hours()), (time!(1:00), 1));
+    /// assert_eq!(time!(0:30).overflowing_add((-1).hours()), (time!(23:30), -1));
+    /// assert_eq!(time!(12:00).overflowing_add(1.hours()), (time!(13:00), 0));
+    /// ```
+    pub const fn overflowing_add(self, duration: Duration) -> (Self, i64) {
+        let (date_adjustment, time) = self.adjusting_add(duration);
+        let days = duration.whole_days()
+            + match date_adjustment {
+                DateAdjustment::Previous => -1,
+                DateAdjustment::Next => 1,
+                DateAdjustment::None => 0,
+            };
+        (time, days)
+    }
+
+    /// Computes `self - duration`, wrapping around midnight. The number of days crossed is
+    /// returned alongside the resulting `Time`, and is negative when moving backward in time.
+    ///
+    /// This is equivalent to the behavior of `Time - Duration`, with the exception that the
+    /// number of days is not discarded.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:00).overflowing_sub(48.hours()), (time!(12:00), -2));
+    /// assert_eq!(time!(0:30).overflowing_sub(1.hours()), (time!(23:30), -1));
+    /// assert_eq!(time!(23:30).overflowing_sub((-1).hours()), (time!(0:30), 1));
+    /// assert_eq!(time!(12:00).overflowing_sub(1.hours()), (time!(11:00), 0));
+    /// ```
+    pub const fn overflowing_sub(self, duration: Duration) -> (Self, i64) {
+        let (date_adjustment, time) = self.adjusting_sub(duration);
+        let days = -duration.whole_days()
+            + match date_adjustment {
+                DateAdjustment::Previous => -1,
+                DateAdjustment::Next => 1,
+                DateAdjustment::None => 0,
+            };
+        (time, days)
+    }
+    // endregion overflowing arithmetic
+
+    // region: clamping
+    /// Restrict the time to the inclusive range `min..=max`. Unlike [`Ord::clamp`], this method
+    /// can be used in `const` contexts.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `min` is later than `max`.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// let (min, max) = (time!(9:00), time!(17:00));
+    /// assert_eq!(time!(8:30).clamp(min, max), min);
+    /// assert_eq!(time!(17:00:01).clamp(min, max), max);
+    /// assert_eq!(time!(12:00).clamp(min, max), time!(12:00));
+    /// ```
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        if min.as_u64() > max.as_u64() {
+            crate::expect_failed("minimum must not be greater than maximum");
+        }
+
+        if self.as_u64() < min.as_u64() {
+            min
+        } else if self.as_u64() > max.as_u64() {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion clamping
+}
+
+// region: attach date
+/// Methods to add a [`Date`] component, resulting in a [`PrimitiveDateTime`].
+impl Time {
+    /// Create a [`PrimitiveDateTime`] using the provided [`Date`] and the existing time. This is
+    /// equivalent to [`Date::with_time`].
+    ///
+    /// ```rust
+    /// # use time_macros::{date, datetime, time};
+    /// assert_eq!(
+    ///     time!(12:30).on(date!(1970-01-01)),
+    ///     datetime!(1970-01-01 12:30),
+    /// );
+    /// ```
+    pub const fn on(self, date: Date) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(date, self)
+    }
 }
+// endregion attach date
 
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
@@ -724,6 +1087,54 @@ impl Time {
         format.format_into(output, None, Some(self), None)
     }
 
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// writing the result to a [`fmt::Write`] implementor.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::time;
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut buf = String::new();
+    /// time!(12:34:56).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "12:34:56");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_fmt(output, None, Some(self), None)
+    }
+
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// writing the result to the start of a byte slice. The number of bytes written is returned.
+    ///
+    /// If the slice is too small to hold the formatted value, [`Format::BufferTooSmall`] is
+    /// returned. The contents of the slice are unspecified in this situation.
+    ///
+    /// [`Format::BufferTooSmall`]: error::Format::BufferTooSmall
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::time;
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// let mut buf = [0; 32];
+    /// let len = time!(12:34:56).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], b"12:34:56");
+    /// assert!(time!(12:34:56)
+    ///     .format_into_slice(&mut buf[..7], &format)
+    ///     .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_slice(output, None, Some(self), None)
+    }
+
     /// Format the `Time` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -756,6 +1167,27 @@ impl Time {
     ) -> Result<Self, error::Parse> {
         description.parse_time(input.as_bytes())
     }
+
+    /// Parse a `Time` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the value and the number of bytes
+    /// consumed.
+    ///
+    /// Unlike [`Time::parse`], input may remain after the value is parsed.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::{time, format_description};
+    /// let format = format_description!("[hour]:[minute]:[second]");
+    /// assert_eq!(Time::parse_partial("12:00:00 rest", &format)?, (time!(12:00), 8));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_partial(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, usize), error::Parse> {
+        let (parsed, consumed) = description.parse_partial(input.as_bytes())?;
+        Ok((parsed.try_into()?, consumed))
+    }
 }
 
 mod private {
@@ -12,7 +12,6 @@ use powerfmt::ext::FormatterExt;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
 use crate::convert::*;
-use crate::error;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
 use crate::internal_macros::ensure_ranged;
@@ -20,8 +19,7 @@ use crate::internal_macros::ensure_ranged;
 use crate::parsing::Parsable;
 #[cfg(feature = "local-offset")]
 use crate::sys::local_offset_at;
-#[cfg(feature = "local-offset")]
-use crate::OffsetDateTime;
+use crate::{error, OffsetDateTime, PrimitiveDateTime};
 
 /// The type of the `hours` field of `UtcOffset`.
 type Hours = RangedI8<-25, 25>;
@@ -117,6 +115,54 @@ impl UtcOffset {
         ))
     }
 
+    /// Create a `UtcOffset` representing an offset by the number of hours, minutes, and seconds
+    /// provided, saturating to the nearest valid offset if the total is out of range.
+    ///
+    /// As with [`UtcOffset::from_hms`], the sign of all three components should match. If they do
+    /// not, all smaller components will have their signs flipped. Components are not required to
+    /// be individually in range; the resulting offset is the total number of seconds, clamped to
+    /// the range `-25:59:59..=+25:59:59`.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::from_hms_saturating(1, 2, 3).as_hms(), (1, 2, 3));
+    /// assert_eq!(
+    ///     UtcOffset::from_hms_saturating(1, -30, 0).as_hms(),
+    ///     (1, 30, 0)
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::from_hms_saturating(0, 90, 0).as_hms(),
+    ///     (1, 30, 0)
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::from_hms_saturating(30, 0, 0).as_hms(),
+    ///     (25, 59, 59)
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::from_hms_saturating(-30, 0, 0).as_hms(),
+    ///     (-25, -59, -59)
+    /// );
+    /// ```
+    pub const fn from_hms_saturating(hours: i8, minutes: i8, seconds: i8) -> Self {
+        let is_negative = if hours != 0 {
+            hours < 0
+        } else if minutes != 0 {
+            minutes < 0
+        } else {
+            seconds < 0
+        };
+
+        let total = hours.unsigned_abs() as i32 * Second::per(Hour) as i32
+            + minutes.unsigned_abs() as i32 * Second::per(Minute) as i32
+            + seconds.unsigned_abs() as i32;
+
+        Self::from_whole_seconds_ranged(WholeSeconds::new_saturating(if is_negative {
+            -total
+        } else {
+            total
+        }))
+    }
+
     /// Create a `UtcOffset` representing an offset of the hours, minutes, and seconds provided. All
     /// three parameters must have the same sign.
     ///
@@ -334,6 +380,117 @@ impl UtcOffset {
     }
     // endregion is_{sign}
 
+    // region: rounding
+    /// Round the offset to the nearest whole minute, with halfway values rounded away from zero.
+    /// The sign of the offset is preserved.
+    ///
+    /// If rounding would exceed the largest representable offset, the result is instead rounded
+    /// toward zero, so `±25:59:59` becomes `±25:59:00`.
+    ///
+    /// ```rust
+    /// # use time_macros::offset;
+    /// assert_eq!(offset!(+0:00:30).round_to_minutes(), offset!(+0:01));
+    /// assert_eq!(offset!(-0:00:30).round_to_minutes(), offset!(-0:01));
+    /// assert_eq!(offset!(+1:02:29).round_to_minutes(), offset!(+1:02));
+    /// assert_eq!(offset!(+25:59:59).round_to_minutes(), offset!(+25:59));
+    /// ```
+    pub const fn round_to_minutes(self) -> Self {
+        self.round_to(Second::per(Minute) as _)
+    }
+
+    /// Round the offset to the nearest whole hour, with halfway values rounded away from zero. The
+    /// sign of the offset is preserved.
+    ///
+    /// If rounding would exceed the largest representable offset, the result is instead rounded
+    /// toward zero, so `±25:30` becomes `±25:00`.
+    ///
+    /// ```rust
+    /// # use time_macros::offset;
+    /// assert_eq!(offset!(+5:30).round_to_hours(), offset!(+6));
+    /// assert_eq!(offset!(-5:30).round_to_hours(), offset!(-6));
+    /// assert_eq!(offset!(+5:29:59).round_to_hours(), offset!(+5));
+    /// assert_eq!(offset!(+25:30).round_to_hours(), offset!(+25));
+    /// ```
+    pub const fn round_to_hours(self) -> Self {
+        self.round_to(Second::per(Hour) as _)
+    }
+
+    /// Round the offset to the nearest multiple of `unit` seconds, with halfway values rounded away
+    /// from zero. Values that would be out of range are rounded toward zero instead.
+    const fn round_to(self, unit: i32) -> Self {
+        let magnitude = self.whole_seconds().abs();
+        let mut rounded = (magnitude + unit / 2) / unit * unit;
+        if rounded > WholeSeconds::MAX.get() {
+            rounded -= unit;
+        }
+
+        Self::from_whole_seconds_ranged(WholeSeconds::new_saturating(if self.is_negative() {
+            -rounded
+        } else {
+            rounded
+        }))
+    }
+    // endregion rounding
+
+    // region: offset transitions
+    /// Determine which moments a local date and time refers to around a transition between two
+    /// offsets, such as the start or end of daylight saving time.
+    ///
+    /// `before` is the offset in effect strictly before the `transition` instant, and `after` is
+    /// the offset in effect from it onwards. The local datetime is interpreted in both offsets.
+    /// Interpreting it in `before` is valid if the resulting moment is before the transition, and
+    /// interpreting it in `after` is valid if the resulting moment is at or after the transition.
+    ///
+    /// - If exactly one interpretation is valid, [`LocalResult::Single`] is returned.
+    /// - If both are valid, the local time occurs twice (a "fold"), which happens when the clock is
+    ///   set back. [`LocalResult::Ambiguous`] is returned with the earlier moment first.
+    /// - If neither is valid, the local time is skipped (a "gap"), which happens when the clock is
+    ///   set forward. [`LocalResult::None`] is returned.
+    ///
+    /// No time zone database is consulted; the result is determined entirely by the offsets and
+    /// the transition.
+    ///
+    /// ```rust
+    /// # use time::{LocalResult, UtcOffset};
+    /// # use time_macros::{datetime, offset};
+    /// let (est, edt) = (offset!(-5), offset!(-4));
+    ///
+    /// // Clocks in New York moved from 2:00 EST to 3:00 EDT.
+    /// let transition = datetime!(2024-03-10 2:00 -5);
+    /// assert_eq!(
+    ///     UtcOffset::classify_local(datetime!(2024-03-10 2:30), transition, est, edt),
+    ///     LocalResult::None
+    /// );
+    ///
+    /// // Clocks in New York moved from 2:00 EDT to 1:00 EST.
+    /// let transition = datetime!(2024-11-03 2:00 -4);
+    /// assert_eq!(
+    ///     UtcOffset::classify_local(datetime!(2024-11-03 1:30), transition, edt, est),
+    ///     LocalResult::Ambiguous(datetime!(2024-11-03 1:30 -4), datetime!(2024-11-03 1:30 -5))
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::classify_local(datetime!(2024-11-03 3:00), transition, edt, est),
+    ///     LocalResult::Single(datetime!(2024-11-03 3:00 -5))
+    /// );
+    /// ```
+    pub fn classify_local(
+        local: PrimitiveDateTime,
+        transition: OffsetDateTime,
+        before: Self,
+        after: Self,
+    ) -> LocalResult {
+        let earlier = local.assume_offset(before);
+        let later = local.assume_offset(after);
+
+        match (earlier < transition, later >= transition) {
+            (true, true) => LocalResult::Ambiguous(earlier, later),
+            (true, false) => LocalResult::Single(earlier),
+            (false, true) => LocalResult::Single(later),
+            (false, false) => LocalResult::None,
+        }
+    }
+    // endregion offset transitions
+
     // region: local offset
     /// Attempt to obtain the system's UTC offset at a known moment in time. If the offset cannot be
     /// determined, an error is returned.
@@ -347,7 +504,25 @@ impl UtcOffset {
     /// ```
     #[cfg(feature = "local-offset")]
     pub fn local_offset_at(datetime: OffsetDateTime) -> Result<Self, error::IndeterminateOffset> {
-        local_offset_at(datetime).ok_or(error::IndeterminateOffset)
+        Ok(local_offset_at(datetime)?)
+    }
+
+    /// Attempt to obtain the system's UTC offset at a known moment in time. If the offset cannot be
+    /// determined, the reason is returned.
+    ///
+    /// ```rust
+    /// # use time::{error::IndeterminateOffsetReason, OffsetDateTime, UtcOffset};
+    /// match UtcOffset::local_offset_at_detailed(OffsetDateTime::UNIX_EPOCH) {
+    ///     Ok(offset) => println!("{offset}"),
+    ///     Err(IndeterminateOffsetReason::Unsupported) => println!("{}", UtcOffset::UTC),
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// ```
+    #[cfg(feature = "local-offset")]
+    pub fn local_offset_at_detailed(
+        datetime: OffsetDateTime,
+    ) -> Result<Self, error::IndeterminateOffsetReason> {
+        local_offset_at(datetime)
     }
 
     /// Attempt to obtain the system's current UTC offset. If the offset cannot be determined, an
@@ -363,7 +538,7 @@ impl UtcOffset {
     #[cfg(feature = "local-offset")]
     pub fn current_local_offset() -> Result<Self, error::IndeterminateOffset> {
         let now = OffsetDateTime::now_utc();
-        local_offset_at(now).ok_or(error::IndeterminateOffset)
+        Ok(local_offset_at(now)?)
     }
     // endregion: local offset
 }
@@ -380,6 +555,54 @@ impl UtcOffset {
         format.format_into(output, None, None, Some(self))
     }
 
+    /// Format the `UtcOffset` using the provided [format description](crate::format_description),
+    /// writing the result to a [`fmt::Write`] implementor.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::offset;
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// let mut buf = String::new();
+    /// offset!(+1).format_into_fmt(&mut buf, &format)?;
+    /// assert_eq!(buf, "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_fmt(output, None, None, Some(self))
+    }
+
+    /// Format the `UtcOffset` using the provided [format description](crate::format_description),
+    /// writing the result to the start of a byte slice. The number of bytes written is returned.
+    ///
+    /// If the slice is too small to hold the formatted value, [`Format::BufferTooSmall`] is
+    /// returned. The contents of the slice are unspecified in this situation.
+    ///
+    /// [`Format::BufferTooSmall`]: error::Format::BufferTooSmall
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::offset;
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// let mut buf = [0; 32];
+    /// let len = offset!(+1).format_into_slice(&mut buf, &format)?;
+    /// assert_eq!(&buf[..len], b"+01:00");
+    /// assert!(offset!(+1)
+    ///     .format_into_slice(&mut buf[..5], &format)
+    ///     .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into_slice(output, None, None, Some(self))
+    }
+
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -412,6 +635,88 @@ impl UtcOffset {
     ) -> Result<Self, error::Parse> {
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse a `UtcOffset` from the start of the input using the provided [format
+    /// description](crate::format_description), returning the value and the number of bytes
+    /// consumed.
+    ///
+    /// Unlike [`UtcOffset::parse`], input may remain after the value is parsed.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::{offset, format_description};
+    /// let format = format_description!("[offset_hour]:[offset_minute]");
+    /// assert_eq!(UtcOffset::parse_partial("-03:42 rest", &format)?, (offset!(-3:42), 6));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_partial(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, usize), error::Parse> {
+        let (parsed, consumed) = description.parse_partial(input.as_bytes())?;
+        Ok((parsed.try_into()?, consumed))
+    }
+}
+
+/// Parse a `UtcOffset` from a string in one of the forms `Z`, `±HH`, `±HHMM`, `±HH:MM`, or
+/// `±HH:MM:SS`. `Z` may also be lowercase.
+///
+/// ```rust
+/// # use time::UtcOffset;
+/// # use time_macros::offset;
+/// assert_eq!("Z".parse::<UtcOffset>()?, offset!(UTC));
+/// assert_eq!("+05".parse::<UtcOffset>()?, offset!(+5));
+/// assert_eq!("-0330".parse::<UtcOffset>()?, offset!(-3:30));
+/// assert_eq!("+01:02:03".parse::<UtcOffset>()?, offset!(+1:02:03));
+/// assert!("+01:60".parse::<UtcOffset>().is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg(feature = "parsing")]
+impl core::str::FromStr for UtcOffset {
+    type Err = error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::error::ParseFromDescription::{InvalidComponent, UnexpectedTrailingCharacters};
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, sign};
+        use crate::parsing::ParsedItem;
+
+        if let b"Z" | b"z" = s.as_bytes() {
+            return Ok(Self::UTC);
+        }
+
+        let ParsedItem(input, sign) = sign(s.as_bytes()).ok_or(InvalidComponent("offset hour"))?;
+        let sign = if sign == b'-' { -1 } else { 1 };
+
+        let ParsedItem(input, hour) =
+            exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset hour"))?;
+
+        let (input, is_extended) = match ascii_char::<b':'>(input) {
+            Some(ParsedItem(input, ())) => (input, true),
+            None => (input, false),
+        };
+        let (input, minute) = match exactly_n_digits::<2, u8>(input) {
+            Some(ParsedItem(input, minute)) => (input, minute),
+            None if is_extended => return Err(InvalidComponent("offset minute").into()),
+            None => (input, 0),
+        };
+
+        let (input, second) = match ascii_char::<b':'>(input) {
+            Some(ParsedItem(input, ())) if is_extended => {
+                let ParsedItem(input, second) =
+                    exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset second"))?;
+                (input, second)
+            }
+            _ => (input, 0),
+        };
+
+        if !input.is_empty() {
+            return Err(UnexpectedTrailingCharacters.into());
+        }
+
+        // The digits are at most 99, so they always fit in an `i8`.
+        Self::from_hms(sign * hour as i8, sign * minute as i8, sign * second as i8)
+            .map_err(|err| error::TryFromParsed::ComponentRange(err).into())
+    }
 }
 
 mod private {
@@ -475,3 +780,65 @@ impl Neg for UtcOffset {
         Self::from_hms_ranged(self.hours.neg(), self.minutes.neg(), self.seconds.neg())
     }
 }
+
+/// The moments that a local date and time refers to, as returned by [`UtcOffset::classify_local`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalResult {
+    /// The local datetime refers to exactly one moment.
+    Single(OffsetDateTime),
+    /// The local datetime refers to two moments, as it occurs twice when the clock is set back.
+    /// The earlier moment is first.
+    Ambiguous(OffsetDateTime, OffsetDateTime),
+    /// The local datetime does not exist, as it is skipped when the clock is set forward.
+    None,
+}
+
+impl LocalResult {
+    /// Obtain the moment if it is unambiguous.
+    ///
+    /// ```rust
+    /// # use time::LocalResult;
+    /// # use time_macros::datetime;
+    /// let moment = datetime!(2024-11-03 1:30 -4);
+    /// assert_eq!(LocalResult::Single(moment).single(), Some(moment));
+    /// assert_eq!(LocalResult::None.single(), None);
+    /// ```
+    pub const fn single(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Single(moment) => Some(moment),
+            Self::Ambiguous(..) | Self::None => None,
+        }
+    }
+
+    /// Obtain the earliest moment, if any.
+    ///
+    /// ```rust
+    /// # use time::LocalResult;
+    /// # use time_macros::datetime;
+    /// let earlier = datetime!(2024-11-03 1:30 -4);
+    /// let later = datetime!(2024-11-03 1:30 -5);
+    /// assert_eq!(LocalResult::Ambiguous(earlier, later).earliest(), Some(earlier));
+    /// ```
+    pub const fn earliest(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Single(moment) | Self::Ambiguous(moment, _) => Some(moment),
+            Self::None => None,
+        }
+    }
+
+    /// Obtain the latest moment, if any.
+    ///
+    /// ```rust
+    /// # use time::LocalResult;
+    /// # use time_macros::datetime;
+    /// let earlier = datetime!(2024-11-03 1:30 -4);
+    /// let later = datetime!(2024-11-03 1:30 -5);
+    /// assert_eq!(LocalResult::Ambiguous(earlier, later).latest(), Some(later));
+    /// ```
+    pub const fn latest(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Single(moment) | Self::Ambiguous(_, moment) => Some(moment),
+            Self::None => None,
+        }
+    }
+}
@@ -31,6 +31,72 @@ pub enum Weekday {
 }
 
 impl Weekday {
+    /// Create a `Weekday` from its one-indexed number of days from Monday, where Monday is `1` and
+    /// Sunday is `7`. This is the numbering used by ISO 8601.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_monday_one_indexed(1), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_monday_one_indexed(7), Ok(Weekday::Sunday));
+    /// assert!(Weekday::from_monday_one_indexed(0).is_err());
+    /// ```
+    pub const fn from_monday_one_indexed(n: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_number(n, 1, Monday)
+    }
+
+    /// Create a `Weekday` from its zero-indexed number of days from Monday, where Monday is `0` and
+    /// Sunday is `6`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_monday_zero_indexed(0), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_monday_zero_indexed(6), Ok(Weekday::Sunday));
+    /// assert!(Weekday::from_monday_zero_indexed(7).is_err());
+    /// ```
+    pub const fn from_monday_zero_indexed(n: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_number(n, 0, Monday)
+    }
+
+    /// Create a `Weekday` from its one-indexed number of days from Sunday, where Sunday is `1` and
+    /// Saturday is `7`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_sunday_one_indexed(1), Ok(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_sunday_one_indexed(7), Ok(Weekday::Saturday));
+    /// assert!(Weekday::from_sunday_one_indexed(0).is_err());
+    /// ```
+    pub const fn from_sunday_one_indexed(n: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_number(n, 1, Sunday)
+    }
+
+    /// Create a `Weekday` from its zero-indexed number of days from Sunday, where Sunday is `0` and
+    /// Saturday is `6`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_sunday_zero_indexed(0), Ok(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_sunday_zero_indexed(6), Ok(Weekday::Saturday));
+    /// assert!(Weekday::from_sunday_zero_indexed(7).is_err());
+    /// ```
+    pub const fn from_sunday_zero_indexed(n: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_number(n, 0, Sunday)
+    }
+
+    /// Create a `Weekday` from its number, where `first` is numbered `offset`.
+    const fn from_number(n: u8, offset: u8, first: Self) -> Result<Self, error::ComponentRange> {
+        if n < offset || n - offset >= 7 {
+            return Err(error::ComponentRange {
+                name: "weekday",
+                minimum: offset as _,
+                maximum: offset as i64 + 6,
+                value: n as _,
+                conditional_range: false,
+            });
+        }
+        Ok(first.nth_next(n - offset))
+    }
+
     /// Get the previous weekday.
     ///
     /// ```rust
@@ -96,6 +162,7 @@ impl Weekday {
     /// assert_eq!(Weekday::Monday.nth_prev(1), Weekday::Sunday);
     /// assert_eq!(Weekday::Sunday.nth_prev(10), Weekday::Thursday);
     /// ```
+    #[doc(alias = "nth_previous")]
     pub const fn nth_prev(self, n: u8) -> Self {
         match self.number_days_from_monday() as i8 - (n % 7) as i8 {
             1 | -6 => Tuesday,
@@ -111,6 +178,19 @@ impl Weekday {
         }
     }
 
+    /// Get the number of days from `self` until the next occurrence of `other`, in the range
+    /// `0..=6`. This is zero if the weekdays are the same.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Friday.days_until(Weekday::Monday), 3);
+    /// assert_eq!(Weekday::Monday.days_until(Weekday::Friday), 4);
+    /// assert_eq!(Weekday::Monday.days_until(Weekday::Monday), 0);
+    /// ```
+    pub const fn days_until(self, other: Self) -> u8 {
+        (other.number_days_from_monday() + 7 - self.number_days_from_monday()) % 7
+    }
+
     /// Get the one-indexed number of days from Monday.
     ///
     /// ```rust
@@ -159,6 +239,20 @@ impl Weekday {
             Sunday => 0,
         }
     }
+
+    /// Get an iterator over all seven weekdays in order, beginning with `self`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// let mut weekdays = Weekday::Sunday.all_from();
+    /// assert_eq!(weekdays.len(), 7);
+    /// assert_eq!(weekdays.next(), Some(Weekday::Sunday));
+    /// assert_eq!(weekdays.next(), Some(Weekday::Monday));
+    /// assert_eq!(weekdays.last(), Some(Weekday::Saturday));
+    /// ```
+    pub fn all_from(self) -> impl ExactSizeIterator<Item = Self> + DoubleEndedIterator + Clone {
+        (0..7).map(move |n| self.nth_next(n))
+    }
 }
 
 mod private {
@@ -202,19 +296,41 @@ impl fmt::Display for Weekday {
     }
 }
 
+/// Parse a weekday from its full English name or three-letter abbreviation, ignoring case, or
+/// from its ISO 8601 number, where Monday is 1 and Sunday is 7.
+///
+/// ```rust
+/// # use time::Weekday;
+/// assert_eq!("Monday".parse(), Ok(Weekday::Monday));
+/// assert_eq!("tue".parse(), Ok(Weekday::Tuesday));
+/// assert_eq!("7".parse(), Ok(Weekday::Sunday));
+/// assert!("Mo".parse::<Weekday>().is_err());
+/// ```
 impl FromStr for Weekday {
     type Err = error::InvalidVariant;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Monday" => Ok(Monday),
-            "Tuesday" => Ok(Tuesday),
-            "Wednesday" => Ok(Wednesday),
-            "Thursday" => Ok(Thursday),
-            "Friday" => Ok(Friday),
-            "Saturday" => Ok(Saturday),
-            "Sunday" => Ok(Sunday),
-            _ => Err(error::InvalidVariant),
+        const NAMES: [(&str, Weekday); 7] = [
+            ("Monday", Monday),
+            ("Tuesday", Tuesday),
+            ("Wednesday", Wednesday),
+            ("Thursday", Thursday),
+            ("Friday", Friday),
+            ("Saturday", Saturday),
+            ("Sunday", Sunday),
+        ];
+
+        if !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_digit()) {
+            return match s.parse::<u8>() {
+                Ok(number @ 1..=7) => Ok(Monday.nth_next(number - 1)),
+                _ => Err(error::InvalidVariant),
+            };
         }
+
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]))
+            .map(|&(_, weekday)| weekday)
+            .ok_or(error::InvalidVariant)
     }
 }